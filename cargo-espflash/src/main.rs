@@ -2,6 +2,7 @@ use std::{
     fs,
     path::PathBuf,
     process::{Command, ExitStatus, Stdio, exit},
+    time::Duration,
 };
 
 use cargo_metadata::{Message, MetadataCommand};
@@ -15,9 +16,14 @@ use espflash::{
         *,
     },
     flasher::FlashSize,
-    image_format::{ImageFormat, ImageFormatKind, idf::check_idf_bootloader},
+    image_format::{
+        ImageFormat,
+        ImageFormatKind,
+        idf::check_idf_bootloader,
+        partitions::PartitionTableFormat,
+    },
     logging::initialize_logger,
-    target::{Chip, XtalFrequency},
+    target::Chip,
     update::check_for_update,
 };
 use log::{LevelFilter, debug, info};
@@ -61,6 +67,15 @@ enum CargoSubcommand {
             action
         )]
         skip_update_check: bool,
+
+        /// Abort the operation if it takes longer than SECS seconds
+        ///
+        /// The process exits with code 124 when the timeout is exceeded. A
+        /// flash read or write in progress is cancelled and the device
+        /// is reset, other operations are given a few seconds to stop
+        /// before the process exits without resetting the device.
+        #[clap(long, global = true, value_name = "SECS", env = "ESPFLASH_TIMEOUT")]
+        timeout: Option<u64>,
     },
 }
 
@@ -70,7 +85,12 @@ enum Commands {
     ///
     /// Automatically detects and prints the chip type, crystal frequency, flash
     /// size, chip features, and MAC address of a connected target device.
-    BoardInfo(ConnectArgs),
+    BoardInfo(BoardInfoArgs),
+    /// Calculate the checksum of the given region
+    ///
+    /// MD5 is computed by the device, while SHA-256 and CRC-32 are computed
+    /// by the host after reading the region back.
+    Checksum(ChecksumArgs),
     /// Calculate the MD5 checksum of the given region
     ChecksumMd5(ChecksumMd5Args),
     /// Generate completions for the given shell
@@ -80,12 +100,34 @@ enum Commands {
     /// depending on which shell is being used; consult your shell's
     /// documentation to determine the appropriate path.
     Completions(CompletionsArgs),
+    /// Compare two ESP-IDF application images
+    ///
+    /// Reports differences in the image header and in each segment, matching
+    /// segments by their load address, along with the total number of
+    /// changed bytes.
+    DiffImage(DiffImageArgs),
     /// Erase Flash entirely
     EraseFlash(EraseFlashArgs),
     /// Erase specified partitions
     EraseParts(ErasePartsArgs),
     /// Erase specified region
     EraseRegion(EraseRegionArgs),
+    /// Search the flash of the target device for a byte pattern
+    ///
+    /// The flash is read in chunks and the offsets of all matches are
+    /// printed, without dumping the flash to a file first.
+    FindBytes(FindBytesArgs),
+    /// Print the JEDEC ID and unique ID of the target device's flash chip
+    ///
+    /// The 64-bit unique ID is read with the 0x4B command, which not all
+    /// flash chips support.
+    FlashId(ConnectArgs),
+    /// Print the decoded status registers of the target device's flash chip
+    ///
+    /// Shows the block protection and quad enable bits, and whether the
+    /// status register is locked by the /WP pin, to help diagnose writes
+    /// which silently fail.
+    FlashStatus(ConnectArgs),
     /// Flash an application in ELF format to a target device
     ///
     /// First convert the ELF file produced by cargo into the appropriate
@@ -118,10 +160,31 @@ enum Commands {
     /// '--to-binary' options, plus the ability to print a partition table
     /// in tabular format.
     PartitionTable(PartitionTableArgs),
+    /// Print a hexdump of a region of flash
+    ///
+    /// The region is read in chunks and printed as offset, hex and ASCII
+    /// columns, without writing it to a file first.
+    Peek(PeekArgs),
+    /// Read a single eFuse field by name
+    ///
+    /// The field names are those of the chip's eFuse table, for example
+    /// `WAFER_VERSION_MAJOR`. Fields of up to 64 bits are printed as an
+    /// integer, wider fields as hex encoded little-endian bytes.
+    ///
+    /// With `--calibration`, the ADC and temperature sensor calibration fields
+    /// are printed instead, decoded into millivolts or degrees Celsius where
+    /// their encoding is known.
+    ReadEfuse(ReadEfuseArgs),
     /// Read SPI flash content
     ReadFlash(ReadFlashArgs),
     /// Reset the target device
     Reset(ConnectArgs),
+    /// Print the reason for the most recent reset of the target device
+    ///
+    /// The reason is decoded on a best-effort basis. Since resetting the device
+    /// into download mode overwrites it, use `--before no-reset` on a device
+    /// which is already in download mode.
+    ResetReason(ConnectArgs),
     /// Generate a binary application image and save it to a local disk
     ///
     /// If the '--merge' option is used, then the bootloader, partition table,
@@ -129,9 +192,27 @@ enum Commands {
     /// Otherwise, each segment will be saved as individual binaries, prefixed
     /// with their intended addresses in flash.
     SaveImage(SaveImageArgs),
+    /// Check the connection to a device by round-tripping a flash region
+    ///
+    /// Writes a known pattern to a scratch region at the end of flash, reads it
+    /// back and verifies it, and reports the measured throughput. The region is
+    /// restored to its previous contents afterwards, unless `--erase` is given
+    /// to erase it.
+    Selftest(SelftestArgs),
+    /// Print version and build information
+    ///
+    /// Includes the versions of the bootloaders and flasher stubs bundled with
+    /// this build, which is useful when reporting issues.
+    Version(VersionArgs),
     /// Write a binary file to a specific address or partition in a target
     /// device's flash
     WriteBin(WriteBinArgs),
+    /// Write a value or a binary file to the target device's memory
+    ///
+    /// The address must lie within one of the chip's RAM regions, including
+    /// RTC fast and slow memory, which is useful for setting up state before
+    /// running code from RAM. Use `--after no-reset` to keep the written data.
+    WriteMem(WriteMemArgs),
 }
 
 #[derive(Debug, Args)]
@@ -183,15 +264,36 @@ pub struct ErasePartsArgs {
     #[clap(flatten)]
     pub connect_args: ConnectArgs,
     /// Labels of the partitions to be erased
-    #[arg(value_name = "LABELS", value_delimiter = ',')]
+    #[arg(
+        value_name = "LABELS",
+        value_delimiter = ',',
+        required_unless_present = "partition_type"
+    )]
     pub erase_parts: Vec<String>,
+    /// Also erase all partitions of the given type, e.g. `data`
+    #[arg(long = "type", visible_alias = "partition-type", value_name = "TYPE")]
+    pub partition_type: Option<String>,
+    /// Only erase partitions of the given subtype, e.g. `ota`
+    #[arg(
+        long = "subtype",
+        visible_alias = "partition-subtype",
+        value_name = "SUBTYPE",
+        requires = "partition_type"
+    )]
+    pub partition_subtype: Option<String>,
     /// Input partition table
     #[arg(long, value_name = "FILE")]
     pub partition_table: Option<PathBuf>,
+    /// Format of the partition table, detected from its contents if not given
+    #[arg(long, value_name = "FORMAT")]
+    pub partition_table_format: Option<PartitionTableFormat>,
     /// Specify a (binary) package within a workspace which may provide a
     /// partition table
     #[arg(long)]
     pub package: Option<String>,
+    /// Do not ask for confirmation before erasing
+    #[arg(short = 'y', long)]
+    pub yes: bool,
 }
 
 /// Build and flash an application to a target device
@@ -237,9 +339,14 @@ fn main() -> Result<()> {
     let CargoSubcommand::Espflash {
         subcommand: args,
         skip_update_check,
+        timeout,
     } = cli.subcommand;
     debug!("{args:#?}, {skip_update_check:#?}");
 
+    if let Some(timeout) = timeout {
+        start_timeout_watchdog(Duration::from_secs(timeout));
+    }
+
     // Only check for updates once the command-line arguments have been processed,
     // to avoid printing any update notifications when the help message is
     // displayed.
@@ -252,23 +359,43 @@ fn main() -> Result<()> {
 
     // Execute the correct action based on the provided subcommand and its
     // associated arguments.
-    match args {
+    let result = match args {
         Commands::BoardInfo(args) => board_info(&args, &config),
+        Commands::Checksum(args) => checksum(&args, &config),
         Commands::ChecksumMd5(args) => checksum_md5(&args, &config),
         Commands::Completions(args) => completions(&args, &mut Cli::command(), "cargo"),
+        Commands::DiffImage(args) => diff_image(&args),
         Commands::EraseFlash(args) => erase_flash(args, &config),
         Commands::EraseParts(args) => erase_parts(args, &config),
         Commands::EraseRegion(args) => erase_region(args, &config),
+        Commands::FindBytes(args) => find_bytes(&args, &config),
         Commands::Flash(args) => flash(args, &config),
+        Commands::FlashId(args) => flash_id(&args, &config),
+        Commands::FlashStatus(args) => flash_status(&args, &config),
         Commands::HoldInReset(args) => hold_in_reset(args, &config),
         Commands::ListPorts(args) => list_ports(&args, &config.port_config),
         Commands::Monitor(args) => serial_monitor(args, &config),
         Commands::PartitionTable(args) => partition_table(args),
+        Commands::Peek(args) => peek(&args, &config),
+        Commands::ReadEfuse(args) => read_efuse(&args, &config),
         Commands::ReadFlash(args) => read_flash(args, &config),
         Commands::Reset(args) => reset(args, &config),
+        Commands::ResetReason(args) => reset_reason(&args, &config),
         Commands::SaveImage(args) => save_image(args, &config),
+        Commands::Selftest(args) => selftest(args, &config),
+        Commands::Version(args) => {
+            version(&args, env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+        }
         Commands::WriteBin(args) => write_bin(args, &config),
+        Commands::WriteMem(args) => write_mem(&args, &config),
+    };
+
+    // A cancelled operation fails with the exit code of the timeout instead
+    if timed_out() {
+        std::process::exit(TIMEOUT_EXIT_CODE);
     }
+
+    result
 }
 
 #[derive(Debug, Clone)]
@@ -279,28 +406,42 @@ struct BuildContext {
 }
 
 fn erase_parts(args: ErasePartsArgs, config: &Config) -> Result<()> {
-    if args.connect_args.no_stub {
+    if !args.connect_args.use_stub(config) {
         return Err(EspflashError::StubRequired).into_diagnostic();
     }
 
-    let partition_table = args.partition_table.as_deref().or(config
-        .project_config
-        .idf_format_args
-        .partition_table
-        .as_deref());
+    let idf_format_args = &config.project_config.idf_format_args;
+    let (partition_table, partition_table_format) = match args.partition_table.as_deref() {
+        Some(path) => (Some(path), args.partition_table_format),
+        None => (
+            idf_format_args.partition_table.as_deref(),
+            args.partition_table_format
+                .or(idf_format_args.partition_table_format),
+        ),
+    };
     let mut flasher = connect(&args.connect_args, config, false, false)?;
     let chip = flasher.chip();
     let partition_table = match partition_table {
-        Some(path) => Some(parse_partition_table(path)?),
+        Some(path) => Some(parse_partition_table(path, partition_table_format)?),
         None => None,
     };
 
-    info!("Erasing the following partitions: {:?}", args.erase_parts);
+    let mut erase_parts = args.erase_parts;
+    if let Some(ty) = &args.partition_type {
+        erase_parts.extend(partition_labels_by_type(
+            partition_table.as_ref(),
+            ty,
+            args.partition_subtype.as_deref(),
+        )?);
+    }
+
+    confirm_erase_partitions(partition_table.as_ref(), &erase_parts, args.yes)?;
+
+    info!("Erasing the following partitions: {erase_parts:?}");
 
-    erase_partitions(&mut flasher, partition_table, Some(args.erase_parts), None)?;
-    flasher
-        .connection()
-        .reset_after(!args.connect_args.no_stub, chip)?;
+    erase_partitions(&mut flasher, partition_table, Some(erase_parts), None)?;
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
 
     Ok(())
 }
@@ -309,20 +450,34 @@ fn flash(args: FlashArgs, config: &Config) -> Result<()> {
     let metadata = PackageMetadata::load(&args.build_args.package)?;
     let cargo_config = CargoConfig::load(&metadata.workspace_root, &metadata.package_root);
 
+    if (args.flash_args.dump_after.is_some() || args.flash_args.verify_mode() == VerifyMode::Sha256)
+        && !args.connect_args.use_stub(config)
+    {
+        return Err(EspflashError::StubRequired).into_diagnostic();
+    }
+
     let mut flasher = connect(
         &args.connect_args,
         config,
-        args.flash_args.no_verify,
+        !args.flash_args.verify_md5(),
         args.flash_args.no_skip,
     )?;
+    flasher.set_verify_app_only(args.flash_args.verify_mode() == VerifyMode::App);
+    flasher.set_verify_bootloader(!args.flash_args.no_verify_bootloader);
+    flasher.set_compress_bootloader(!args.flash_args.no_compress_bootloader);
+    flasher.set_redundant_write(args.flash_args.redundant_write);
     flasher.verify_minimum_revision(args.flash_args.image.min_chip_rev)?;
+    if let Some(size) = args.flash_args.flash_block_size {
+        flasher.set_flash_block_size(size as usize)?;
+    }
+    flasher.set_auto_baud_backoff(args.flash_args.auto_baud_backoff);
 
     // If the user has provided a flash size via a command-line argument or config,
     // we'll override the detected (or default) value with this.
     if let Some(flash_size) = args.build_args.flash_config_args.flash_size {
-        flasher.set_flash_size(flash_size);
+        flasher.set_flash_size(flash_size)?;
     } else if let Some(flash_size) = config.project_config.flash.size {
-        flasher.set_flash_size(flash_size);
+        flasher.set_flash_size(flash_size)?;
     }
 
     let chip = flasher.chip();
@@ -352,10 +507,13 @@ fn flash(args: FlashArgs, config: &Config) -> Result<()> {
         args.format,
         &args.flash_args.erase_parts,
         &args.flash_args.erase_data_parts,
+        &args.flash_args.nvs_keys,
+        &args.flash_args.preserve,
     )?;
 
-    print_board_info(&mut flasher)?;
+    let dev_info = print_board_info(&mut flasher)?;
     ensure_chip_compatibility(chip, Some(elf_data.as_slice()))?;
+    check_elf_segments(chip, &elf_data, args.flash_args.image.lenient)?;
 
     let mut flash_config = args.build_args.flash_config_args;
     flash_config.flash_size = flash_config
@@ -367,13 +525,16 @@ fn flash(args: FlashArgs, config: &Config) -> Result<()> {
     if args.flash_args.ram {
         flasher.load_elf_to_ram(&elf_data, &mut EspflashProgress::default())?;
     } else {
-        let flash_data = make_flash_data(
+        let mut flash_data = make_flash_data(
             args.flash_args.image,
             &flash_config,
             config,
             chip,
             target_xtal_freq,
-        );
+        )?;
+        flash_data.chip_revision = dev_info
+            .revision
+            .map(|(major, minor)| (major * 100 + minor) as u16);
         let image_format = make_image_format(
             &elf_data,
             &flash_data,
@@ -383,9 +544,23 @@ fn flash(args: FlashArgs, config: &Config) -> Result<()> {
             build_ctx.bootloader_path,
             build_ctx.partition_table_path,
         )?;
+        if args.flash_args.verify_hash_from_image {
+            image_format.verify_digests()?;
+        }
+
+        check_flash_protection(
+            &mut flasher,
+            args.flash_args.unlock_flash,
+            args.connect_args.non_interactive,
+        )?;
 
         // If using ESP-IDF image format, check if we need to erase partitions.
+        let mut preserved = Vec::new();
         if let ImageFormat::EspIdf(idf_format) = &image_format {
+            if let Some(labels) = &args.flash_args.preserve {
+                preserved = preserve_partitions(&mut flasher, idf_format, labels)?;
+            }
+
             if args.flash_args.erase_parts.is_some() || args.flash_args.erase_data_parts.is_some() {
                 erase_partitions(
                     &mut flasher,
@@ -394,22 +569,56 @@ fn flash(args: FlashArgs, config: &Config) -> Result<()> {
                     args.flash_args.erase_data_parts,
                 )?;
             }
+
+            if let Some(nvs_keys) = &args.flash_args.nvs_keys {
+                write_nvs_keys(&mut flasher, idf_format, nvs_keys)?;
+            }
         }
 
+        let digest_region = match args.flash_args.verify_mode() {
+            VerifyMode::Sha256 => Some(app_digest_region(&image_format)?),
+            _ => None,
+        };
+
+        // Steps which run after flashing need the device to stay in download
+        // mode, so it is reset once they have completed
+        let post_flash = args.flash_args.dump_after.is_some()
+            || !preserved.is_empty()
+            || digest_region.is_some();
+        if post_flash {
+            flasher.set_reset_after_flash(false);
+        }
+        if args.flash_args.monitor {
+            defer_reset_to_monitor(&mut flasher, &mut monitor_args);
+        }
         flash_image(&mut flasher, image_format)?;
+
+        if let Some(region) = digest_region {
+            verify_app_digest(&mut flasher, region)?;
+        }
+
+        if !preserved.is_empty() {
+            restore_partitions(
+                &mut flasher,
+                &preserved,
+                &mut EspflashProgress::default(),
+                false,
+            )?;
+        }
+
+        if let Some(path) = &args.flash_args.dump_after {
+            dump_flash_after(&mut flasher, path, false)?;
+        }
+
+        if post_flash && !monitor_args.reset_on_start {
+            let use_stub = flasher.is_using_stub();
+            flasher.connection().reset_after(use_stub, chip)?;
+        }
     }
 
     if args.flash_args.monitor {
         let pid = flasher.connection().usb_pid();
-
-        // The 26MHz ESP32-C2's need to be treated as a special case.
-        if chip == Chip::Esp32c2
-            && target_xtal_freq == XtalFrequency::_26Mhz
-            && monitor_args.monitor_baud == 115_200
-        {
-            // 115_200 * 26 MHz / 40 MHz = 74_880
-            monitor_args.monitor_baud = 74_880;
-        }
+        adjust_monitor_baud(chip, target_xtal_freq, &mut monitor_args);
 
         monitor_args.elf = Some(build_ctx.artifact_path);
 
@@ -621,6 +830,12 @@ fn save_image(args: SaveImageArgs, config: &Config) -> Result<()> {
     if args.save_image_args.image.check_app_descriptor && args.format == ImageFormatKind::EspIdf {
         check_idf_bootloader(&elf_data)?;
     }
+    ensure_chip_compatibility(args.save_image_args.chip, Some(elf_data.as_slice()))?;
+    check_elf_segments(
+        args.save_image_args.chip,
+        &elf_data,
+        args.save_image_args.image.lenient,
+    )?;
 
     // Since we have no `Flasher` instance and as such cannot print the board
     // information, we will print whatever information we _do_ have.
@@ -631,21 +846,21 @@ fn save_image(args: SaveImageArgs, config: &Config) -> Result<()> {
     let mut flash_config = args.build_args.flash_config_args;
     flash_config.flash_size = flash_config
         .flash_size // Use CLI argument if provided
-        .or(config.project_config.flash.size) // If no CLI argument, try the config file
-        .or_else(|| Some(FlashSize::default())); // Otherwise, use a reasonable default value
+        .or(config.project_config.flash.size); // If no CLI argument, try the config file
 
     let xtal_freq = args
         .save_image_args
         .xtal_freq
         .unwrap_or(args.save_image_args.chip.default_xtal_frequency());
 
-    let flash_data = make_flash_data(
+    let mut flash_data = make_flash_data(
         args.save_image_args.image,
         &flash_config,
         config,
         args.save_image_args.chip,
         xtal_freq,
-    );
+    )?;
+    flash_data.entry = args.save_image_args.entry;
     let image_format = make_image_format(
         &elf_data,
         &flash_data,
@@ -656,11 +871,23 @@ fn save_image(args: SaveImageArgs, config: &Config) -> Result<()> {
         build_ctx.partition_table_path,
     )?;
 
+    if args.save_image_args.header_only {
+        return print_image_header(&image_format, args.save_image_args.chip);
+    }
+    let Some(file) = args.save_image_args.file else {
+        unreachable!("clap requires a file unless `--header-only` is given")
+    };
+
     save_elf_as_image(
-        args.save_image_args.file,
-        flash_data.flash_settings.size,
-        args.save_image_args.merge,
-        args.save_image_args.skip_padding,
+        file,
+        args.save_image_args.merge.then(|| MergeOptions {
+            // Pad to the default flash size if none was given
+            pad_to: (!args.save_image_args.skip_padding)
+                .then(|| flash_data.flash_settings.size.unwrap_or_default()),
+            fill_byte: args.save_image_args.fill_byte,
+            append: args.save_image_args.append,
+        }),
+        args.save_image_args.split_args,
         image_format,
     )?;
 
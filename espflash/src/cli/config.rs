@@ -96,6 +96,9 @@ pub struct ProjectConfig {
     /// Flash settings
     #[serde(default)]
     pub flash: FlashSettings,
+    /// Never use the RAM stub, unless `--stub` is passed
+    #[serde(default)]
+    pub no_stub: bool,
 }
 
 /// Serial port configuration
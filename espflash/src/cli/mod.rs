@@ -13,31 +13,43 @@
 #![allow(missing_docs)]
 
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs::{self, File},
-    io::{Read, Write},
+    io::{IsTerminal, Read, Write},
     num::ParseIntError,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+use base64::{Engine as _, engine::general_purpose};
 use clap::{Args, ValueEnum};
 use clap_complete::Shell;
 use comfy_table::{Attribute, Cell, Color, Table, modifiers, presets::UTF8_FULL};
 use config::PortConfig;
+use dialoguer::{Confirm, theme::ColorfulTheme};
 use esp_idf_part::{DataType, Partition, PartitionTable};
-use indicatif::{HumanCount, ProgressBar, style::ProgressStyle};
-use log::{debug, info, warn};
+use indicatif::{HumanBytes, HumanCount, MultiProgress, ProgressBar, style::ProgressStyle};
+use log::{debug, error, info, warn};
+use md5::Md5;
 use miette::{IntoDiagnostic, Result, WrapErr};
+use object::{Architecture, Object as _};
 use serde::{Deserialize, Serialize};
 use serialport::{FlowControl, SerialPortInfo, SerialPortType, UsbPortInfo};
+use sha2::{Digest, Sha256};
+use strum::IntoEnumIterator;
 
 use self::{
-    config::Config,
-    monitor::{LogFormat, check_monitor_args, monitor},
+    config::{Config, UsbDevice},
+    monitor::{LineEnding, LogFormat, LogLevel, check_monitor_args, monitor},
 };
 use crate::{
     connection::{
+        AdapterInit,
         Connection,
+        CustomReset,
+        ResetSequence,
         reset::{ResetAfterOperation, ResetBeforeOperation},
     },
     error::{Error, MissingPartition, MissingPartitionTable},
@@ -45,14 +57,44 @@ use crate::{
         DeviceInfo,
         FLASH_SECTOR_SIZE,
         FlashData,
+        FlashDataBuilder,
         FlashFrequency,
         FlashMode,
-        FlashSettings,
+        FlashReport,
         FlashSize,
         Flasher,
+        encryption::decrypt_flash_data,
+        status::StatusLayout,
+        stubs::FlashStub,
     },
-    image_format::{ImageFormat, ImageFormatKind, Metadata, idf::IdfBootloaderFormat},
-    target::{Chip, ProgressCallbacks, XtalFrequency},
+    image_format::{
+        ImageFormat,
+        ImageFormatKind,
+        Metadata,
+        Segment,
+        idf::{
+            AppInfo,
+            IdfBootloaderFormat,
+            ImageHeaderInfo,
+            OTA_SUBTYPE_MAX,
+            OTA_SUBTYPE_MIN,
+            bootloader_supports_revision,
+            default_bootloader_version,
+            encode_hex,
+            image_segments,
+            selected_ota_slot,
+            verify_appended_digest,
+        },
+        invalid_segments,
+        partitions::{
+            PartitionTableFormat,
+            Severity,
+            parse_device_table,
+            to_idf_bin,
+            validate_csv,
+        },
+    },
+    target::{Chip, ProgressCallbacks, XtalFrequency, efuse},
 };
 
 pub mod config;
@@ -65,16 +107,36 @@ mod serial;
 #[non_exhaustive]
 pub struct ConnectArgs {
     /// Reset operation to perform after connecting to the target
-    #[arg(short = 'a', long, default_value = "hard-reset")]
+    #[arg(
+        short = 'a',
+        long,
+        env = "ESPFLASH_AFTER",
+        default_value = "hard-reset"
+    )]
     pub after: ResetAfterOperation,
     /// Baud rate at which to communicate with target device
+    ///
+    /// Defaults to a baud rate suited to the serial adapter, if known.
     #[arg(short = 'B', long, env = "ESPFLASH_BAUD")]
     pub baud: Option<u32>,
     /// Reset operation to perform before connecting to the target
-    #[arg(short = 'b', long, default_value = "default-reset")]
+    #[arg(
+        short = 'b',
+        long,
+        env = "ESPFLASH_BEFORE",
+        default_value = "default-reset"
+    )]
     pub before: ResetBeforeOperation,
     /// Target device
-    #[arg(short = 'c', long)]
+    ///
+    /// When the chip is autodetected, connecting fails if the detected chip
+    /// differs from this one.
+    #[arg(
+        short = 'c',
+        long,
+        visible_alias = "expected-chip",
+        env = "ESPFLASH_CHIP"
+    )]
     pub chip: Option<Chip>,
     /// Require confirmation before auto-connecting to a recognized device.
     #[arg(long)]
@@ -83,8 +145,28 @@ pub struct ConnectArgs {
     #[arg(long)]
     pub list_all_ports: bool,
     /// Do not use the RAM stub for loading
-    #[arg(long)]
+    ///
+    /// Can also be enabled for every command with `no_stub = true` in the
+    /// configuration file.
+    #[arg(long, env = "ESPFLASH_NO_STUB", overrides_with = "stub")]
     pub no_stub: bool,
+    /// Use the RAM stub, even if disabled in the configuration file or with
+    /// `ESPFLASH_NO_STUB`
+    #[arg(long, overrides_with = "no_stub")]
+    pub stub: bool,
+    /// Fall back to the ROM loader if the RAM stub fails to load
+    ///
+    /// The ROM loader is slower and lacks some features of the stub, such as
+    /// erasing the whole flash, but works on some boards where the stub
+    /// upload fails.
+    #[arg(long, conflicts_with = "no_stub")]
+    pub stub_fallback: bool,
+    /// Don't detect the chip and flash size, trust the provided ones instead
+    ///
+    /// Requires `--chip`. The flash size is taken from `--flash-size` or the
+    /// configuration file, and defaults to 4MB.
+    #[arg(long, requires = "chip")]
+    pub skip_autodetect: bool,
     /// Serial port connected to target device
     #[arg(short = 'p', long, env = "ESPFLASH_PORT")]
     pub port: Option<String>,
@@ -92,6 +174,110 @@ pub struct ConnectArgs {
     /// device
     #[arg(long)]
     pub non_interactive: bool,
+    /// Log the raw protocol exchange with the target device
+    ///
+    /// Every command opcode, payload length and response status is logged,
+    /// along with a hex dump of the first BYTES bytes of each packet if given.
+    #[arg(long, value_name = "BYTES", num_args = 0..=1, default_missing_value = "0")]
+    pub trace_io: Option<usize>,
+    /// Align writes to the serial port to USB packets of BYTES bytes
+    ///
+    /// Every frame sent is padded to a whole number of packets. Without a
+    /// value, the packet size is chosen based on the USB device:
+    /// 512 bytes for high-speed bridges, 64 bytes otherwise. This can improve
+    /// throughput on native USB targets.
+    #[arg(long, value_name = "BYTES", num_args = 0..=1, default_missing_value = "0")]
+    pub usb_align: Option<usize>,
+    /// Timeout for long-running operations, such as erasing flash and
+    /// computing MD5 checksums, in seconds
+    ///
+    /// By default the timeout scales with the size of the region involved.
+    #[arg(long, value_name = "SECS", visible_alias = "read-timeout")]
+    pub op_timeout: Option<u64>,
+    /// Initialization sequence for serial adapters which need it before they
+    /// pass data on to the target device
+    ///
+    /// One of `release-lines`, `toggle-lines`, `wake-byte`, or `hex:` followed
+    /// by a preamble to send, e.g. `hex:55aa`.
+    #[arg(long, value_name = "PROFILE", visible_alias = "extra-baud-init")]
+    pub adapter_init: Option<AdapterInit>,
+    /// Custom sequence of control line changes resetting the target device
+    /// into download mode, for fixtures without the usual auto-reset circuit
+    ///
+    /// Steps are separated by `|`: `D0`/`D1` and `R0`/`R1` set DTR and RTS,
+    /// `U0,1` sets both at once and `W0.1` waits for the given number of
+    /// seconds, e.g. `D0|R1|W0.1|D1|R0|W0.05|D0`. Not used with `--before
+    /// no-reset` or `--before no-reset-no-sync`.
+    #[arg(long, value_name = "SEQUENCE")]
+    pub reset_sequence: Option<ResetSequence>,
+    /// Custom sequence of control line changes resetting the target device to
+    /// run the application after flashing, in the format of `--reset-sequence`
+    #[arg(long, value_name = "SEQUENCE", requires = "reset_sequence")]
+    pub hard_reset_sequence: Option<ResetSequence>,
+    /// Refuse to write or erase anything on the target device
+    ///
+    /// Commands which only read, such as `board-info`, `read-flash` and
+    /// `checksum`, still work. Meant for diagnostic deployments which must
+    /// never modify a device.
+    #[arg(long, env = "ESPFLASH_READ_ONLY")]
+    pub read_only: bool,
+    /// Crystal frequency of the target device
+    ///
+    /// Overrides the detected frequency when changing the baud rate,
+    /// generating the image and adjusting the monitor baud rate.
+    #[arg(long, short = 'x')]
+    pub xtal_freq: Option<XtalFrequency>,
+    /// Send the given hex bytes to the running application before connecting
+    ///
+    /// For applications which reboot into the ROM download mode on request,
+    /// on boards which can't be reset into it through the control lines.
+    #[arg(long, value_name = "HEX", value_parser = parse_hex_bytes)]
+    pub enter_bootloader_cmd: Option<Vec<u8>>,
+    /// Baud rate at which to send `--enter-bootloader-cmd`
+    #[arg(
+        long,
+        value_name = "BAUD",
+        default_value = "115200",
+        requires = "enter_bootloader_cmd"
+    )]
+    pub enter_bootloader_baud: u32,
+}
+
+impl ConnectArgs {
+    /// Whether to use the RAM stub, taking the configuration file into account
+    pub fn use_stub(&self, config: &Config) -> bool {
+        self.stub || !(self.no_stub || config.project_config.no_stub)
+    }
+}
+
+/// Print information about a connected target device
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct BoardInfoArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    pub connect_args: ConnectArgs,
+    /// Append the board information as a row to the given CSV file
+    ///
+    /// A header is written if the file is new. The columns are, in order:
+    /// `timestamp` (seconds since the Unix epoch), `port`, `chip`,
+    /// `revision`, `mac_address` and `flash_size`.
+    #[arg(long, value_name = "FILE")]
+    pub append_csv: Option<PathBuf>,
+    /// Read and print the partition table stored on the device
+    ///
+    /// This requires an additional flash read.
+    #[arg(long, visible_alias = "label-offsets")]
+    pub partitions: bool,
+    /// Offset of the partition table on the device
+    #[arg(
+        long,
+        value_name = "OFFSET",
+        default_value = "0x8000",
+        value_parser = parse_u32,
+        requires = "partitions"
+    )]
+    pub partition_table_offset: u32,
 }
 
 /// Generate completions for the given shell
@@ -102,6 +288,18 @@ pub struct CompletionsArgs {
     pub shell: Shell,
 }
 
+/// Compare two ESP-IDF application images
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct DiffImageArgs {
+    /// Image to compare against
+    #[arg(value_name = "OLD")]
+    pub old: PathBuf,
+    /// Image to compare
+    #[arg(value_name = "NEW")]
+    pub new: PathBuf,
+}
+
 /// Erase entire flash of target device
 #[derive(Debug, Args)]
 #[non_exhaustive]
@@ -109,6 +307,24 @@ pub struct EraseFlashArgs {
     /// Connection configuration
     #[clap(flatten)]
     pub connect_args: ConnectArgs,
+    /// Label of a partition to preserve, erasing the rest of the flash
+    /// (repeatable)
+    #[arg(
+        long = "keep",
+        visible_alias = "keep-partition",
+        value_name = "LABEL",
+        requires = "partition_table"
+    )]
+    pub keep: Vec<String>,
+    /// Path to a CSV or binary partition table, used to resolve `--keep`
+    #[arg(long, value_name = "FILE")]
+    pub partition_table: Option<PathBuf>,
+    /// Format of the partition table, detected from its contents if not given
+    #[arg(long, value_name = "FORMAT", requires = "partition_table")]
+    pub partition_table_format: Option<PartitionTableFormat>,
+    /// Do not ask for confirmation before erasing
+    #[arg(short = 'y', long)]
+    pub yes: bool,
 }
 
 /// Erase specified region of flash
@@ -128,21 +344,71 @@ pub struct EraseRegionArgs {
     /// Must be multiple of 4096(0x1000)
     #[arg(value_parser = parse_u32)]
     pub size: u32,
+    /// Do not ask for confirmation before erasing
+    #[arg(short = 'y', long)]
+    pub yes: bool,
 }
 
-/// Configure communication with the target device's flash
+/// Search the flash of the target device for a byte pattern
 #[derive(Debug, Args)]
 #[non_exhaustive]
+pub struct FindBytesArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    pub connect_args: ConnectArgs,
+    /// Pattern to search for, as hex bytes
+    ///
+    /// Each `??` matches any byte, e.g. `DEADBEEF` or `E9 ?? 02`.
+    #[arg(long, value_parser = parse_byte_pattern)]
+    pub pattern: BytePattern,
+    /// Region to search, as `<start>:<end>`
+    ///
+    /// Defaults to the whole flash.
+    #[arg(long, value_parser = parse_range)]
+    pub range: Option<(u32, u32)>,
+}
+
+/// A byte pattern in which `None` matches any byte
+pub type BytePattern = Vec<Option<u8>>;
+
+/// Configure communication with the target device's flash
+#[derive(Debug, Args, Clone)]
+#[non_exhaustive]
 pub struct FlashConfigArgs {
     /// Flash frequency
-    #[arg(short = 'f', long, value_name = "FREQ", value_enum)]
+    #[arg(
+        short = 'f',
+        long,
+        env = "ESPFLASH_FLASH_FREQ",
+        value_name = "FREQ",
+        value_enum
+    )]
     pub flash_freq: Option<FlashFrequency>,
     /// Flash mode to use
-    #[arg(short = 'm', long, value_name = "MODE", value_enum)]
+    #[arg(
+        short = 'm',
+        long,
+        env = "ESPFLASH_FLASH_MODE",
+        value_name = "MODE",
+        value_enum
+    )]
     pub flash_mode: Option<FlashMode>,
     /// Flash size of the target
-    #[arg(short = 's', long, value_name = "SIZE", value_enum)]
+    #[arg(
+        short = 's',
+        long,
+        env = "ESPFLASH_FLASH_SIZE",
+        value_name = "SIZE",
+        value_enum
+    )]
     pub flash_size: Option<FlashSize>,
+    /// Base address added to all flash write addresses
+    ///
+    /// Shifts the bootloader, partition table and application up by the given
+    /// amount, e.g. to reserve a region at 0x0 for a custom first-stage
+    /// loader.
+    #[arg(long, value_name = "ADDR", value_parser = parse_u32)]
+    pub flash_base: Option<u32>,
 }
 
 /// Flash an application to a target device
@@ -162,9 +428,54 @@ pub struct FlashArgs {
     /// Don't verify the flash contents after flashing
     #[arg(long)]
     pub no_verify: bool,
+    /// Which of the flashed data to verify after flashing
+    ///
+    /// Verifying only the application speeds up development iterations.
+    /// Defaults to `all`, unless `--no-verify` is given.
+    #[arg(long, value_enum, value_name = "WHAT", conflicts_with = "no_verify")]
+    pub verify: Option<VerifyMode>,
+    /// Don't verify the bootloader after flashing
+    ///
+    /// The bootloader is otherwise always verified, regardless of `--verify`
+    /// and `--no-verify`.
+    #[arg(long)]
+    pub no_verify_bootloader: bool,
+    /// Check the SHA256 digests appended to the bootloader and application
+    /// before flashing
+    ///
+    /// The digests are recomputed after the flash settings in the image
+    /// headers have been patched, so a mismatch points at a corrupt image.
+    #[arg(long)]
+    pub verify_hash_from_image: bool,
+    /// Write the bootloader region without compression
+    ///
+    /// Everything else is still written compressed.
+    #[arg(long)]
+    pub no_compress_bootloader: bool,
+    /// Write the bootloader and partition table twice, verifying each write
+    ///
+    /// Meant for critical-region reliability on high-value units, at the cost
+    /// of a few seconds per flash, not for general use. Regions which are
+    /// skipped because they are unchanged are not rewritten.
+    #[arg(long, visible_alias = "flash-twice")]
+    pub redundant_write: bool,
     /// Don't skip flashing of parts with matching checksum
     #[arg(long)]
     pub no_skip: bool,
+    /// Size of each block of data sent while writing flash
+    ///
+    /// Larger blocks improve throughput on good links, smaller blocks may
+    /// improve reliability on bad ones. Must be a power of two, at most 0x4000
+    /// when using the flasher stub and 0x400 otherwise.
+    #[arg(long, value_name = "BYTES", value_parser = parse_u32)]
+    pub flash_block_size: Option<u32>,
+    /// Step down the baud rate and retry on communication errors while writing
+    ///
+    /// Single errors are retried at the same baud rate, repeated errors while
+    /// writing one segment step through 921,600, 460,800, 230,400 and 115,200
+    /// baud.
+    #[arg(long)]
+    pub auto_baud_backoff: bool,
     /// Image related arguments
     #[clap(flatten)]
     pub image: ImageArgs,
@@ -178,6 +489,35 @@ pub struct FlashArgs {
     /// Only valid when using the `esp-idf` format.
     #[arg(long, value_name = "PARTS", value_enum, value_delimiter = ',')]
     pub erase_data_parts: Option<Vec<DataType>>,
+    /// Write the given NVS encryption keys file to the `nvs_keys` partition
+    ///
+    /// Only valid when using the `esp-idf` format.
+    #[arg(long, value_name = "FILE")]
+    pub nvs_keys: Option<PathBuf>,
+    /// Labels of partitions to preserve, e.g. `nvs`
+    ///
+    /// The partitions are read from the device before flashing and written
+    /// back afterwards, even if the image overwrites them. Only valid when
+    /// using the `esp-idf` format.
+    #[arg(
+        long,
+        value_name = "LABELS",
+        value_delimiter = ',',
+        conflicts_with = "ram"
+    )]
+    pub preserve: Option<Vec<String>>,
+    /// Clear the flash chip's block protection bits before writing
+    ///
+    /// Some flash chips are shipped with write protection enabled in their
+    /// status register, in which case writes and erases silently fail.
+    #[arg(long)]
+    pub unlock_flash: bool,
+    /// Read the whole flash back into the given file after flashing
+    ///
+    /// Useful to capture a golden sample of a known-good device. Requires the
+    /// RAM stub.
+    #[arg(long, value_name = "FILE", conflicts_with = "ram")]
+    pub dump_after: Option<PathBuf>,
 }
 
 /// Operations for ESP-IDF partition tables
@@ -190,12 +530,92 @@ pub struct PartitionTableArgs {
     /// Input partition table
     #[arg(value_name = "FILE")]
     partition_table: PathBuf,
+    /// Format of the input partition table, detected from its contents if not
+    /// given
+    #[arg(long, value_name = "FORMAT", conflicts_with = "validate")]
+    partition_table_format: Option<PartitionTableFormat>,
     /// Convert CSV partition table to binary representation
     #[arg(long, conflicts_with = "to_csv")]
     to_binary: bool,
     /// Convert binary partition table to CSV representation
     #[arg(long, conflicts_with = "to_binary")]
     to_csv: bool,
+    /// Omit the MD5 checksum entry from the binary partition table, like
+    /// `gen_esp32part.py --disable-md5sum`
+    #[arg(long, requires = "to_binary")]
+    no_md5: bool,
+    /// Validate a CSV partition table, reporting all problems found
+    #[arg(long, conflicts_with_all = ["to_binary", "to_csv", "output"])]
+    validate: bool,
+    /// Offset of the partition table, used when validating
+    #[arg(long, value_name = "OFFSET", default_value = "0x8000", value_parser = parse_u32, requires = "validate")]
+    partition_table_offset: u32,
+    /// Write a JSON manifest with the MD5 digest of each partition's contents
+    /// in the merged image given with `--image`
+    ///
+    /// Partitions extending past the end of the image are digested as if the
+    /// missing bytes were erased (0xFF).
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "image",
+        conflicts_with_all = ["to_binary", "to_csv", "validate"]
+    )]
+    manifest: Option<PathBuf>,
+    /// Merged image to compute the manifest from
+    #[arg(long, value_name = "FILE", requires = "manifest")]
+    image: Option<PathBuf>,
+}
+
+/// Read a single eFuse field of the target device
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct ReadEfuseArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    pub connect_args: ConnectArgs,
+    /// Name of the field, as listed in the chip's eFuse table
+    #[arg(long, value_name = "NAME", required_unless_present = "calibration")]
+    pub field: Option<String>,
+    /// Read and decode the ADC and temperature sensor calibration fields
+    #[arg(long, conflicts_with = "field")]
+    pub calibration: bool,
+    /// Print the field as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Write a value or a binary file to the target device's memory
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct WriteMemArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    pub connect_args: ConnectArgs,
+    /// Address to write to, which must be 4-byte aligned
+    #[arg(long, value_parser = parse_u32)]
+    pub addr: u32,
+    /// 32-bit value to write
+    #[arg(long, value_parser = parse_u32, required_unless_present = "file", conflicts_with = "file")]
+    pub value: Option<u32>,
+    /// Binary file to write, zero-padded to a multiple of 4 bytes
+    #[arg(long, value_name = "FILE")]
+    pub file: Option<PathBuf>,
+}
+
+/// Print a hexdump of a region of flash
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct PeekArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    pub connect_args: ConnectArgs,
+    /// Region to dump, as `<start>:<end>`
+    #[arg(long, value_parser = parse_range)]
+    pub range: (u32, u32),
+    /// Number of bytes per line
+    #[arg(long, default_value = "16", value_parser = clap::value_parser!(u32).range(1..=64))]
+    pub width: u32,
 }
 
 /// Reads the content of flash memory and saves it to a file
@@ -217,10 +637,117 @@ pub struct ReadFlashArgs {
     #[arg(value_parser = parse_u32)]
     pub size: u32,
     /// File name to save the read data to
-    pub file: PathBuf,
+    #[arg(required_unless_present = "base64")]
+    pub file: Option<PathBuf>,
+    /// Print the data to stdout as base64 instead of saving it to a file
+    ///
+    /// The output is wrapped at 76 characters per line, so it can be copied
+    /// over text-only channels.
+    #[arg(long, conflicts_with_all = ["file", "meta"])]
+    pub base64: bool,
     /// Maximum number of un-acked packets
     #[arg(long, default_value = "64", value_parser = parse_u32)]
     pub max_in_flight: u32,
+    /// Write a sidecar `.meta` file describing the dump
+    ///
+    /// The file contains the offset, length, chip and SHA256 of the dump, and
+    /// can be passed to `write-bin --from-dump` to validate a restore. With
+    /// `--split-size`, it also lists the parts the dump was split into.
+    #[arg(long)]
+    pub meta: bool,
+    /// Decrypt the data with the given flash encryption key after reading it
+    ///
+    /// The key file must contain the raw 256-bit (XTS-AES-128) or 512-bit
+    /// (XTS-AES-256) key. Not supported for the ESP32.
+    #[arg(long, value_name = "KEY_FILE")]
+    pub decrypt_key: Option<PathBuf>,
+    /// Split the dump into numbered files of at most SIZE bytes each
+    ///
+    /// The parts are named `<FILE>.000`, `<FILE>.001` and so on, and a
+    /// `<FILE>.index` file records the flash offset of each of them. The size
+    /// accepts `K`/`KiB` and `M`/`MiB` suffixes.
+    #[arg(long, value_name = "SIZE", value_parser = parse_byte_size, conflicts_with = "base64")]
+    pub split_size: Option<u32>,
+}
+
+/// Index of a flash dump split into parts by `read-flash --split-size`
+#[derive(Debug, Serialize)]
+struct SplitDumpIndex {
+    /// Flash offset the dump was read from
+    offset: u32,
+    /// Length of the whole dump in bytes
+    length: u32,
+    /// The parts, in order
+    parts: Vec<SplitDumpPart>,
+}
+
+/// A single part of a split flash dump
+#[derive(Debug, Serialize)]
+struct SplitDumpPart {
+    /// File name of the part, relative to the index
+    file: String,
+    /// Flash offset of the part
+    offset: u32,
+    /// Length of the part in bytes
+    length: u32,
+}
+
+/// Metadata describing a flash dump produced by `read-flash`
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DumpMetadata {
+    /// Flash offset the dump was read from
+    pub offset: u32,
+    /// Length of the dump in bytes
+    pub length: u32,
+    /// Chip the dump was read from
+    pub chip: Chip,
+    /// SHA256 of the dump, hex encoded
+    pub sha256: String,
+    /// File names of the parts the dump was split into with `--split-size`,
+    /// in order, relative to the metadata file
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<String>,
+}
+
+impl DumpMetadata {
+    /// Load dump metadata from a `.meta` file
+    pub fn load(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path)
+            .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+
+        toml::from_str(&data)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to parse dump metadata: {}", path.display()))
+    }
+
+    /// Ensure the given data and address match the dump described by this
+    /// metadata
+    pub fn validate(&self, address: u32, data: &[u8]) -> Result<()> {
+        if address != self.offset {
+            return Err(miette::miette!(
+                "Address {address:#x} does not match the dump offset {:#x}",
+                self.offset
+            ));
+        }
+
+        if data.len() != self.length as usize {
+            return Err(miette::miette!(
+                "Data is {} bytes long, but the dump was {} bytes long",
+                data.len(),
+                self.length
+            ));
+        }
+
+        let sha256 = encode_hex(Sha256::digest(data));
+        if sha256 != self.sha256 {
+            return Err(miette::miette!(
+                "SHA256 of the data ({sha256}) does not match the dump ({})",
+                self.sha256
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 /// Save the image to disk instead of flashing to device.
@@ -229,26 +756,102 @@ pub struct ReadFlashArgs {
 #[group(skip)]
 pub struct SaveImageArgs {
     /// Chip to create an image for.
-    #[arg(long, value_enum)]
+    #[arg(long, env = "ESPFLASH_CHIP", value_enum)]
     pub chip: Chip,
     /// File name to save the generated image to.
-    pub file: PathBuf,
+    #[arg(required_unless_present = "header_only")]
+    pub file: Option<PathBuf>,
+    /// Print the fields of the application image header and exit, without
+    /// saving the image
+    #[arg(long, conflicts_with_all = ["merge", "append"])]
+    pub header_only: bool,
     /// Boolean flag to merge binaries into single binary.
     #[arg(long)]
     pub merge: bool,
     /// Don't pad the image to the flash size.
     #[arg(long, requires = "merge")]
     pub skip_padding: bool,
+    /// Byte used to fill the gaps between segments and pad the merged image
+    #[arg(
+        long,
+        value_name = "BYTE",
+        default_value = "0xff",
+        value_parser = parse_u8,
+        requires = "merge"
+    )]
+    pub fill_byte: u8,
+    /// Additional binary to insert into the merged image at the given offset
+    ///
+    /// Can be repeated. Appended binaries must not overlap with each other or
+    /// with the generated bootloader, partition table and application.
+    #[arg(
+        long,
+        visible_alias = "append-image",
+        value_name = "ADDR=FILE",
+        value_parser = parse_append_image,
+        requires = "merge"
+    )]
+    pub append: Vec<(u32, PathBuf)>,
+    /// Output arguments for individual image files
+    #[clap(flatten)]
+    pub split_args: SplitImageArgs,
     /// Crystal frequency of the target
     #[arg(long, short = 'x')]
     pub xtal_freq: Option<XtalFrequency>,
+    /// Entry point to write to the image header, instead of the ELF's
+    #[arg(long, value_name = "ADDR", value_parser = parse_u32)]
+    pub entry: Option<u32>,
     #[clap(flatten)]
     /// Image arguments.
     pub image: ImageArgs,
 }
 
+/// Arguments controlling where and how individual image files are saved
+#[derive(Debug, Args, Default)]
+#[non_exhaustive]
+#[group(skip)]
+pub struct SplitImageArgs {
+    /// Also save the bootloader, partition table and application as individual
+    /// files in the given directory, prefixed with their offsets in flash
+    ///
+    /// Any other flashed data, e.g. extra ELF images or the `otadata`
+    /// partition, is saved as `<offset>_data.bin`.
+    #[arg(long, value_name = "DIR", requires = "merge")]
+    pub also_split: Option<PathBuf>,
+    /// Directory to save the image files to, when not merging
+    ///
+    /// Defaults to the directory of the given file.
+    #[arg(long, value_name = "DIR", conflicts_with = "merge")]
+    pub output_dir: Option<PathBuf>,
+    /// Template for the names of the image files, when not merging
+    ///
+    /// `{offset}` and `{offset:#x}` are replaced by the flash offset of the
+    /// file's contents in decimal and hexadecimal, `{index}` by the index of
+    /// the file and `{file}` by the name of the given file, without its
+    /// directory. The template must contain
+    /// the offset or the index, so that file names are unique.
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with = "merge",
+        value_parser = parse_name_template
+    )]
+    pub name_template: Option<String>,
+    /// Only save the given segments of the application image, by index
+    ///
+    /// Each segment is saved to its own file, named after its index and load
+    /// address unless `--name-template` is given.
+    #[arg(
+        long,
+        value_name = "INDICES",
+        value_delimiter = ',',
+        conflicts_with = "merge"
+    )]
+    pub only_segments: Option<Vec<usize>>,
+}
+
 /// Image arguments needed for image generation.
-#[derive(Debug, Args)]
+#[derive(Debug, Args, Clone)]
 #[non_exhaustive]
 #[group(skip)]
 pub struct ImageArgs {
@@ -261,6 +864,17 @@ pub struct ImageArgs {
     /// Skip checking whether the app descriptor is present in the image.
     #[arg(long = "ignore-app-descriptor", visible_alias ="ignore_app_descriptor",default_value_t = true, action = clap::ArgAction::SetFalse)]
     pub check_app_descriptor: bool, // todo: remove ignore_app_descriptor alias before v5
+    /// Only warn about ELF segments outside of valid flash/RAM regions, instead
+    /// of failing.
+    #[arg(long)]
+    pub lenient: bool,
+    /// Generate a reproducible image
+    ///
+    /// Clears the compile time and date in the ESP-IDF app descriptor, so that
+    /// the same sources always yield the same image. All other fields written
+    /// by espflash are already deterministic.
+    #[arg(long)]
+    pub reproducible: bool,
 }
 
 /// ESP-IDF image format arguments
@@ -271,15 +885,52 @@ pub struct IdfFormatArgs {
     /// Path to a binary ESP-IDF bootloader file
     #[arg(long, value_name = "FILE")]
     pub bootloader: Option<PathBuf>,
+    /// Bootloader to use for the chip revisions it supports (repeatable)
+    ///
+    /// When flashing without `--bootloader`, the first of these bootloaders
+    /// whose image header supports the revision of the connected chip is used
+    /// instead of the bundled one.
+    #[arg(long, value_name = "FILE", conflicts_with = "bootloader")]
+    #[serde(default)]
+    pub bootloader_variant: Vec<PathBuf>,
     /// Path to a CSV file containing partition table
     #[arg(long, value_name = "FILE")]
     pub partition_table: Option<PathBuf>,
+    /// Format of the partition table, detected from its contents if not given
+    #[arg(long, value_name = "FORMAT")]
+    pub partition_table_format: Option<PartitionTableFormat>,
     /// Partition table offset
     #[arg(long, value_name = "OFFSET", value_parser = parse_u32)]
     pub partition_table_offset: Option<u32>,
     /// Label of target app partition
     #[arg(long, value_name = "LABEL")]
     pub target_app_partition: Option<String>,
+    /// Write the application to the given OTA slot and select it for booting
+    ///
+    /// The application is written to the `ota_<SLOT>` app partition, and the
+    /// `otadata` partition is updated so that the bootloader boots it.
+    #[arg(long, value_name = "SLOT", value_parser = clap::value_parser!(u8).range(0..16), conflicts_with = "target_app_partition")]
+    pub ota_slot: Option<u8>,
+    /// ELF section describing additional data to flash alongside the
+    /// application
+    ///
+    /// The section holds a sequence of records, each made up of a
+    /// little-endian u32 flash address, a little-endian u32 payload length and
+    /// the payload, padded with zeroes to a multiple of 4 bytes. Addresses are
+    /// relocated by `--flash-base` and must be aligned to a flash sector, and
+    /// payloads are padded to whole 4 KiB flash sectors.
+    #[arg(long, value_name = "SECTION")]
+    pub extra_from_section: Option<String>,
+    /// Additional ELF image to flash at the given address, e.g. coprocessor
+    /// firmware (repeatable)
+    ///
+    /// The loadable sections of the ELF file are flattened into a binary,
+    /// padded to whole 4 KiB flash sectors, which must not overlap the
+    /// application or any other flashed data. The address must be aligned to
+    /// a flash sector.
+    #[arg(long, value_name = "FILE@ADDR", value_parser = parse_extra_elf)]
+    #[serde(default)]
+    pub extra_elf: Vec<(PathBuf, u32)>,
 }
 
 /// Arguments for connection and monitoring
@@ -292,6 +943,19 @@ pub struct MonitorArgs {
     /// Monitoring arguments
     #[clap(flatten)]
     monitor_args: MonitorConfigArgs,
+    /// Print the name and version of the application on the device before
+    /// monitoring
+    ///
+    /// The application descriptor is read from the app partition selected by
+    /// the `otadata` partition, or from the factory app partition otherwise.
+    /// Not available with `--before no-reset-no-sync`.
+    #[arg(long)]
+    show_app_desc: bool,
+    /// Offset of the partition table on the device, for `--show-app-desc`
+    ///
+    /// Defaults to the offset in the configuration file, or 0x8000.
+    #[arg(long, value_name = "OFFSET", value_parser = parse_u32, requires = "show_app_desc")]
+    partition_table_offset: Option<u32>,
 }
 
 /// Open the serial monitor without flashing
@@ -332,6 +996,88 @@ pub struct MonitorConfigArgs {
     /// Try to resolve all addresses, even well-known misleading ones
     #[arg(long)]
     pub all_addresses: bool,
+    /// Reset the device once the monitor is ready to capture its output
+    #[arg(skip)]
+    reset_on_start: bool,
+    /// Line ending convention of the device, used to translate received line
+    /// endings for display and to terminate typed input.
+    ///
+    /// By default received line endings are normalized to CRLF and Enter sends
+    /// CR.
+    #[arg(long, value_name = "EOL")]
+    pub eol: Option<LineEnding>,
+    /// Hide application log lines less severe than the given level
+    ///
+    /// Lines are matched on the ESP-IDF `X (timestamp) TAG:` prefix; anything
+    /// not in that format is always shown.
+    #[arg(long, value_name = "LEVEL", ignore_case = true)]
+    pub log_level: Option<LogLevel>,
+    /// Also write the monitor output to a timestamped log file in the
+    /// directory of the ELF image
+    #[arg(long)]
+    pub append_log_to_elf_dir: bool,
+}
+
+/// Which of the flashed data to verify after flashing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[non_exhaustive]
+pub enum VerifyMode {
+    /// Verify everything that was written
+    All,
+    /// Only verify the application
+    App,
+    /// Don't verify anything
+    None,
+    /// Read back the application and check it against its appended SHA256
+    /// digest, like the ROM does when booting it
+    Sha256,
+}
+
+impl FlashArgs {
+    /// The verification mode selected by `--verify` and `--no-verify`
+    pub fn verify_mode(&self) -> VerifyMode {
+        match self.verify {
+            Some(mode) => mode,
+            None if self.no_verify => VerifyMode::None,
+            None => VerifyMode::All,
+        }
+    }
+
+    /// Whether the written regions are verified with MD5 checksums computed
+    /// by the device
+    pub fn verify_md5(&self) -> bool {
+        matches!(self.verify_mode(), VerifyMode::All | VerifyMode::App)
+    }
+}
+
+/// Checksum algorithm to use when checksumming a flash region
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[non_exhaustive]
+pub enum ChecksumAlgo {
+    /// MD5, computed by the device
+    Md5,
+    /// SHA-256, computed by the host from the region read back
+    Sha256,
+    /// CRC-32 (IEEE), computed by the host from the region read back
+    Crc32,
+}
+
+/// Arguments for checksum calculation
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct ChecksumArgs {
+    /// Start address
+    #[clap(value_parser = parse_u32)]
+    address: u32,
+    /// Size of the region to check
+    #[clap(value_parser = parse_u32)]
+    size: u32,
+    /// Checksum algorithm to use
+    #[arg(long, value_enum, default_value = "md5")]
+    algo: ChecksumAlgo,
+    /// Connection configuration
+    #[clap(flatten)]
+    connect_args: ConnectArgs,
 }
 
 /// Arguments for MD5 checksum calculation
@@ -375,6 +1121,18 @@ pub struct WriteBinArgs {
     /// Connection configuration
     #[clap(flatten)]
     connect_args: ConnectArgs,
+    /// Size of each block of data sent while writing flash
+    ///
+    /// Must be a power of two, at most 0x4000 when using the flasher stub and
+    /// 0x400 otherwise.
+    #[arg(long, value_name = "BYTES", value_parser = parse_u32)]
+    pub flash_block_size: Option<u32>,
+    /// Step down the baud rate and retry on communication errors while writing
+    #[arg(long)]
+    pub auto_baud_backoff: bool,
+    /// Validate the file against a `.meta` file written by `read-flash --meta`
+    #[arg(long, value_name = "META")]
+    pub from_dump: Option<PathBuf>,
     /// Open a serial monitor after writing
     #[arg(short = 'M', long)]
     pub monitor: bool,
@@ -383,6 +1141,59 @@ pub struct WriteBinArgs {
     pub monitor_args: MonitorConfigArgs,
 }
 
+/// Check the connection to a device by round-tripping a flash region
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct SelftestArgs {
+    /// Connection configuration
+    #[clap(flatten)]
+    connect_args: ConnectArgs,
+    /// Start address of the scratch region
+    ///
+    /// Defaults to the end of flash. Must be multiple of 4096(0x1000)
+    #[arg(long, value_parser = parse_u32)]
+    pub address: Option<u32>,
+    /// Size of the scratch region
+    ///
+    /// Must be multiple of 4096(0x1000)
+    #[arg(long, default_value = "0x10000", value_parser = parse_u32)]
+    pub size: u32,
+    /// Erase the region afterwards, instead of restoring its previous
+    /// contents
+    ///
+    /// Whatever was stored in the region is lost.
+    #[arg(long)]
+    pub erase: bool,
+}
+
+/// Print version and build information
+#[derive(Debug, Args)]
+#[non_exhaustive]
+pub struct VersionArgs {
+    /// Print the information as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Version and build information, including the bundled assets
+#[derive(Debug, Serialize)]
+struct VersionInfo {
+    name: String,
+    version: String,
+    git_commit: Option<String>,
+    chips: Vec<ChipAssets>,
+}
+
+/// Versions of the assets bundled for a chip
+#[derive(Debug, Serialize)]
+struct ChipAssets {
+    chip: Chip,
+    /// ESP-IDF version the default bootloader was built from
+    bootloader: Option<String>,
+    /// The flasher stubs carry no version, so they are identified by a digest
+    stub_sha256: String,
+}
+
 /// Parses a bootloader file from a path
 pub fn parse_bootloader(path: &Path) -> Result<Vec<u8>, Error> {
     // If the '--bootloader' option is provided, load the binary file at the
@@ -392,6 +1203,27 @@ pub fn parse_bootloader(path: &Path) -> Result<Vec<u8>, Error> {
         .map_err(|e| Error::FileOpenError(path.display().to_string(), e))
 }
 
+/// Parses a file name template for individual image files
+pub fn parse_name_template(input: &str) -> Result<String, String> {
+    if !["{offset}", "{offset:#x}", "{index}"]
+        .iter()
+        .any(|field| input.contains(field))
+    {
+        return Err("the template must contain `{offset}`, `{offset:#x}` or `{index}`".into());
+    }
+
+    Ok(input.to_string())
+}
+
+/// Fills in the fields of a file name template
+fn render_name_template(template: &str, offset: u32, index: usize, file: &str) -> String {
+    template
+        .replace("{offset:#x}", &format!("{offset:#x}"))
+        .replace("{offset}", &offset.to_string())
+        .replace("{index}", &index.to_string())
+        .replace("{file}", file)
+}
+
 /// Parses an integer, in base-10 or hexadecimal format, into a [u32]
 pub fn parse_u32(input: &str) -> Result<u32, ParseIntError> {
     let input: &str = &input.replace('_', "");
@@ -404,6 +1236,169 @@ pub fn parse_u32(input: &str) -> Result<u32, ParseIntError> {
     u32::from_str_radix(s, radix)
 }
 
+/// Parses a size in bytes, in base-10 or hexadecimal format, optionally
+/// followed by a `K`/`KiB` or `M`/`MiB` suffix
+pub fn parse_byte_size(input: &str) -> Result<u32, String> {
+    let lower = input.to_ascii_lowercase();
+    let (number, multiplier) = [("kib", 1024), ("k", 1024), ("mib", 1 << 20), ("m", 1 << 20)]
+        .into_iter()
+        .find_map(|(suffix, multiplier)| {
+            lower
+                .strip_suffix(suffix)
+                .map(|number| (number.trim(), multiplier))
+        })
+        .unwrap_or((lower.as_str(), 1));
+
+    let size = parse_u32(number)
+        .map_err(|e| e.to_string())?
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("{input} is too large"))?;
+    if size == 0 {
+        return Err("The size must not be zero".to_string());
+    }
+
+    Ok(size)
+}
+
+/// Parses an integer, in base-10 or hexadecimal format, into a [u8]
+pub fn parse_u8(input: &str) -> Result<u8, String> {
+    let value = parse_u32(input).map_err(|e| e.to_string())?;
+
+    u8::try_from(value).map_err(|_| format!("{input} does not fit into a single byte"))
+}
+
+/// Parses an `<addr>=<file>` pair, as used by `save-image --append`
+pub fn parse_append_image(input: &str) -> Result<(u32, PathBuf), String> {
+    let (addr, file) = input
+        .split_once('=')
+        .ok_or_else(|| format!("expected `<addr>=<file>`, found `{input}`"))?;
+    let addr = parse_u32(addr).map_err(|e| format!("invalid address `{addr}`: {e}"))?;
+
+    Ok((addr, PathBuf::from(file)))
+}
+
+/// Parses a `<file>@<addr>` pair, as used by `--extra-elf`
+pub fn parse_extra_elf(input: &str) -> Result<(PathBuf, u32), String> {
+    let (file, addr) = input
+        .rsplit_once('@')
+        .ok_or_else(|| format!("expected `<file>@<addr>`, found `{input}`"))?;
+    let addr = parse_u32(addr).map_err(|e| format!("invalid address `{addr}`: {e}"))?;
+
+    Ok((PathBuf::from(file), addr))
+}
+
+/// Parse a `VID:PID` pair of hexadecimal USB IDs
+pub fn parse_usb_filter(input: &str) -> Result<UsbDevice, String> {
+    let (vid, pid) = input
+        .split_once(':')
+        .ok_or_else(|| format!("expected `<vid>:<pid>`, found `{input}`"))?;
+    let parse_id = |id: &str| {
+        u16::from_str_radix(id.trim_start_matches("0x"), 16)
+            .map_err(|e| format!("invalid USB ID `{id}`: {e}"))
+    };
+
+    Ok(UsbDevice {
+        vid: parse_id(vid)?,
+        pid: parse_id(pid)?,
+    })
+}
+
+/// Parse a hex byte pattern in which `??` matches any byte
+///
+/// Whitespace between bytes is ignored.
+pub fn parse_byte_pattern(input: &str) -> Result<BytePattern, String> {
+    let digits = input
+        .trim_start_matches("0x")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    if digits.is_empty() || digits.len() % 2 != 0 {
+        return Err(format!(
+            "expected a non-empty sequence of hex bytes, found `{input}`"
+        ));
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| match pair {
+            ['?', '?'] => Ok(None),
+            [hi, lo] => u8::from_str_radix(&format!("{hi}{lo}"), 16)
+                .map(Some)
+                .map_err(|e| format!("invalid byte `{hi}{lo}`: {e}")),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Parse a sequence of hex bytes, e.g. `0xdeadbeef` or `de ad be ef`
+pub fn parse_hex_bytes(input: &str) -> Result<Vec<u8>, String> {
+    parse_byte_pattern(input)?
+        .into_iter()
+        .map(|byte| byte.ok_or_else(|| format!("wildcards are not allowed, found `{input}`")))
+        .collect()
+}
+
+/// Parse a `<start>:<end>` address range
+pub fn parse_range(input: &str) -> Result<(u32, u32), String> {
+    let (start, end) = input
+        .split_once(':')
+        .ok_or_else(|| format!("expected `<start>:<end>`, found `{input}`"))?;
+    let start = parse_u32(start).map_err(|e| format!("invalid address `{start}`: {e}"))?;
+    let end = parse_u32(end).map_err(|e| format!("invalid address `{end}`: {e}"))?;
+    if end <= start {
+        return Err(format!(
+            "the end of the range `{input}` must be after its start"
+        ));
+    }
+
+    Ok((start, end))
+}
+
+/// Exit code used when the operation is aborted by `--timeout`
+///
+/// This is the same code as used by the `timeout` utility.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Time the operation is given to stop and reset the device once the timeout
+/// has been exceeded, before the process is exited regardless
+const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Set once the timeout of the watchdog started by [start_timeout_watchdog]
+/// has been exceeded
+static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+/// Abort the operation if it is still running after `timeout`
+///
+/// Flash reads and writes reporting their progress through [EspflashProgress]
+/// or [DeviceProgress] are cancelled, which resets the device, after which the
+/// caller is expected to exit with [TIMEOUT_EXIT_CODE], see [timed_out]. If
+/// the operation does not stop within a few seconds, e.g. because it is
+/// waiting for the device, the process is exited with [TIMEOUT_EXIT_CODE]
+/// regardless, without resetting the device.
+pub fn start_timeout_watchdog(timeout: Duration) {
+    std::thread::spawn(move || {
+        std::thread::sleep(timeout);
+
+        error!(
+            "Operation did not complete within {}s, aborting",
+            timeout.as_secs()
+        );
+        TIMED_OUT.store(true, Ordering::SeqCst);
+
+        std::thread::sleep(TIMEOUT_GRACE_PERIOD);
+
+        let _ = crossterm::terminal::disable_raw_mode();
+        error!("Operation did not stop, exiting without resetting the device");
+        std::process::exit(TIMEOUT_EXIT_CODE);
+    });
+}
+
+/// Whether the timeout of the watchdog started by [start_timeout_watchdog] has
+/// been exceeded
+pub fn timed_out() -> bool {
+    TIMED_OUT.load(Ordering::SeqCst)
+}
+
 /// Select a serial port and establish a connection with a target device
 pub fn connect(
     args: &ConnectArgs,
@@ -424,32 +1419,151 @@ pub fn connect(
 
     // Attempt to open the serial port and set its initial baud rate.
     info!("Serial port: '{}'", port_info.port_name);
+
+    if let Some(command) = &args.enter_bootloader_cmd {
+        request_bootloader(&port_info, command, args.enter_bootloader_baud)?;
+    }
+
     info!("Connecting...");
 
-    let serial_port = serialport::new(&port_info.port_name, 115_200)
-        .flow_control(FlowControl::None)
-        .open_native()
-        .map_err(Error::from)
-        .wrap_err_with(|| format!("Failed to open serial port {}", port_info.port_name))?;
+    let connect = |connection, use_stub| match args.chip {
+        Some(chip) if args.skip_autodetect => Flasher::connect_without_autodetect(
+            connection,
+            use_stub,
+            !no_verify,
+            !no_skip,
+            chip,
+            // Replaced by `--flash-size` later on, for the commands which take it
+            config.project_config.flash.size.unwrap_or_default(),
+            args.baud.or(config.project_config.baudrate),
+        ),
+        _ => Flasher::connect(
+            connection,
+            use_stub,
+            !no_verify,
+            !no_skip,
+            args.chip,
+            args.baud.or(config.project_config.baudrate),
+        ),
+    };
 
-    // NOTE: since `serial_port_info` filters out all PCI Port and Bluetooth
-    //       serial ports, we can just pretend these types don't exist here.
-    let port_info = match port_info.port_type {
-        SerialPortType::UsbPort(info) => info,
-        SerialPortType::PciPort | SerialPortType::Unknown => {
-            debug!("Matched `SerialPortType::PciPort or ::Unknown`");
-            UsbPortInfo {
-                vid: 0,
-                pid: 0,
-                serial_number: None,
-                manufacturer: None,
-                product: None,
-            }
+    let connection = open_connection(args, config, &port_info)?;
+    let result = connect(connection, args.use_stub(config));
+
+    let mut flasher = match result {
+        Err(Error::StubLoadFailed(err)) if args.stub_fallback => {
+            warn!("Failed to load the flash stub: {err}");
+            warn!(
+                "Falling back to the ROM loader, flashing will be slower and some features will be unavailable"
+            );
+
+            // The serial port was dropped along with the failed connection, so it has
+            // to be reopened before retrying.
+            let connection = open_connection(args, config, &port_info)?;
+            connect(connection, false)?
+        }
+        result => result?,
+    };
+
+    if args.baud.is_none()
+        && config.project_config.baudrate.is_none()
+        && args.before != ResetBeforeOperation::NoResetNoSync
+    {
+        let vid = match &port_info.port_type {
+            SerialPortType::UsbPort(usb) => Some(usb.vid),
+            _ => None,
+        };
+        let usb_serial_jtag = flasher.connection().is_using_usb_serial_jtag();
+
+        match default_baud(vid, usb_serial_jtag) {
+            Some(baud) => {
+                debug!("Using a default baud rate of {baud} for this serial adapter");
+                flasher.change_baud(baud)?;
+            }
+            None => debug!(
+                "Using the default baud rate of {}",
+                flasher.chip().params().default_baud
+            ),
+        }
+    }
+
+    Ok(flasher)
+}
+
+/// Pick a baud rate to use when none was configured, based on the serial
+/// adapter the device is connected through
+///
+/// Native USB ignores the baud rate, and unknown adapters are left at the
+/// baud rate of the ROM loader, as they may not support higher ones.
+fn default_baud(vid: Option<u16>, usb_serial_jtag: bool) -> Option<u32> {
+    if usb_serial_jtag {
+        return None;
+    }
+
+    match vid? {
+        // FTDI
+        0x0403 => Some(921_600),
+        // Silicon Labs CP210x
+        0x10c4 => Some(460_800),
+        // WCH CH34x
+        0x1a86 => Some(460_800),
+        _ => None,
+    }
+}
+
+/// Ask the running application to reboot into the ROM download mode, by
+/// sending it `command` at the given baud rate
+fn request_bootloader(port_info: &SerialPortInfo, command: &[u8], baud: u32) -> Result<()> {
+    // Time for the application to reboot before syncing with the ROM loader
+    const REBOOT_DELAY: Duration = Duration::from_millis(200);
+
+    debug!("Sending {} bytes to enter the bootloader", command.len());
+
+    let mut serial_port = serialport::new(&port_info.port_name, baud)
+        .flow_control(FlowControl::None)
+        .open_native()
+        .map_err(Error::from)
+        .wrap_err_with(|| format!("Failed to open serial port {}", port_info.port_name))?;
+    serial_port.write_all(command).into_diagnostic()?;
+    serial_port.flush().into_diagnostic()?;
+    drop(serial_port);
+
+    std::thread::sleep(REBOOT_DELAY);
+
+    Ok(())
+}
+
+/// Open the serial port described by `port_info` and set up a connection to
+/// the target device on it
+fn open_connection(
+    args: &ConnectArgs,
+    config: &Config,
+    port_info: &SerialPortInfo,
+) -> Result<Connection> {
+    let serial_port = serialport::new(&port_info.port_name, 115_200)
+        .flow_control(FlowControl::None)
+        .open_native()
+        .map_err(Error::from)
+        .wrap_err_with(|| format!("Failed to open serial port {}", port_info.port_name))?;
+
+    // NOTE: since `serial_port_info` filters out all PCI Port and Bluetooth
+    //       serial ports, we can just pretend these types don't exist here.
+    let port_info = match &port_info.port_type {
+        SerialPortType::UsbPort(info) => info.clone(),
+        SerialPortType::PciPort | SerialPortType::Unknown => {
+            debug!("Matched `SerialPortType::PciPort or ::Unknown`");
+            UsbPortInfo {
+                vid: 0,
+                pid: 0,
+                serial_number: None,
+                manufacturer: None,
+                product: None,
+            }
         }
         _ => unreachable!(),
     };
 
-    let connection = Connection::new(
+    let mut connection = Connection::new(
         *Box::new(serial_port),
         port_info,
         args.after,
@@ -458,20 +1572,43 @@ pub fn connect(
             .or(config.project_config.baudrate)
             .unwrap_or(115_200),
     );
-    Ok(Flasher::connect(
-        connection,
-        !args.no_stub,
-        !no_verify,
-        !no_skip,
-        args.chip,
-        args.baud.or(config.project_config.baudrate),
-    )?)
+    connection.set_trace_io(args.trace_io);
+    connection.set_op_timeout(args.op_timeout.map(Duration::from_secs));
+    connection.set_adapter_init(args.adapter_init.clone());
+    if let Some(sequence) = args.reset_sequence.clone() {
+        let mut strategy = CustomReset::new(move |serial_port| sequence.run(serial_port));
+        if let Some(sequence) = args.hard_reset_sequence.clone() {
+            strategy = strategy.with_hard_reset(move |serial_port| sequence.run(serial_port));
+        }
+        connection.set_reset_strategy(strategy);
+    }
+    connection.set_read_only(args.read_only);
+    connection.set_xtal_frequency(args.xtal_freq);
+    if let Some(packet_size) = args.usb_align {
+        let packet_size = match packet_size {
+            0 => connection.usb_packet_size(),
+            size => size,
+        };
+        debug!("Aligning writes to {packet_size} byte USB packets");
+        connection.set_usb_align(Some(packet_size));
+    }
+
+    Ok(connection)
 }
 
 /// Connect to a target device and print information about its chip
-pub fn board_info(args: &ConnectArgs, config: &Config) -> Result<()> {
-    let mut flasher = connect(args, config, true, true)?;
-    print_board_info(&mut flasher)?;
+pub fn board_info(args: &BoardInfoArgs, config: &Config) -> Result<()> {
+    if args.partitions && !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired.into());
+    }
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+    let info = print_board_info(&mut flasher)?;
+
+    if let Some(path) = &args.append_csv {
+        let port = flasher.connection().port_name().unwrap_or_default();
+        append_board_info_csv(path, &port, &info)?;
+    }
 
     let chip = flasher.chip();
     if chip != Chip::Esp32 {
@@ -481,7 +1618,312 @@ pub fn board_info(args: &ConnectArgs, config: &Config) -> Result<()> {
         println!("Security features: None");
     }
 
-    flasher.connection().reset_after(!args.no_stub, chip)?;
+    if args.partitions {
+        let data = flasher.read_flash_data(
+            args.partition_table_offset,
+            FLASH_SECTOR_SIZE as u32,
+            READ_BLOCK_SIZE,
+            READ_MAX_IN_FLIGHT,
+        )?;
+
+        match parse_device_table(&data) {
+            Ok(Some(table)) => pretty_print(table),
+            Ok(None) => warn!(
+                "No partition table found at {:#x}",
+                args.partition_table_offset
+            ),
+            Err(err) => warn!("{err}"),
+        }
+    }
+
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Append a row describing the device to a CSV file, writing the header first
+/// if the file is new
+fn append_board_info_csv(path: &Path, port: &str, info: &DeviceInfo) -> Result<()> {
+    const HEADER: &str = "timestamp,port,chip,revision,mac_address,flash_size\n";
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .into_diagnostic()?
+        .as_secs();
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+
+    // Build the whole row up front, so that it is appended with a single write
+    // when several stations log to the same file.
+    let mut row = String::new();
+    if file.metadata().into_diagnostic()?.len() == 0 {
+        row.push_str(HEADER);
+    }
+    row.push_str(&board_info_csv_row(timestamp, port, info));
+
+    file.write_all(row.as_bytes())
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Failed to write to {}", path.display()))?;
+    info!("Board information appended to {}", path.display());
+
+    Ok(())
+}
+
+/// Format a CSV row describing the device, quoting fields as needed
+fn board_info_csv_row(timestamp: u64, port: &str, info: &DeviceInfo) -> String {
+    let revision = info
+        .revision
+        .map(|(major, minor)| format!("v{major}.{minor}"))
+        .unwrap_or_default();
+    let fields = [
+        timestamp.to_string(),
+        port.to_string(),
+        info.chip.to_string(),
+        revision,
+        info.mac_address.clone().unwrap_or_default(),
+        info.flash_size.to_string(),
+    ];
+
+    let mut row = fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+
+    row
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break, as
+/// described in RFC 4180
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Connect to a target device and print the reason for its most recent reset
+pub fn reset_reason(args: &ConnectArgs, config: &Config) -> Result<()> {
+    if args.before != ResetBeforeOperation::NoReset {
+        warn!(
+            "Resetting the device into download mode overwrites the previous reset reason, consider using `--before no-reset`"
+        );
+    }
+
+    let mut flasher = connect(args, config, true, true)?;
+
+    let chip = flasher.chip();
+    let reason = chip.reset_reason(flasher.connection())?;
+    println!("Reset reason:      {reason}");
+
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Print the JEDEC ID and, if supported, the unique ID of the flash chip
+pub fn flash_id(args: &ConnectArgs, config: &Config) -> Result<()> {
+    let mut flasher = connect(args, config, true, true)?;
+
+    let jedec_id = flasher.flash_jedec_id()?;
+    println!("Manufacturer:      {:#04x}", jedec_id & 0xff);
+    println!("Device:            {:#06x}", (jedec_id >> 8) & 0xffff);
+
+    match flasher.flash_unique_id()? {
+        Some(unique_id) => println!("Unique ID:         {unique_id:016x}"),
+        None => println!("Unique ID:         Not supported by the flash chip"),
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Print the decoded status registers of the target device's flash chip
+pub fn flash_status(args: &ConnectArgs, config: &Config) -> Result<()> {
+    let mut flasher = connect(args, config, true, true)?;
+    let status = flasher.flash_status()?;
+
+    let second = match status.layout {
+        Some(StatusLayout::Macronix) => "CR ",
+        _ => "SR2",
+    };
+    println!(
+        "Status registers:  SR1 {:#04x}, {second} {:#04x}",
+        status.raw & 0xff,
+        status.raw >> 8
+    );
+
+    if status.layout.is_none() {
+        println!("Layout:            Unknown flash chip, showing raw values only");
+    } else {
+        let flag = |bit: Option<bool>| match bit {
+            Some(true) => "set",
+            Some(false) => "clear",
+            None => "n/a",
+        };
+
+        if let Some(bp) = status.block_protect() {
+            println!("Block protect:     {bp:#05b}");
+        }
+        println!("Top/bottom (TB):   {}", flag(status.top_bottom()));
+        println!("Complement (CMP):  {}", flag(status.complement()));
+        println!("Quad enable (QE):  {}", flag(status.quad_enable()));
+        println!(
+            "SR protect (SRP):  {}",
+            flag(status.status_register_protect())
+        );
+
+        if status.is_protected() == Some(true) {
+            warn!("Part of the flash is write protected, see `--unlock-flash`");
+        }
+        if status.status_register_protect() == Some(true) && status.quad_enable() == Some(false) {
+            warn!(
+                "The status register is locked while /WP is low, check how the /WP pin is wired if the protection cannot be cleared"
+            );
+        }
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Write a value or a binary file to the target device's memory, e.g. RTC
+/// memory
+pub fn write_mem(args: &WriteMemArgs, config: &Config) -> Result<()> {
+    let data = match (&args.file, args.value) {
+        (Some(path), _) => {
+            fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?
+        }
+        (None, Some(value)) => value.to_le_bytes().to_vec(),
+        (None, None) => unreachable!("clap requires either `--value` or `--file`"),
+    };
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+    flasher.write_memory(args.addr, &data)?;
+    info!(
+        "Wrote {} bytes to memory at {:#010x}",
+        data.len(),
+        args.addr
+    );
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// The value of a single eFuse field, as printed by `read-efuse`
+#[derive(Debug, Serialize)]
+struct EfuseFieldValue {
+    name: String,
+    block: u32,
+    bit_start: u32,
+    bit_count: u32,
+    /// The value as an integer, for fields of up to 64 bits
+    value: Option<u64>,
+    /// The little-endian bytes of the field, hex encoded
+    raw: String,
+}
+
+/// Connect to a target device and print the value of a single eFuse field
+pub fn read_efuse(args: &ReadEfuseArgs, config: &Config) -> Result<()> {
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+
+    let chip = flasher.chip();
+
+    let Some(name) = &args.field else {
+        read_efuse_calibration(&mut flasher, args.json)?;
+
+        let use_stub = flasher.is_using_stub();
+        return Ok(flasher.connection().reset_after(use_stub, chip)?);
+    };
+    let field = chip.efuse_field(name)?;
+
+    // No eFuse field is wider than 256 bits.
+    let bytes = chip.read_efuse_le::<[u8; 32]>(flasher.connection(), field.clone())?;
+    let bytes = &bytes[..field.bit_count.div_ceil(8) as usize];
+
+    let value = (field.bit_count <= 64).then(|| {
+        let mut word = [0u8; 8];
+        word[..bytes.len()].copy_from_slice(bytes);
+        u64::from_le_bytes(word)
+    });
+
+    let field_value = EfuseFieldValue {
+        name: name.to_uppercase(),
+        block: field.block,
+        bit_start: field.bit_start,
+        bit_count: field.bit_count,
+        value,
+        raw: encode_hex(bytes),
+    };
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&field_value).into_diagnostic()?
+        );
+    } else {
+        match field_value.value {
+            Some(value) => println!("{} = {value} ({value:#x})", field_value.name),
+            None => println!("{} = {}", field_value.name, field_value.raw),
+        }
+    }
+
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Print the ADC and temperature sensor calibration fields of the device
+fn read_efuse_calibration(flasher: &mut Flasher, json: bool) -> Result<()> {
+    let chip = flasher.chip();
+
+    let raw = efuse::calibration::fields(chip)
+        .map(|(name, field)| {
+            let value = chip.read_efuse_le::<u64>(flasher.connection(), field.clone())?;
+            Ok((*name, value))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if raw.is_empty() {
+        warn!("No calibration eFuse fields are known for {chip}");
+    }
+
+    let values = efuse::calibration::decode(chip, &raw);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&values).into_diagnostic()?
+        );
+        return Ok(());
+    }
+
+    for value in values {
+        match (value.value, value.unit) {
+            (Some(decoded), Some(unit)) => {
+                println!("{} = {} ({decoded} {unit})", value.name, value.raw)
+            }
+            _ => println!("{} = {}", value.name, value.raw),
+        }
+    }
 
     Ok(())
 }
@@ -494,13 +1936,76 @@ pub fn checksum_md5(args: &ChecksumMd5Args, config: &Config) -> Result<()> {
     println!("0x{checksum:x}");
 
     let chip = flasher.chip();
-    flasher
-        .connection()
-        .reset_after(!args.connect_args.no_stub, chip)?;
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Connect to a target device and calculate the checksum of the given region
+/// using the given algorithm
+///
+/// MD5 is computed by the device, the other algorithms are computed by the
+/// host after reading the region back.
+pub fn checksum(args: &ChecksumArgs, config: &Config) -> Result<()> {
+    if args.algo != ChecksumAlgo::Md5 && !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired).into_diagnostic();
+    }
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+
+    // The stub may have failed to load, with `--stub-fallback`
+    if args.algo != ChecksumAlgo::Md5 && !flasher.is_using_stub() {
+        return Err(Error::StubRequired.into());
+    }
+
+    let checksum = match args.algo {
+        ChecksumAlgo::Md5 => {
+            info!("MD5 computed by the device");
+            format!("0x{:x}", flasher.checksum_md5(args.address, args.size)?)
+        }
+        algo => {
+            let data = flasher.read_flash_data(
+                args.address,
+                args.size,
+                READ_BLOCK_SIZE,
+                READ_MAX_IN_FLIGHT,
+            )?;
+            if algo == ChecksumAlgo::Sha256 {
+                info!("SHA-256 computed by the host");
+                format!("0x{}", encode_hex(Sha256::digest(&data)))
+            } else {
+                info!("CRC-32 computed by the host");
+                format!("0x{:08x}", crc32(&data))
+            }
+        }
+    };
+    println!("{checksum}");
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
 
     Ok(())
 }
 
+/// Compute the CRC-32 (IEEE 802.3) of the given data
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
 /// List the available serial ports.
 pub fn list_ports(args: &ListPortsArgs, config: &PortConfig) -> Result<()> {
     let mut ports: Vec<SerialPortInfo> = serial::detect_usb_serial_ports(true)?
@@ -584,6 +2089,57 @@ pub fn completions(args: &CompletionsArgs, app: &mut clap::Command, bin_name: &s
     Ok(())
 }
 
+/// Print version and build information
+///
+/// The git commit is only available when the `ESPFLASH_GIT_COMMIT` environment
+/// variable was set at build time.
+pub fn version(args: &VersionArgs, name: &str, version: &str) -> Result<()> {
+    let chips = Chip::all()
+        .iter()
+        .map(|&chip| {
+            let stub = FlashStub::get(chip);
+            let mut hasher = Sha256::new();
+            hasher.update(stub.text().1);
+            hasher.update(stub.data().1);
+
+            ChipAssets {
+                chip,
+                bootloader: default_bootloader_version(chip, chip.default_xtal_frequency()),
+                stub_sha256: encode_hex(hasher.finalize()),
+            }
+        })
+        .collect();
+
+    let info = VersionInfo {
+        name: name.to_string(),
+        version: version.to_string(),
+        git_commit: option_env!("ESPFLASH_GIT_COMMIT").map(String::from),
+        chips,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&info).into_diagnostic()?);
+        return Ok(());
+    }
+
+    match &info.git_commit {
+        Some(commit) => println!("{} {} ({commit})", info.name, info.version),
+        None => println!("{} {}", info.name, info.version),
+    }
+    println!();
+    println!("{:<10} {:<26} Stub (SHA-256)", "Chip", "Bootloader");
+    for assets in &info.chips {
+        println!(
+            "{:<10} {:<26} {}",
+            assets.chip.to_string(),
+            assets.bootloader.as_deref().unwrap_or("unknown"),
+            &assets.stub_sha256[..16]
+        );
+    }
+
+    Ok(())
+}
+
 /// Parses chip revision from string to major * 100 + minor format
 pub fn parse_chip_rev(chip_rev: &str) -> Result<u16> {
     let mut split = chip_rev.split('.');
@@ -625,7 +2181,21 @@ pub fn print_board_info(flasher: &mut Flasher) -> Result<DeviceInfo> {
         println!();
     }
 
-    println!("Crystal frequency: {}", info.crystal_frequency);
+    match info.uart_clock {
+        Some(clock) if xtal_is_ambiguous(info.crystal_frequency, clock) => println!(
+            "Crystal frequency: unknown (measured {:.1} MHz, assuming {})",
+            clock as f32 / 1_000_000.0,
+            info.crystal_frequency
+        ),
+        _ => println!("Crystal frequency: {}", info.crystal_frequency),
+    }
+    if let Some(clock) = info.uart_clock {
+        println!(
+            "APB clock:         {:.1} MHz (measured)",
+            clock as f32 / 1_000_000.0
+        );
+    }
+    println!("Baud rate:         {}", info.baud_rate);
     println!("Flash size:        {}", info.flash_size);
     println!("Features:          {}", info.features.join(", "));
 
@@ -636,8 +2206,29 @@ pub fn print_board_info(flasher: &mut Flasher) -> Result<DeviceInfo> {
     Ok(info)
 }
 
+/// Whether a measured UART clock is too far off the detected crystal
+/// frequency to trust the detection
+fn xtal_is_ambiguous(xtal: XtalFrequency, uart_clock: u32) -> bool {
+    let expected = xtal.mhz() * 1_000_000;
+
+    uart_clock.abs_diff(expected) > expected / 10
+}
+
 /// Open a serial monitor
+///
+/// With `--before no-reset-no-sync` the monitor attaches to the running
+/// device without connecting to the bootloader or resetting it.
 pub fn serial_monitor(args: MonitorArgs, config: &Config) -> Result<()> {
+    if args.connect_args.before == ResetBeforeOperation::NoResetNoSync {
+        if args.show_app_desc {
+            return Err(miette::miette!(
+                "`--show-app-desc` requires connecting to the bootloader, which `--before no-reset-no-sync` skips"
+            ));
+        }
+
+        return attach_monitor(args, config);
+    }
+
     let mut flasher = connect(&args.connect_args, config, true, true)?;
     let pid = flasher.connection().usb_pid();
 
@@ -656,15 +2247,7 @@ pub fn serial_monitor(args: MonitorArgs, config: &Config) -> Result<()> {
     ensure_chip_compatibility(chip, firmware_elf.as_deref())?;
 
     let mut monitor_args = args.monitor_args;
-
-    // The 26MHz ESP32-C2's need to be treated as a special case.
-    if chip == Chip::Esp32c2
-        && chip.xtal_frequency(flasher.connection())? == XtalFrequency::_26Mhz
-        && monitor_args.monitor_baud == 115_200
-    {
-        // 115_200 * 26 MHz / 40 MHz = 74_880
-        monitor_args.monitor_baud = 74_880;
-    }
+    adjust_monitor_baud(chip, dev_info.crystal_frequency, &mut monitor_args);
 
     let mut elfs: Vec<&[u8]> = Vec::new();
     if let Some(firmware_elf) = firmware_elf.as_ref() {
@@ -680,6 +2263,22 @@ pub fn serial_monitor(args: MonitorArgs, config: &Config) -> Result<()> {
         elfs.push(rom_elf.as_ref())
     }
 
+    if args.show_app_desc {
+        if flasher.is_using_stub() {
+            let table_offset = args
+                .partition_table_offset
+                .or(config.project_config.idf_format_args.partition_table_offset)
+                .unwrap_or(DEFAULT_PARTITION_TABLE_OFFSET);
+            match read_running_app_info(&mut flasher, table_offset) {
+                Ok(Some((partition, info))) => print_app_info(&partition, &info),
+                Ok(None) => warn!("No application found on the device"),
+                Err(err) => warn!("Failed to read the application descriptor: {err}"),
+            }
+        } else {
+            warn!("Reading the application descriptor requires the flasher stub");
+        }
+    }
+
     monitor(
         flasher.into(),
         elfs,
@@ -689,33 +2288,279 @@ pub fn serial_monitor(args: MonitorArgs, config: &Config) -> Result<()> {
     )
 }
 
-/// Convert the provided firmware image from ELF to binary
-pub fn save_elf_as_image<'a>(
-    image_path: PathBuf,
-    flash_size: Option<FlashSize>,
-    merge: bool,
-    skip_padding: bool,
-    image_format: ImageFormat<'a>,
-) -> Result<()> {
-    let metadata = image_format.metadata();
-    if metadata.contains_key("app_size") && metadata.contains_key("part_size") {
-        let app_size = metadata["app_size"].parse::<u32>().unwrap();
-        let part_size = metadata["part_size"].parse::<u32>().unwrap();
+/// Offset of the partition table on the device, unless configured otherwise
+const DEFAULT_PARTITION_TABLE_OFFSET: u32 = 0x8000;
 
-        display_image_size(app_size, Some(part_size));
+/// Read the application descriptor of the application the bootloader would
+/// boot, along with the partition it's stored in
+fn read_running_app_info(
+    flasher: &mut Flasher,
+    table_offset: u32,
+) -> Result<Option<(Partition, AppInfo)>> {
+    let table = flasher.read_flash_data(
+        table_offset,
+        FLASH_SECTOR_SIZE as u32,
+        READ_BLOCK_SIZE,
+        READ_MAX_IN_FLIGHT,
+    )?;
+    let Some(table) = parse_device_table(&table)? else {
+        return Ok(None);
+    };
+
+    // OTA app partitions, ordered by their subtype (`ota_0`, `ota_1`, ...)
+    let mut ota_apps = table
+        .partitions()
+        .iter()
+        .filter_map(|p| match p.subtype() {
+            esp_idf_part::SubType::App(app)
+                if (OTA_SUBTYPE_MIN..=OTA_SUBTYPE_MAX).contains(&(app as u8)) =>
+            {
+                Some((app as u8, p))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    ota_apps.sort_by_key(|(subtype, _)| *subtype);
+    let ota_apps = ota_apps.into_iter().map(|(_, p)| p).collect::<Vec<_>>();
+
+    let otadata = table.partitions().iter().find(|p| {
+        p.ty() == esp_idf_part::Type::Data
+            && p.subtype() == esp_idf_part::SubType::Data(DataType::Ota)
+    });
+
+    let mut selected = None;
+    if let Some(otadata) = otadata {
+        let data = flasher.read_flash_data(
+            otadata.offset(),
+            otadata.size(),
+            READ_BLOCK_SIZE,
+            READ_MAX_IN_FLIGHT,
+        )?;
+        selected = selected_ota_slot(&data, ota_apps.len() as u8)
+            .and_then(|slot| ota_apps.get(slot as usize).copied());
     }
 
-    if merge {
-        let mut file = fs::OpenOptions::new()
-            .write(true)
+    // Without a valid OTA selection the bootloader boots the factory app, or
+    // the first OTA app if there is none
+    let partition = selected
+        .or_else(|| {
+            table
+                .partitions()
+                .iter()
+                .find(|p| p.subtype() == esp_idf_part::SubType::App(esp_idf_part::AppType::Factory))
+        })
+        .or_else(|| ota_apps.first().copied());
+    let Some(partition) = partition else {
+        return Ok(None);
+    };
+
+    let image = flasher.read_flash_data(
+        partition.offset(),
+        AppInfo::IMAGE_PREFIX_SIZE as u32,
+        READ_BLOCK_SIZE,
+        READ_MAX_IN_FLIGHT,
+    )?;
+
+    Ok(AppInfo::from_image(&image).map(|info| (partition.clone(), info)))
+}
+
+/// Print the application descriptor of an application as a banner
+fn print_app_info(partition: &Partition, info: &AppInfo) {
+    println!("App partition:     {}", partition.name());
+    println!("App:               {} {}", info.project_name, info.version);
+    println!("ESP-IDF version:   {}", info.idf_version);
+    println!("Compiled:          {} {}", info.date, info.time);
+}
+
+/// Open the serial monitor on a running device, without resetting it
+fn attach_monitor(args: MonitorArgs, config: &Config) -> Result<()> {
+    let port_info = serial::serial_port_info(&args.connect_args, config)?;
+    let pid = match &port_info.port_type {
+        SerialPortType::UsbPort(info) => info.pid,
+        _ => 0,
+    };
+
+    info!("Serial port: '{}'", port_info.port_name);
+    info!("Attaching without resetting the device...");
+
+    // The control lines are left untouched, so the device is not reset.
+    let serial = serialport::new(&port_info.port_name, args.monitor_args.monitor_baud)
+        .flow_control(FlowControl::None)
+        .open_native()
+        .map_err(Error::from)
+        .wrap_err_with(|| format!("Failed to open serial port {}", port_info.port_name))?;
+
+    let firmware_elf = if let Some(elf_path) = args.monitor_args.elf.clone() {
+        let path = fs::canonicalize(elf_path).into_diagnostic()?;
+        Some(fs::read(path).into_diagnostic()?)
+    } else {
+        None
+    };
+
+    if let Some(chip) = args.connect_args.chip {
+        ensure_chip_compatibility(chip, firmware_elf.as_deref())?;
+    }
+
+    let rom_elf = match &args.monitor_args.rom_elf {
+        Some(rom) => Some(fs::read(rom).into_diagnostic()?),
+        None => None,
+    };
+
+    let mut elfs: Vec<&[u8]> = Vec::new();
+    elfs.extend(firmware_elf.as_deref());
+    elfs.extend(rom_elf.as_deref());
+
+    let mut monitor_args = args.monitor_args;
+    monitor_args.no_reset = true;
+
+    monitor(
+        serial,
+        elfs,
+        pid,
+        monitor_args,
+        args.connect_args.non_interactive,
+    )
+}
+
+/// Print the fields of the application image header, as `save-image` would
+/// write it
+pub fn print_image_header(image_format: &ImageFormat<'_>, chip: Chip) -> Result<()> {
+    let info = ImageHeaderInfo::from_image(&image_format.app_segment().data)
+        .ok_or(Error::InvalidAppImage)?;
+
+    let or_invalid = |value: Option<String>| value.unwrap_or_else(|| "invalid".into());
+    let chip_name = Chip::iter()
+        .find(|chip| chip.id() == info.chip_id)
+        .map(|chip| chip.to_string());
+    let revision = |rev: u16| format!("v{}.{}", rev / 100, rev % 100);
+
+    println!("Magic:             {:#04x}", info.magic);
+    println!("Segment count:     {}", info.segment_count);
+    println!(
+        "Flash mode:        {:#04x} ({})",
+        info.flash_mode,
+        or_invalid(
+            info.flash_mode()
+                .map(|mode| format!("{mode:?}").to_lowercase())
+        )
+    );
+    println!(
+        "Flash size:        {:#03x} ({})",
+        info.flash_config >> 4,
+        or_invalid(info.flash_size().map(|size| size.to_string()))
+    );
+    println!(
+        "Flash frequency:   {:#03x} ({})",
+        info.flash_config & 0xf,
+        or_invalid(info.flash_freq(chip).map(|freq| freq.to_string()))
+    );
+    println!("Flash config byte: {:#04x}", info.flash_config);
+    println!("Entry point:       {:#010x}", info.entry);
+    println!("WP pin:            {:#04x}", info.wp_pin);
+    println!(
+        "Chip ID:           {:#06x} ({})",
+        info.chip_id,
+        chip_name.unwrap_or_else(|| "unknown".into())
+    );
+    println!(
+        "Chip revisions:    {} - {}",
+        revision(info.min_chip_rev_full),
+        revision(info.max_chip_rev_full)
+    );
+    println!("Appended digest:   {}", info.append_digest);
+
+    Ok(())
+}
+
+/// Options for merging all images into a single binary
+#[derive(Debug, Clone)]
+pub struct MergeOptions {
+    /// Flash size to pad the merged image to, if any
+    pub pad_to: Option<FlashSize>,
+    /// Byte used to fill the gaps between segments and the padding
+    pub fill_byte: u8,
+    /// Additional binaries to insert at the given offsets
+    pub append: Vec<(u32, PathBuf)>,
+}
+
+/// Convert the provided firmware image from ELF to binary
+///
+/// With `merge`, all images are merged into a single binary, otherwise they
+/// are saved as individual files according to `split_args`.
+pub fn save_elf_as_image<'a>(
+    image_path: PathBuf,
+    merge: Option<MergeOptions>,
+    split_args: SplitImageArgs,
+    image_format: ImageFormat<'a>,
+) -> Result<()> {
+    let metadata = image_format.metadata();
+    if metadata.contains_key("app_size") && metadata.contains_key("part_size") {
+        let app_size = metadata["app_size"].parse::<u32>().unwrap();
+        let part_size = metadata["part_size"].parse::<u32>().unwrap();
+
+        display_image_size(app_size, Some(part_size));
+    }
+
+    if let Some(MergeOptions {
+        pad_to,
+        fill_byte,
+        append,
+    }) = merge
+    {
+        let flash_size = pad_to.unwrap_or_else(|| image_format.flash_size());
+        let app_offset = image_format.app_offset();
+
+        let mut segments = image_format.flash_segments();
+        let split = split_args.also_split.map(|dir| (dir, segments.clone()));
+
+        let mut appended = HashMap::new();
+        for (addr, path) in append {
+            let data =
+                fs::read(&path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+            segments.push(Segment {
+                addr,
+                data: Cow::Owned(data),
+            });
+            appended.insert(addr, path);
+        }
+        segments.sort_by_key(|segment| segment.addr);
+
+        if let Some(segment) = segments.iter().find(|segment| {
+            segment
+                .addr
+                .checked_add(segment.size())
+                .is_none_or(|end| end > flash_size.size())
+        }) {
+            let component = match appended.get(&segment.addr) {
+                Some(path) => format!("'{}'", path.display()),
+                None if segment.addr == app_offset => "The application".to_string(),
+                None => format!("The segment at {:#x}", segment.addr),
+            };
+            return Err(miette::miette!(
+                help = "Make sure you set the correct flash size with the `--flash-size` option",
+                "{component} ends at {:#x}, which exceeds the flash size of {flash_size} ({:#x} bytes)",
+                segment.addr as u64 + segment.size() as u64,
+                flash_size.size()
+            ));
+        }
+
+        check_segment_overlap(&segments)?;
+
+        // Only create any files once the image is known to be valid
+        if let Some((dir, segments)) = split {
+            save_split_image(&dir, &segments)?;
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .write(true)
             .truncate(true)
             .create(true)
             .open(image_path)
             .into_diagnostic()?;
 
-        for segment in image_format.flash_segments() {
+        for segment in segments {
             let padding_bytes = vec![
-                0xffu8;
+                fill_byte;
                 segment.addr as usize
                     - file.metadata().into_diagnostic()?.len() as usize
             ];
@@ -723,24 +2568,53 @@ pub fn save_elf_as_image<'a>(
             file.write_all(&segment.data).into_diagnostic()?;
         }
 
-        if !skip_padding {
-            // Take flash_size as input parameter, if None, use default value of 4Mb
+        if let Some(flash_size) = pad_to {
             let padding_bytes = vec![
-                0xffu8;
-                flash_size.unwrap_or_default().size() as usize
+                fill_byte;
+                flash_size.size() as usize
                     - file.metadata().into_diagnostic()?.len() as usize
             ];
             file.write_all(&padding_bytes).into_diagnostic()?;
         }
     } else {
-        match image_format.ota_segments().as_slice() {
-            [single] => fs::write(&image_path, &single.data).into_diagnostic()?,
-            parts => {
-                for part in parts {
-                    let part_path = format!("{:#x}_{}", part.addr, image_path.display());
-                    fs::write(part_path, &part.data).into_diagnostic()?
+        if let Some(dir) = &split_args.output_dir {
+            fs::create_dir_all(dir)
+                .map_err(|e| Error::FileOpenError(dir.display().to_string(), e))?;
+        }
+
+        // Individual files are named after the file name of the image, and saved next
+        // to it unless an output directory is given
+        let file_name = image_path
+            .file_name()
+            .ok_or_else(|| miette::miette!("'{}' is not a file path", image_path.display()))?
+            .to_string_lossy()
+            .into_owned();
+        let dir = match &split_args.output_dir {
+            Some(dir) => dir.as_path(),
+            None => image_path.parent().unwrap_or(Path::new("")),
+        };
+
+        let mut parts = image_format
+            .ota_segments()
+            .into_iter()
+            .enumerate()
+            .collect::<Vec<_>>();
+        if let Some(indices) = &split_args.only_segments {
+            parts = select_image_segments(&parts[0].1.data, indices)?;
+        }
+
+        for (index, part) in &parts {
+            let part_name = match &split_args.name_template {
+                Some(template) => render_name_template(template, part.addr, *index, &file_name),
+                None if split_args.only_segments.is_some() => {
+                    format!("seg{index}_{:#x}_{file_name}", part.addr)
                 }
-            }
+                None if parts.len() == 1 => file_name.clone(),
+                None => format!("{:#x}_{file_name}", part.addr),
+            };
+            let part_path = dir.join(part_name);
+
+            fs::write(&part_path, &part.data).into_diagnostic()?;
         }
     }
 
@@ -749,6 +2623,81 @@ pub fn save_elf_as_image<'a>(
     Ok(())
 }
 
+/// Check that none of the segments of a merged image, sorted by address,
+/// overlap each other
+fn check_segment_overlap(segments: &[Segment<'_>]) -> Result<()> {
+    for pair in segments.windows(2) {
+        let (current, next) = (&pair[0], &pair[1]);
+        let end = current.addr.checked_add(current.size()).ok_or_else(|| {
+            miette::miette!(
+                "Segment at {:#x} ({} bytes) extends past the end of the address space",
+                current.addr,
+                current.size()
+            )
+        })?;
+
+        if end > next.addr {
+            return Err(miette::miette!(
+                "Segment at {:#x} ({} bytes) overlaps with segment at {:#x}",
+                current.addr,
+                current.size(),
+                next.addr
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pick the segments with the given indices out of an application image,
+/// keeping their indices
+fn select_image_segments(
+    image: &[u8],
+    indices: &[usize],
+) -> Result<Vec<(usize, Segment<'static>)>> {
+    let segments = image_segments(image)
+        .ok_or_else(|| miette::miette!("The application image could not be split into segments"))?;
+
+    indices
+        .iter()
+        .map(|&index| {
+            let segment = segments.get(index).ok_or_else(|| {
+                miette::miette!(
+                    "Segment index {index} is out of range, the image has segments 0 to {}",
+                    segments.len().saturating_sub(1)
+                )
+            })?;
+
+            Ok((
+                index,
+                Segment {
+                    addr: segment.addr,
+                    data: Cow::Owned(segment.data.to_vec()),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Save the bootloader, partition table and application segments as individual
+/// files in the given directory
+///
+/// Any further segments are only named by their address.
+fn save_split_image(dir: &Path, segments: &[Segment<'_>]) -> Result<()> {
+    fs::create_dir_all(dir).map_err(|e| Error::FileOpenError(dir.display().to_string(), e))?;
+
+    let names = ["bootloader", "partition-table", "app"]
+        .into_iter()
+        .chain(std::iter::repeat("data"));
+    for (segment, name) in segments.iter().zip(names) {
+        let path = dir.join(format!("{:#x}_{name}.bin", segment.addr));
+        fs::write(&path, &segment.data)
+            .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+    }
+
+    Ok(())
+}
+
 /// Displays the image or app size
 pub(crate) fn display_image_size(app_size: u32, part_size: Option<u32>) {
     if let Some(part_size) = part_size {
@@ -822,23 +2771,197 @@ impl ProgressCallbacks for EspflashProgress {
         }
         self.verifying = false;
     }
+
+    /// Cancel the operation once the timeout has been exceeded
+    fn is_cancelled(&mut self) -> bool {
+        timed_out()
+    }
 }
 
+/// Progress callback implementation showing a single line for a device, for
+/// use when working with several devices at once
+#[derive(Debug)]
+pub struct DeviceProgress {
+    pb: ProgressBar,
+    addr: u32,
+}
+
+impl DeviceProgress {
+    /// Add a progress line for the device with the given name
+    pub fn new(multi: &MultiProgress, name: &str) -> Self {
+        let pb = multi.add(
+            ProgressBar::new(0)
+                .with_prefix(name.to_string())
+                .with_style(
+                    ProgressStyle::default_bar()
+                        .template(
+                            "{prefix:<16} [{elapsed_precise}] [{bar:40}] {pos:>7}/{len:7} {msg}",
+                        )
+                        .unwrap()
+                        .progress_chars("=> "),
+                ),
+        );
+        pb.set_message("Connecting...");
+
+        Self { pb, addr: 0 }
+    }
+
+    /// Finish the progress line with the outcome for the device
+    pub fn finish_with_result<T>(&self, result: &Result<T>) {
+        use crossterm::style::Stylize;
+
+        match result {
+            Ok(_) => self.pb.finish_with_message(format!("{}", "OK!".green())),
+            Err(e) => self
+                .pb
+                .abandon_with_message(format!("{} {e}", "Failed!".red())),
+        }
+    }
+}
+
+impl ProgressCallbacks for DeviceProgress {
+    fn init(&mut self, addr: u32, len: usize) {
+        self.addr = addr;
+        self.pb.set_length(len as u64);
+        self.pb.set_position(0);
+        self.pb.set_message(format!("{addr:<#8X}"));
+    }
+
+    fn update(&mut self, current: usize) {
+        self.pb.set_position(current as u64);
+    }
+
+    fn verifying(&mut self) {
+        self.pb
+            .set_message(format!("{:<#8X} Verifying...", self.addr));
+    }
+
+    fn finish(&mut self, skipped: bool) {
+        if skipped {
+            self.pb
+                .set_message(format!("{:<#8X} Skipped! (checksum matches)", self.addr));
+        }
+    }
+
+    fn is_cancelled(&mut self) -> bool {
+        timed_out()
+    }
+}
+
+/// Run the given operation on all connected devices matching the USB filter
+/// in parallel
+///
+/// Each device is handled on its own thread, with its own progress line, and
+/// failures are isolated so that they do not abort the other devices. A
+/// summary is printed once all devices are done, and an error is returned if
+/// any of them failed.
+pub fn for_each_device<F>(
+    connect_args: &ConnectArgs,
+    usb_filter: Option<&UsbDevice>,
+    config: &Config,
+    op: F,
+) -> Result<()>
+where
+    F: Fn(&ConnectArgs, &mut DeviceProgress) -> Result<()> + Sync,
+{
+    let ports = serial::matching_serial_ports(usb_filter, config)?;
+    if ports.is_empty() {
+        return Err(Error::NoSerial.into());
+    }
+    info!("Found {} matching devices", ports.len());
+
+    let multi = MultiProgress::new();
+    let results = std::thread::scope(|scope| {
+        let handles = ports
+            .iter()
+            .map(|port| {
+                let mut connect_args = connect_args.clone();
+                connect_args.port = Some(port.port_name.clone());
+                connect_args.non_interactive = true;
+
+                let mut progress = DeviceProgress::new(&multi, &port.port_name);
+                let op = &op;
+                scope.spawn(move || {
+                    let result = op(&connect_args, &mut progress);
+                    progress.finish_with_result(&result);
+                    result
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(miette::miette!("Thread panicked")))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    println!();
+    let mut failed = 0;
+    for (port, result) in ports.iter().zip(&results) {
+        match result {
+            Ok(()) => println!("{:<16} OK", port.port_name),
+            Err(e) => {
+                failed += 1;
+                println!("{:<16} FAILED: {e}", port.port_name);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(miette::miette!(
+            "{failed} of {} devices failed",
+            results.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Approximate chip erase speed, used to estimate its duration
+const ERASE_CHIP_BYTES_PER_SEC: u32 = 512 * 1024;
+
 /// Erase the entire flash memory of a target device
 pub fn erase_flash(args: EraseFlashArgs, config: &Config) -> Result<()> {
-    if args.connect_args.no_stub {
+    if !args.connect_args.use_stub(config) {
         return Err(Error::StubRequired.into());
     }
 
     let mut flasher = connect(&args.connect_args, config, true, true)?;
-    info!("Erasing Flash...");
+
+    if !args.keep.is_empty() {
+        return erase_flash_except(&mut flasher, &args);
+    }
+
+    confirm_erase(
+        &format!(
+            "Erase the entire flash ({})?",
+            HumanBytes(flasher.flash_size().size() as u64)
+        ),
+        args.yes,
+    )?;
+
+    // Erasing the whole chip is a single command with no progress reporting, so
+    // estimate its duration and show a spinner to indicate that it's running.
+    let estimate = (flasher.flash_size().size() / ERASE_CHIP_BYTES_PER_SEC).max(1);
+    info!("Erasing Flash, this takes about {estimate} seconds...");
 
     let chip = flasher.chip();
 
-    flasher.erase_flash()?;
-    flasher
-        .connection()
-        .reset_after(!args.connect_args.no_stub, chip)?;
+    let spinner = ProgressBar::new_spinner().with_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner} [{elapsed_precise}] Erasing...")
+            .unwrap(),
+    );
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    let result = flasher.erase_flash();
+    spinner.finish_and_clear();
+    result?;
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
 
     info!("Flash has been erased!");
 
@@ -847,7 +2970,7 @@ pub fn erase_flash(args: EraseFlashArgs, config: &Config) -> Result<()> {
 
 /// Erase a specified region of flash memory
 pub fn erase_region(args: EraseRegionArgs, config: &Config) -> Result<()> {
-    if args.connect_args.no_stub {
+    if !args.connect_args.use_stub(config) {
         return Err(Error::StubRequired).into_diagnostic();
     }
 
@@ -858,31 +2981,401 @@ pub fn erase_region(args: EraseRegionArgs, config: &Config) -> Result<()> {
         })
         .into_diagnostic();
     }
+    let end = args.address.checked_add(args.size).ok_or_else(|| {
+        miette::miette!(
+            "The region at {:#x} ({} bytes) extends past the end of the address space",
+            args.address,
+            args.size
+        )
+    })?;
 
     let mut flasher = connect(&args.connect_args, config, true, true)?;
     let chip = flasher.chip();
 
+    confirm_erase(
+        &format!(
+            "Erase {:#x}..{end:#x} ({})?",
+            args.address,
+            HumanBytes(args.size as u64)
+        ),
+        args.yes,
+    )?;
+
     info!(
         "Erasing region at 0x{:08x} ({} bytes)",
         args.address, args.size
     );
 
     flasher.erase_region(args.address, args.size)?;
-    flasher
-        .connection()
-        .reset_after(!args.connect_args.no_stub, chip)?;
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Warn if the flash chip is write protected, and clear the protection if
+/// `unlock` is set
+///
+/// Clearing the protection requires confirmation, unless `non_interactive` is
+/// set.
+pub fn check_flash_protection(
+    flasher: &mut Flasher,
+    unlock: bool,
+    non_interactive: bool,
+) -> Result<()> {
+    match flasher.is_flash_protected() {
+        Ok(true) => {}
+        Ok(false) => return Ok(()),
+        Err(e) => {
+            debug!("Could not read the flash status register: {e}");
+            return Ok(());
+        }
+    }
+    let status = flasher.read_flash_status()?;
+
+    warn!(
+        "The flash is write protected (status register 0x{status:04x}), writing to it may silently fail"
+    );
+    if !unlock {
+        warn!("Use `--unlock-flash` to clear the protection before writing");
+        return Ok(());
+    }
+
+    if !non_interactive
+        && !Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Clear the flash write protection?")
+            .interact_opt()
+            .into_diagnostic()?
+            .ok_or(Error::Cancelled)?
+    {
+        return Err(Error::Cancelled.into());
+    }
+
+    flasher.unlock_flash()?;
+    info!("Flash write protection cleared");
+
+    Ok(())
+}
+
+/// Ask the user to confirm a destructive operation described by `prompt`
+///
+/// No prompt is shown if `yes` is set or stdin is not a terminal, so that
+/// scripts keep working unchanged.
+pub fn confirm_erase(prompt: &str, yes: bool) -> Result<()> {
+    if yes || !std::io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .default(false)
+        .interact_opt()
+        .into_diagnostic()?
+        .ok_or(Error::Cancelled)?;
+    if !confirmed {
+        return Err(Error::Cancelled.into());
+    }
 
     Ok(())
 }
 
+/// Ask the user to confirm erasing the partitions with the given labels
+///
+/// See [confirm_erase] for when the prompt is skipped.
+pub fn confirm_erase_partitions(
+    partition_table: Option<&PartitionTable>,
+    labels: &[String],
+    yes: bool,
+) -> Result<()> {
+    let summary = labels
+        .iter()
+        .map(|label| match partition_table.and_then(|t| t.find(label)) {
+            Some(part) => format!("{label} ({})", HumanBytes(part.size() as u64)),
+            None => label.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    confirm_erase(&format!("Erase partition(s) {}?", summary.join(", ")), yes)
+}
+
 /// Write an ELF image to a target device's flash
 pub fn flash_image<'a>(flasher: &mut Flasher, image_format: ImageFormat<'a>) -> Result<()> {
-    flasher.load_image_to_flash(&mut EspflashProgress::default(), image_format)?;
+    let report = flasher.load_image_to_flash(&mut EspflashProgress::default(), image_format)?;
     info!("Flashing has completed!");
+    print_flash_report(&report);
+
+    Ok(())
+}
+
+/// Read the whole flash back into `path`, once flashing has completed
+///
+/// The reset after flashing must have been disabled, so that the application
+/// doesn't run before the flash is read. If `reset` is set, the target is reset
+/// afterwards.
+pub fn dump_flash_after(flasher: &mut Flasher, path: &Path, reset: bool) -> Result<()> {
+    let size = flasher.flash_size().size();
+    info!("Reading {size:#x} bytes of flash into '{}'", path.display());
+
+    let data = flasher.read_flash_data(0, size, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?;
+    fs::write(path, &data).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+
+    if reset {
+        let chip = flasher.chip();
+        let use_stub = flasher.is_using_stub();
+        flasher.connection().reset_after(use_stub, chip)?;
+    }
+
+    Ok(())
+}
+
+/// The contents of a partition, saved before flashing to be written back
+/// afterwards
+#[derive(Debug)]
+pub struct PreservedPartition {
+    label: String,
+    offset: u32,
+    size: u32,
+    data: Vec<u8>,
+}
+
+/// Read the partitions with the given labels from the device, so that they
+/// can be restored with [restore_partitions] once the image has been flashed
+///
+/// The partitions are looked up in the partition table currently on the
+/// device, and must exist with the same type and at least the same size in the
+/// partition table of the image.
+pub fn preserve_partitions(
+    flasher: &mut Flasher,
+    idf_format: &IdfBootloaderFormat<'_>,
+    labels: &[String],
+) -> Result<Vec<PreservedPartition>> {
+    let table_offset = idf_format.partition_table_offset();
+    let device_table = flasher.read_flash_data(
+        table_offset,
+        FLASH_SECTOR_SIZE as u32,
+        READ_BLOCK_SIZE,
+        READ_MAX_IN_FLIGHT,
+    )?;
+    let device_table = parse_device_table(&device_table)?.ok_or_else(|| {
+        miette::miette!(
+            help = "Flash the device without `--preserve` first",
+            "No partition table found on the device at {table_offset:#x}, there is nothing to preserve"
+        )
+    })?;
+    let new_table = idf_format.partition_table();
+
+    let mut preserved = Vec::with_capacity(labels.len());
+    for label in labels {
+        let old = device_table
+            .find(label)
+            .ok_or_else(|| MissingPartition::from(label.clone()))?;
+        let new = new_table
+            .find(label)
+            .ok_or_else(|| MissingPartition::from(label.clone()))?;
+
+        if old.ty() != new.ty() || old.subtype() != new.subtype() || new.size() < old.size() {
+            return Err(miette::miette!(
+                "Cannot preserve partition '{label}': it is {} {} of {:#x} bytes on the device, but {} {} of {:#x} bytes in the new partition table",
+                old.ty(),
+                old.subtype(),
+                old.size(),
+                new.ty(),
+                new.subtype(),
+                new.size()
+            ));
+        }
+
+        info!(
+            "Saving partition '{label}' ({:#x} bytes at {:#x})",
+            old.size(),
+            old.offset()
+        );
+        let data = flasher.read_flash_data(
+            idf_format.relocate(old.offset())?,
+            old.size(),
+            READ_BLOCK_SIZE,
+            READ_MAX_IN_FLIGHT,
+        )?;
+
+        preserved.push(PreservedPartition {
+            label: label.clone(),
+            offset: idf_format.relocate(new.offset())?,
+            size: new.size(),
+            data,
+        });
+    }
+
+    Ok(preserved)
+}
+
+/// Write back partitions saved with [preserve_partitions]
+///
+/// If a partition is larger in the new partition table, the remainder of it
+/// is erased, so that it doesn't keep stale data from the previous layout.
+///
+/// The reset after flashing must have been disabled, so that the application
+/// doesn't run before its partitions are restored. If `reset` is set, the
+/// target is reset afterwards.
+pub fn restore_partitions(
+    flasher: &mut Flasher,
+    preserved: &[PreservedPartition],
+    progress: &mut dyn ProgressCallbacks,
+    reset: bool,
+) -> Result<()> {
+    for part in preserved {
+        info!("Restoring partition '{}' at {:#x}", part.label, part.offset);
+        flasher.write_bin_to_flash(part.offset, &part.data, progress)?;
+
+        let len = part.data.len() as u32;
+        if part.size > len {
+            flasher.erase_region(part.offset + len, part.size - len)?;
+        }
+    }
+
+    if reset {
+        let chip = flasher.chip();
+        let use_stub = flasher.is_using_stub();
+        flasher.connection().reset_after(use_stub, chip)?;
+    }
 
     Ok(())
 }
 
+/// Locate the application in the image, for `--verify sha256`
+///
+/// Returns the flash offset and length of the application, or an error if it
+/// has no appended SHA256 digest to verify against.
+pub fn app_digest_region(image_format: &ImageFormat<'_>) -> Result<(u32, u32)> {
+    let app = image_format.app_segment();
+
+    if verify_appended_digest(&app.data).is_none() {
+        return Err(miette::miette!(
+            help = "Enable `CONFIG_APP_IMAGE_APPEND_SHA256` or the equivalent for your image, or use another `--verify` mode",
+            "The application image has no appended SHA256 digest to verify against"
+        ));
+    }
+
+    Ok((app.addr, app.data.len() as u32))
+}
+
+/// Read back the application written to `offset` and check that its appended
+/// SHA256 digest matches its contents
+pub fn verify_app_digest(flasher: &mut Flasher, (offset, length): (u32, u32)) -> Result<()> {
+    let data = flasher.read_flash_data(offset, length, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?;
+    if verify_appended_digest(&data) != Some(true) {
+        return Err(Error::VerifyFailed.into());
+    }
+    info!("Application at {offset:#x} verified against its appended SHA256 digest");
+
+    Ok(())
+}
+
+/// Log a summary of the regions written and skipped while flashing
+fn print_flash_report(report: &FlashReport) {
+    for region in &report.written {
+        info!(
+            "Wrote   0x{:08x} ({:>7} bytes) md5 {}",
+            region.offset,
+            region.length,
+            encode_hex(region.md5)
+        );
+    }
+    for region in &report.skipped {
+        info!(
+            "Skipped 0x{:08x} ({:>7} bytes) md5 {} (unchanged)",
+            region.offset,
+            region.length,
+            encode_hex(region.md5)
+        );
+    }
+}
+
+/// Erase all of the flash except for the partitions listed in `args.keep`
+fn erase_flash_except(flasher: &mut Flasher, args: &EraseFlashArgs) -> Result<()> {
+    let partition_table = match &args.partition_table {
+        Some(path) => parse_partition_table(path, args.partition_table_format)?,
+        None => return Err(MissingPartitionTable.into()),
+    };
+
+    let mut keep = Vec::with_capacity(args.keep.len());
+    for label in &args.keep {
+        let part = partition_table
+            .find(label.as_str())
+            .ok_or_else(|| MissingPartition::from(label.clone()))?;
+
+        info!(
+            "Keeping {} ({:#x}..{:#x})",
+            part.name(),
+            part.offset(),
+            part.offset() + part.size()
+        );
+        keep.push((part.offset(), part.size()));
+    }
+
+    let regions = complement_regions(flasher.flash_size().size(), keep).into_diagnostic()?;
+    let total = regions.iter().map(|(_, size)| size).sum::<u32>();
+    confirm_erase(
+        &format!(
+            "Erase {} in {} region(s), keeping {}?",
+            HumanBytes(total as u64),
+            regions.len(),
+            args.keep.join(", ")
+        ),
+        args.yes,
+    )?;
+
+    for (offset, size) in regions {
+        info!("Erasing {:#x}..{:#x}...", offset, offset + size);
+        flasher.erase_region(offset, size).into_diagnostic()?;
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    info!(
+        "Flash has been erased, keeping {} partition(s)",
+        args.keep.len()
+    );
+
+    Ok(())
+}
+
+/// Compute the `(offset, size)` regions of `0..flash_size` which are not
+/// covered by any of the `keep` regions
+///
+/// Every kept region must be sector aligned, as only whole sectors can be
+/// erased.
+fn complement_regions(
+    flash_size: u32,
+    mut keep: Vec<(u32, u32)>,
+) -> Result<Vec<(u32, u32)>, Error> {
+    if let Some(&(address, size)) = keep.iter().find(|(offset, size)| {
+        offset % FLASH_SECTOR_SIZE as u32 != 0 || size % FLASH_SECTOR_SIZE as u32 != 0
+    }) {
+        return Err(Error::InvalidEraseRegionArgument { address, size });
+    }
+
+    keep.sort_unstable();
+
+    let mut regions = Vec::new();
+    let mut cursor = 0;
+
+    for (offset, size) in keep {
+        let offset = offset.min(flash_size);
+        if offset > cursor {
+            regions.push((cursor, offset - cursor));
+        }
+        cursor = cursor.max(offset.saturating_add(size).min(flash_size));
+    }
+
+    if cursor < flash_size {
+        regions.push((cursor, flash_size - cursor));
+    }
+
+    Ok(regions)
+}
+
 /// Erase one or more partitions by label or [DataType]
 pub fn erase_partitions(
     flasher: &mut Flasher,
@@ -928,60 +3421,736 @@ pub fn erase_partitions(
         }
     }
 
-    if let Some(parts) = parts_to_erase {
-        parts
-            .iter()
-            .try_for_each(|(_, p)| erase_partition(flasher, p))?;
+    if let Some(parts) = parts_to_erase {
+        parts
+            .iter()
+            .try_for_each(|(_, p)| erase_partition(flasher, p))?;
+    }
+
+    Ok(())
+}
+
+/// Find the labels of all partitions of the given type and, optionally, subtype
+///
+/// Types and subtypes are matched by name, as printed by the `partition-table`
+/// subcommand (e.g. `data` and `ota`), ignoring case.
+pub fn partition_labels_by_type(
+    partition_table: Option<&PartitionTable>,
+    ty: &str,
+    subtype: Option<&str>,
+) -> Result<Vec<String>> {
+    let partition_table = partition_table.ok_or(MissingPartitionTable)?;
+
+    let labels = partition_table
+        .partitions()
+        .iter()
+        .filter(|part| part.ty().to_string().eq_ignore_ascii_case(ty))
+        .filter(|part| {
+            subtype.is_none_or(|subtype| part.subtype().to_string().eq_ignore_ascii_case(subtype))
+        })
+        .map(|part| part.name().to_string())
+        .collect::<Vec<_>>();
+
+    if labels.is_empty() {
+        warn!(
+            "No partitions of type '{ty}'{} found",
+            subtype.map_or(String::new(), |s| format!(" and subtype '{s}'"))
+        );
+    }
+
+    Ok(labels)
+}
+
+/// Erase a single partition
+fn erase_partition(flasher: &mut Flasher, part: &Partition) -> Result<()> {
+    log::info!("Erasing {} ({:?})...", part.name(), part.subtype());
+
+    let offset = part.offset();
+    let size = part.size();
+
+    flasher.erase_region(offset, size).into_diagnostic()
+}
+
+/// Write an NVS encryption keys file to the `nvs_keys` partition
+///
+/// The partition is resolved by its type and subtype (`data`/`nvs_keys`) rather
+/// than by label in the partition table of the image, and the file must fit
+/// within the partition.
+pub fn write_nvs_keys(
+    flasher: &mut Flasher,
+    idf_format: &IdfBootloaderFormat<'_>,
+    path: &Path,
+) -> Result<()> {
+    let partition_table = idf_format.partition_table();
+    let mut parts = partition_table.partitions().iter().filter(|part| {
+        part.ty() == esp_idf_part::Type::Data
+            && part.subtype() == esp_idf_part::SubType::Data(DataType::NvsKeys)
+    });
+
+    let part = match (parts.next(), parts.next()) {
+        (Some(part), None) => part,
+        (None, _) => {
+            return Err(miette::miette!(
+                help =
+                    "Add a partition of type `data` and subtype `nvs_keys` to the partition table",
+                "The partition table does not contain an `nvs_keys` partition"
+            ));
+        }
+        (Some(_), Some(_)) => {
+            return Err(miette::miette!(
+                "The partition table contains more than one `nvs_keys` partition"
+            ));
+        }
+    };
+
+    let data = fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+
+    if data.is_empty() || data.len() > part.size() as usize {
+        return Err(miette::miette!(
+            "The NVS keys file is {} bytes, but the `{}` partition is {} bytes",
+            data.len(),
+            part.name(),
+            part.size()
+        ));
+    }
+
+    let addr = idf_format.relocate(part.offset())?;
+    info!(
+        "Writing NVS keys to partition '{}' at 0x{addr:x}",
+        part.name()
+    );
+    flasher.write_bin_to_flash(addr, &data, &mut EspflashProgress::default())?;
+
+    Ok(())
+}
+
+/// Block size used when reading flash back for host-side processing
+const READ_BLOCK_SIZE: u32 = FLASH_SECTOR_SIZE as u32;
+/// Maximum number of un-acked packets when reading flash back
+const READ_MAX_IN_FLIGHT: u32 = 64;
+
+/// Size of the chunks the flash is read in when streaming it
+const STREAM_CHUNK_SIZE: u32 = 0x10000;
+
+/// Maximum line length of base64 output, as used by MIME
+const BASE64_LINE_WIDTH: usize = 76;
+
+/// Search the flash of a target device for a byte pattern
+///
+/// The flash is streamed in chunks, so memory usage doesn't depend on the
+/// size of the searched region.
+pub fn find_bytes(args: &FindBytesArgs, config: &Config) -> Result<()> {
+    if !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired).into_diagnostic();
+    }
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+
+    // The stub may have failed to load, with `--stub-fallback`
+    if !flasher.is_using_stub() {
+        return Err(Error::StubRequired.into());
+    }
+
+    let flash_size = flasher.flash_size().size();
+    let (start, end) = args.range.unwrap_or((0, flash_size));
+    if end > flash_size {
+        return Err(miette::miette!(
+            "The range {start:#x}:{end:#x} exceeds the flash size ({flash_size:#x} bytes)"
+        ));
+    }
+
+    info!("Searching 0x{start:08x}..0x{end:08x}");
+
+    let mut search = PatternSearch::new(&args.pattern);
+    let mut matches = 0;
+    let mut address = start;
+    while address < end {
+        let size = STREAM_CHUNK_SIZE.min(end - address);
+        let chunk = flasher.read_flash_data(address, size, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?;
+        for offset in search.feed(address, &chunk) {
+            println!("0x{offset:08x}");
+            matches += 1;
+        }
+        address += size;
+    }
+
+    info!("Found {matches} match(es)");
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Print a hexdump of a region of flash to stdout
+///
+/// The region is streamed in chunks, so memory usage doesn't depend on its
+/// size.
+pub fn peek(args: &PeekArgs, config: &Config) -> Result<()> {
+    if !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired).into_diagnostic();
+    }
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+
+    // The stub may have failed to load, with `--stub-fallback`
+    if !flasher.is_using_stub() {
+        return Err(Error::StubRequired.into());
+    }
+
+    let (start, end) = args.range;
+    let flash_size = flasher.flash_size().size();
+    if end > flash_size {
+        return Err(miette::miette!(
+            "The range {start:#x}:{end:#x} exceeds the flash size ({flash_size:#x} bytes)"
+        ));
+    }
+
+    let width = args.width as usize;
+    let mut stdout = std::io::stdout().lock();
+    let mut pending = Vec::new();
+    let mut line_address = start;
+    let mut address = start;
+    while address < end {
+        let size = STREAM_CHUNK_SIZE.min(end - address);
+        let chunk = flasher.read_flash_data(address, size, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?;
+        pending.extend_from_slice(&chunk);
+        address += size;
+
+        // Keep a partial line around until the next chunk completes it.
+        let complete = if address < end {
+            pending.len() - pending.len() % width
+        } else {
+            pending.len()
+        };
+        for line in pending[..complete].chunks(width) {
+            writeln!(stdout, "{}", hexdump_line(line_address, line, width)).into_diagnostic()?;
+            line_address += line.len() as u32;
+        }
+        pending.drain(..complete);
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    Ok(())
+}
+
+/// Format a single line of a hexdump, padding short lines to `width`
+fn hexdump_line(address: u32, data: &[u8], width: usize) -> String {
+    let hex = data
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let ascii = data
+        .iter()
+        .map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect::<String>();
+
+    format!(
+        "{address:08x}  {hex:<hex_width$}  |{ascii}|",
+        hex_width = width * 3 - 1
+    )
+}
+
+/// Incremental search for a byte pattern in consecutive chunks of data
+///
+/// The last `pattern.len() - 1` bytes of each chunk are carried over to the
+/// next one, so matches spanning chunk boundaries are found as well.
+struct PatternSearch<'a> {
+    pattern: &'a [Option<u8>],
+    carry: Vec<u8>,
+}
+
+impl<'a> PatternSearch<'a> {
+    fn new(pattern: &'a [Option<u8>]) -> Self {
+        Self {
+            pattern,
+            carry: Vec::new(),
+        }
+    }
+
+    /// Search the chunk starting at `address`, returning the addresses of all
+    /// matches which end inside of it
+    fn feed(&mut self, address: u32, chunk: &[u8]) -> Vec<u32> {
+        let base = address - self.carry.len() as u32;
+        let mut window = std::mem::take(&mut self.carry);
+        window.extend_from_slice(chunk);
+
+        let matches = window
+            .windows(self.pattern.len())
+            .enumerate()
+            .filter(|(_, bytes)| {
+                bytes
+                    .iter()
+                    .zip(self.pattern)
+                    .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected))
+            })
+            .map(|(i, _)| base + i as u32)
+            .collect();
+
+        let keep = (self.pattern.len() - 1).min(window.len());
+        self.carry = window.split_off(window.len() - keep);
+
+        matches
+    }
+}
+
+/// Write a known pattern to a scratch region, read it back and verify it
+///
+/// Reports the measured write and read throughput. Afterwards the region is
+/// restored to its previous contents, or erased if `--erase` is set.
+pub fn selftest(args: SelftestArgs, config: &Config) -> Result<()> {
+    if !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired).into_diagnostic();
+    }
+
+    let mut flasher = connect(&args.connect_args, config, true, true)?;
+    print_board_info(&mut flasher)?;
+
+    let flash_size = flasher.flash_size().size();
+    let size = args.size;
+    let address = args.address.unwrap_or(flash_size.saturating_sub(size));
+
+    if address % FLASH_SECTOR_SIZE as u32 != 0 || size % FLASH_SECTOR_SIZE as u32 != 0 {
+        return Err(Error::InvalidEraseRegionArgument { address, size }).into_diagnostic();
+    }
+    if size == 0 || address.checked_add(size).is_none_or(|end| end > flash_size) {
+        return Err(miette::miette!(
+            "The region at {address:#x} ({size} bytes) does not fit in the flash ({flash_size} bytes)"
+        ));
+    }
+
+    info!("Running self-test on region at 0x{address:08x} ({size} bytes)");
+
+    let original = if args.erase {
+        warn!("The region at 0x{address:08x} will be erased");
+        None
+    } else {
+        Some(flasher.read_flash_data(address, size, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?)
+    };
+
+    // A simple xorshift sequence, so that every byte value is exercised
+    let mut state = 0x2545_f491u32;
+    let pattern = (0..size)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    flasher.write_bin_to_flash(address, &pattern, &mut EspflashProgress::default())?;
+    let write_time = start.elapsed();
+
+    let md5_ok = Md5::digest(&pattern)[..] == flasher.checksum_md5(address, size)?.to_be_bytes();
+
+    let start = Instant::now();
+    let data = flasher.read_flash_data(address, size, READ_BLOCK_SIZE, READ_MAX_IN_FLIGHT)?;
+    let read_time = start.elapsed();
+    let read_ok = data == pattern;
+
+    match original {
+        Some(original) => {
+            flasher.write_bin_to_flash(address, &original, &mut EspflashProgress::default())?
+        }
+        None => flasher.erase_region(address, size)?,
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    let throughput = |time: Duration| size as f64 / 1024.0 / time.as_secs_f64();
+    println!("Write:             {:.1} KiB/s", throughput(write_time));
+    println!("Read:              {:.1} KiB/s", throughput(read_time));
+
+    if !md5_ok || !read_ok {
+        return Err(miette::miette!(
+            "Self-test failed: the data read back does not match the data written"
+        ));
+    }
+
+    info!("Self-test passed!");
+
+    Ok(())
+}
+
+/// Read flash content and write it to a file
+pub fn read_flash(args: ReadFlashArgs, config: &Config) -> Result<()> {
+    if args.base64 && !args.connect_args.use_stub(config) {
+        return Err(Error::StubRequired.into());
+    }
+
+    let mut flasher = connect(&args.connect_args, config, false, false)?;
+    print_board_info(&mut flasher)?;
+
+    // The stub may have failed to load, with `--stub-fallback`
+    if args.base64 && !flasher.is_using_stub() {
+        return Err(Error::StubRequired.into());
+    }
+
+    let file = match args.file.clone() {
+        Some(file) => file,
+        None => return read_flash_base64(&mut flasher, &args),
+    };
+
+    if !flasher.is_using_stub() {
+        flasher.read_flash_rom(
+            args.address,
+            args.size,
+            args.block_size,
+            args.max_in_flight,
+            file.clone(),
+            &mut EspflashProgress::default(),
+        )?;
+    } else {
+        flasher.read_flash(
+            args.address,
+            args.size,
+            args.block_size,
+            args.max_in_flight,
+            file.clone(),
+            &mut EspflashProgress::default(),
+        )?;
+    }
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    if let Some(key_path) = &args.decrypt_key {
+        let key = fs::read(key_path)
+            .map_err(|e| Error::FileOpenError(key_path.display().to_string(), e))?;
+        let data =
+            fs::read(&file).map_err(|e| Error::FileOpenError(file.display().to_string(), e))?;
+
+        let decrypted = decrypt_flash_data(chip, &key, args.address, &data)?;
+        fs::write(&file, decrypted).into_diagnostic()?;
+        info!("Flash contents decrypted");
+    }
+
+    let metadata = if args.meta {
+        let data =
+            fs::read(&file).map_err(|e| Error::FileOpenError(file.display().to_string(), e))?;
+
+        Some(DumpMetadata {
+            offset: args.address,
+            length: data.len() as u32,
+            chip,
+            sha256: encode_hex(Sha256::digest(&data)),
+            parts: Vec::new(),
+        })
+    } else {
+        None
+    };
+
+    // Split before writing the metadata, which then refers to the parts
+    // instead of the removed dump
+    let parts = match args.split_size {
+        Some(split_size) => split_dump(&file, args.address, split_size)?,
+        None => Vec::new(),
+    };
+
+    if let Some(mut metadata) = metadata {
+        metadata.parts = parts;
+
+        let meta_path = file.with_extension("meta");
+        fs::write(&meta_path, toml::to_string(&metadata).into_diagnostic()?)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to write dump metadata: {}", meta_path.display()))?;
+        info!("Dump metadata written to {}", meta_path.display());
+    }
+
+    Ok(())
+}
+
+/// Split a flash dump into numbered parts of at most `split_size` bytes, and
+/// write an index describing them
+///
+/// Returns the file names of the parts, in order.
+fn split_dump(path: &Path, offset: u32, split_size: u32) -> Result<Vec<String>> {
+    let data = fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    let mut parts = Vec::new();
+    for (index, chunk) in data.chunks(split_size as usize).enumerate() {
+        let part_name = format!("{file_name}.{index:03}");
+        let part_path = path.with_file_name(&part_name);
+        fs::write(&part_path, chunk)
+            .map_err(|e| Error::FileOpenError(part_path.display().to_string(), e))?;
+
+        parts.push(SplitDumpPart {
+            file: part_name,
+            offset: offset + (index as u32 * split_size),
+            length: chunk.len() as u32,
+        });
+    }
+
+    let index = SplitDumpIndex {
+        offset,
+        length: data.len() as u32,
+        parts,
+    };
+    let index_path = path.with_file_name(format!("{file_name}.index"));
+    fs::write(&index_path, toml::to_string(&index).into_diagnostic()?)
+        .map_err(|e| Error::FileOpenError(index_path.display().to_string(), e))?;
+
+    // The parts replace the complete dump
+    fs::remove_file(path).into_diagnostic()?;
+    info!(
+        "Dump split into {} parts, indexed in {}",
+        index.parts.len(),
+        index_path.display()
+    );
+
+    Ok(index.parts.into_iter().map(|part| part.file).collect())
+}
+
+/// Read a region of flash and print it to stdout as wrapped base64
+fn read_flash_base64(flasher: &mut Flasher, args: &ReadFlashArgs) -> Result<()> {
+    let mut data =
+        flasher.read_flash_data(args.address, args.size, args.block_size, args.max_in_flight)?;
+
+    let chip = flasher.chip();
+    let use_stub = flasher.is_using_stub();
+    flasher.connection().reset_after(use_stub, chip)?;
+
+    if let Some(key_path) = &args.decrypt_key {
+        let key = fs::read(key_path)
+            .map_err(|e| Error::FileOpenError(key_path.display().to_string(), e))?;
+        data = decrypt_flash_data(chip, &key, args.address, &data)?;
+        info!("Flash contents decrypted");
+    }
+
+    for line in base64_lines(&data, BASE64_LINE_WIDTH) {
+        println!("{line}");
     }
 
     Ok(())
 }
 
-/// Erase a single partition
-fn erase_partition(flasher: &mut Flasher, part: &Partition) -> Result<()> {
-    log::info!("Erasing {} ({:?})...", part.name(), part.subtype());
-
-    let offset = part.offset();
-    let size = part.size();
+/// Encode `data` as base64, split into lines of at most `width` characters
+fn base64_lines(data: &[u8], width: usize) -> Vec<String> {
+    let encoded = general_purpose::STANDARD.encode(data);
 
-    flasher.erase_region(offset, size).into_diagnostic()
+    encoded
+        .as_bytes()
+        .chunks(width)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect()
 }
 
-/// Read flash content and write it to a file
-pub fn read_flash(args: ReadFlashArgs, config: &Config) -> Result<()> {
-    let mut flasher = connect(&args.connect_args, config, false, false)?;
-    print_board_info(&mut flasher)?;
+/// Compare two ESP-IDF application images
+pub fn diff_image(args: &DiffImageArgs) -> Result<()> {
+    let read = |path: &Path| -> Result<(Vec<u8>, ImageHeaderInfo)> {
+        let data =
+            fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+        let header = ImageHeaderInfo::from_image(&data).ok_or_else(|| {
+            miette::miette!(
+                "'{}' is not a valid ESP-IDF application image",
+                path.display()
+            )
+        })?;
+
+        Ok((data, header))
+    };
+    let (old, old_header) = read(&args.old)?;
+    let (new, new_header) = read(&args.new)?;
+
+    let header_fields = [
+        (
+            "Segment count",
+            old_header.segment_count as u32,
+            new_header.segment_count as u32,
+        ),
+        (
+            "Flash mode",
+            old_header.flash_mode as u32,
+            new_header.flash_mode as u32,
+        ),
+        (
+            "Flash config",
+            old_header.flash_config as u32,
+            new_header.flash_config as u32,
+        ),
+        ("Entry point", old_header.entry, new_header.entry),
+        ("WP pin", old_header.wp_pin as u32, new_header.wp_pin as u32),
+        (
+            "Chip ID",
+            old_header.chip_id as u32,
+            new_header.chip_id as u32,
+        ),
+        (
+            "Min chip revision",
+            old_header.min_chip_rev_full as u32,
+            new_header.min_chip_rev_full as u32,
+        ),
+        (
+            "Max chip revision",
+            old_header.max_chip_rev_full as u32,
+            new_header.max_chip_rev_full as u32,
+        ),
+        (
+            "Appended digest",
+            old_header.append_digest as u32,
+            new_header.append_digest as u32,
+        ),
+    ];
+    for (name, old, new) in header_fields.iter().filter(|(_, old, new)| old != new) {
+        println!("Header {name}: {old:#x} -> {new:#x}");
+    }
 
-    if args.connect_args.no_stub {
-        flasher.read_flash_rom(
-            args.address,
-            args.size,
-            args.block_size,
-            args.max_in_flight,
-            args.file,
-        )?;
-    } else {
-        flasher.read_flash(
-            args.address,
-            args.size,
-            args.block_size,
-            args.max_in_flight,
-            args.file,
-        )?;
+    let corrupt = || miette::miette!("The image is truncated or corrupt");
+    let old_segments = image_segments(&old).ok_or_else(corrupt)?;
+    let new_segments = image_segments(&new).ok_or_else(corrupt)?;
+    let changes = diff_segments(&old_segments, &new_segments);
+
+    let mut changed_bytes = 0;
+    for change in &changes {
+        match *change {
+            SegmentChange::Removed { addr, len } => {
+                println!("Removed segment at {addr:#010x} ({len} bytes)");
+                changed_bytes += len;
+            }
+            SegmentChange::Added { addr, len } => {
+                println!("Added   segment at {addr:#010x} ({len} bytes)");
+                changed_bytes += len;
+            }
+            SegmentChange::Changed {
+                addr,
+                old_len,
+                new_len,
+                count,
+                first,
+            } => {
+                println!(
+                    "Changed segment at {addr:#010x} ({old_len} -> {new_len} bytes): {count} bytes differ, first at offset {first:#x}"
+                );
+                changed_bytes += count;
+            }
+        }
     }
+    let changed_segments = changes.len();
 
-    let chip = flasher.chip();
-    flasher
-        .connection()
-        .reset_after(!args.connect_args.no_stub, chip)?;
+    if changed_segments == 0 && header_fields.iter().all(|(_, old, new)| old == new) {
+        println!("The images are identical");
+    } else {
+        println!("{changed_bytes} bytes changed in {changed_segments} segment(s)");
+    }
 
     Ok(())
 }
 
+/// A difference between the segments of two application images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SegmentChange {
+    /// Only the old image has the segment
+    Removed { addr: u32, len: usize },
+    /// Only the new image has the segment
+    Added { addr: u32, len: usize },
+    /// Both images have the segment, but its contents differ
+    Changed {
+        addr: u32,
+        old_len: usize,
+        new_len: usize,
+        count: usize,
+        first: usize,
+    },
+}
+
+/// Compare the segments of two application images
+///
+/// Segments are paired up by their address, and by their position among the
+/// segments with the same address, as all padding segments are placed at
+/// address 0.
+fn diff_segments(old: &[Segment<'_>], new: &[Segment<'_>]) -> Vec<SegmentChange> {
+    let keyed = |segments: &[Segment<'_>]| {
+        let mut occurrences = HashMap::new();
+        segments
+            .iter()
+            .map(|segment| {
+                let occurrence = occurrences.entry(segment.addr).or_insert(0usize);
+                *occurrence += 1;
+                ((segment.addr, *occurrence), segment.data())
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+    let old = keyed(old);
+    let new = keyed(new);
+
+    let keys = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+    keys.into_iter()
+        .filter_map(|key @ &(addr, _)| match (old.get(key), new.get(key)) {
+            (Some(old), None) => Some(SegmentChange::Removed {
+                addr,
+                len: old.len(),
+            }),
+            (None, Some(new)) => Some(SegmentChange::Added {
+                addr,
+                len: new.len(),
+            }),
+            (Some(old), Some(new)) => {
+                diff_bytes(old, new).map(|(count, first)| SegmentChange::Changed {
+                    addr,
+                    old_len: old.len(),
+                    new_len: new.len(),
+                    count,
+                    first,
+                })
+            }
+            (None, None) => unreachable!(),
+        })
+        .collect()
+}
+
+/// Count the bytes which differ between `old` and `new`, including any bytes
+/// past the end of the shorter one, and find the offset of the first one
+///
+/// Returns `None` if both are identical.
+fn diff_bytes(old: &[u8], new: &[u8]) -> Option<(usize, usize)> {
+    let mismatches = old.iter().zip(new).map(|(a, b)| a != b);
+    let first = mismatches
+        .clone()
+        .position(|differs| differs)
+        .or_else(|| (old.len() != new.len()).then_some(old.len().min(new.len())))?;
+    let count = mismatches.filter(|&differs| differs).count() + old.len().abs_diff(new.len());
+
+    Some((count, first))
+}
+
 /// Convert and display CSV and binary partition tables
 pub fn partition_table(args: PartitionTableArgs) -> Result<()> {
+    if args.validate {
+        return validate_partition_table(&args.partition_table, args.partition_table_offset);
+    }
+
+    if let (Some(manifest), Some(image)) = (&args.manifest, &args.image) {
+        let table = parse_partition_table(&args.partition_table, args.partition_table_format)?;
+        let image =
+            fs::read(image).map_err(|e| Error::FileOpenError(image.display().to_string(), e))?;
+
+        let json =
+            serde_json::to_string_pretty(&partition_manifest(&table, &image)).into_diagnostic()?;
+        fs::write(manifest, json)
+            .map_err(|e| Error::FileOpenError(manifest.display().to_string(), e))?;
+
+        return Ok(());
+    }
+
     if args.to_binary {
-        let table = parse_partition_table(&args.partition_table)?;
+        let table = parse_partition_table(&args.partition_table, args.partition_table_format)?;
 
         // Use either stdout or a file if provided for the output.
         let mut writer: Box<dyn Write> = if let Some(output) = args.output {
@@ -991,11 +4160,16 @@ pub fn partition_table(args: PartitionTableArgs) -> Result<()> {
         };
 
         writer
-            .write_all(&table.to_bin().into_diagnostic()?)
+            .write_all(&to_idf_bin(&table, !args.no_md5)?)
             .into_diagnostic()?;
     } else if args.to_csv {
-        let input = fs::read(&args.partition_table).into_diagnostic()?;
-        let table = PartitionTable::try_from_bytes(input).into_diagnostic()?;
+        let table = parse_partition_table(
+            &args.partition_table,
+            Some(
+                args.partition_table_format
+                    .unwrap_or(PartitionTableFormat::Bin),
+            ),
+        )?;
 
         // Use either stdout or a file if provided for the output.
         let mut writer: Box<dyn Write> = if let Some(output) = args.output {
@@ -1008,8 +4182,7 @@ pub fn partition_table(args: PartitionTableArgs) -> Result<()> {
             .write_all(table.to_csv().into_diagnostic()?.as_bytes())
             .into_diagnostic()?;
     } else {
-        let input = fs::read(&args.partition_table).into_diagnostic()?;
-        let table = PartitionTable::try_from(input).into_diagnostic()?;
+        let table = parse_partition_table(&args.partition_table, args.partition_table_format)?;
 
         pretty_print(table);
     }
@@ -1017,11 +4190,87 @@ pub fn partition_table(args: PartitionTableArgs) -> Result<()> {
     Ok(())
 }
 
+/// Expected contents of a partition, as listed in a partition manifest
+#[derive(Debug, Serialize)]
+struct ManifestEntry {
+    offset: u32,
+    size: u32,
+    md5: String,
+}
+
+/// Compute the MD5 digest of each partition's region of a merged image, keyed
+/// by the partition labels
+fn partition_manifest(table: &PartitionTable, image: &[u8]) -> BTreeMap<String, ManifestEntry> {
+    table
+        .partitions()
+        .iter()
+        .map(|part| {
+            let start = (part.offset() as usize).min(image.len());
+            let end = (part.offset() as usize + part.size() as usize).min(image.len());
+
+            let mut hasher = Md5::new();
+            hasher.update(&image[start..end]);
+            let missing = part.size() as usize - (end - start);
+            hasher.update(vec![0xff; missing]);
+
+            let entry = ManifestEntry {
+                offset: part.offset(),
+                size: part.size(),
+                md5: encode_hex(hasher.finalize()),
+            };
+            (part.name().to_string(), entry)
+        })
+        .collect()
+}
+
+/// Validate a CSV partition table, printing every problem found
+fn validate_partition_table(path: &Path, partition_table_offset: u32) -> Result<()> {
+    use crossterm::style::Stylize;
+
+    let csv = fs::read_to_string(path)
+        .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+    let issues = validate_csv(&csv, partition_table_offset);
+
+    let mut errors = 0;
+    for issue in &issues {
+        let location = match issue.line {
+            Some(line) => format!("{}:{line}", path.display()),
+            None => path.display().to_string(),
+        };
+        let severity = match issue.severity {
+            Severity::Error => {
+                errors += 1;
+                "error".red()
+            }
+            _ => "warning".yellow(),
+        };
+        println!("{location}: {severity}: {}", issue.message);
+    }
+
+    if errors > 0 {
+        return Err(miette::miette!(
+            "The partition table contains {errors} error(s) and {} warning(s)",
+            issues.len() - errors
+        ));
+    }
+    info!("The partition table is valid ({} warning(s))", issues.len());
+
+    Ok(())
+}
+
 /// Parse a [PartitionTable] from the provided path
-pub fn parse_partition_table(path: &Path) -> Result<PartitionTable, Error> {
+///
+/// The format is detected from the contents of the file, unless given.
+pub fn parse_partition_table(
+    path: &Path,
+    format: Option<PartitionTableFormat>,
+) -> Result<PartitionTable, Error> {
     let data = fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
 
-    Ok(PartitionTable::try_from(data)?)
+    match format {
+        Some(format) => format.parse(data),
+        None => Ok(PartitionTable::try_from(data)?),
+    }
 }
 
 /// Pretty print a partition table
@@ -1073,6 +4322,30 @@ fn pretty_print(table: PartitionTable) {
     println!("{pretty}");
 }
 
+/// Pick the first bootloader supporting the chip revision (`major * 100 +
+/// minor`)
+fn select_bootloader_variant(
+    variants: &[PathBuf],
+    chip: Chip,
+    revision: u16,
+) -> Result<Option<PathBuf>, Error> {
+    for path in variants {
+        let bootloader =
+            fs::read(path).map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+        if bootloader_supports_revision(&bootloader, chip, revision) {
+            debug!(
+                "Using bootloader '{}' for chip revision v{}.{}",
+                path.display(),
+                revision / 100,
+                revision % 100
+            );
+            return Ok(Some(path.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
 /// Make an image format from the given arguments
 pub fn make_image_format<'a>(
     elf_data: &'a [u8],
@@ -1088,31 +4361,78 @@ pub fn make_image_format<'a>(
             let mut args = idf_format_args.unwrap_or_default();
             // Set bootloader path with precedence
             if args.bootloader.is_none() {
-                args.bootloader = config
+                args.bootloader = flash_data.bootloader.clone().or_else(|| {
+                    config
+                        .project_config
+                        .idf_format_args
+                        .bootloader
+                        .clone()
+                        .or(build_ctx_bootloader)
+                });
+            }
+
+            if args.bootloader_variant.is_empty() {
+                args.bootloader_variant = config
                     .project_config
                     .idf_format_args
-                    .bootloader
-                    .clone()
-                    .or(build_ctx_bootloader);
+                    .bootloader_variant
+                    .clone();
+            }
+            if let (None, Some(revision)) = (&args.bootloader, flash_data.chip_revision) {
+                args.bootloader =
+                    select_bootloader_variant(&args.bootloader_variant, flash_data.chip, revision)?;
             }
 
             // Set partition table path with precedence
             if args.partition_table.is_none() {
-                args.partition_table = config
+                args.partition_table = flash_data.partition_table.clone().or_else(|| {
+                    config
+                        .project_config
+                        .idf_format_args
+                        .partition_table
+                        .clone()
+                        .or(build_ctx_partition_table)
+                });
+            }
+            if args.partition_table_format.is_none() {
+                args.partition_table_format =
+                    config.project_config.idf_format_args.partition_table_format;
+            }
+            if args.extra_from_section.is_none() {
+                args.extra_from_section = config
                     .project_config
                     .idf_format_args
-                    .partition_table
-                    .clone()
-                    .or(build_ctx_partition_table);
+                    .extra_from_section
+                    .clone();
+            }
+            if args.extra_elf.is_empty() {
+                args.extra_elf = config.project_config.idf_format_args.extra_elf.clone();
             }
-            IdfBootloaderFormat::new(
+
+            let idf = IdfBootloaderFormat::new(
                 elf_data,
                 flash_data,
                 args.partition_table.as_deref(),
+                args.partition_table_format,
                 args.bootloader.as_deref(),
                 args.partition_table_offset,
                 args.target_app_partition.as_deref(),
-            )?
+            )?;
+            let idf = match args.ota_slot {
+                Some(slot) => idf.with_ota_slot(slot)?,
+                None => idf,
+            };
+            let mut idf = match args.extra_from_section.as_deref() {
+                Some(section) => idf.with_extra_from_section(elf_data, section)?,
+                None => idf,
+            };
+            for (path, addr) in &args.extra_elf {
+                let data = fs::read(path)
+                    .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?;
+                idf = idf.with_extra_elf(&data, *addr)?;
+            }
+
+            idf
         }
     };
 
@@ -1126,28 +4446,48 @@ pub fn make_flash_data(
     config: &Config,
     chip: Chip,
     xtal_freq: XtalFrequency,
-) -> FlashData {
+) -> Result<FlashData> {
     // Create flash settings with precedence
     let mode = flash_config_args
         .flash_mode
         .or(config.project_config.flash.mode);
     let size = flash_config_args
         .flash_size
-        .or(config.project_config.flash.size)
-        .or_else(|| Some(FlashSize::default()));
+        .or(config.project_config.flash.size);
     let freq = flash_config_args
         .flash_freq
         .or(config.project_config.flash.freq);
 
-    let flash_settings = FlashSettings::new(mode, size, freq);
+    let flash_data = FlashDataBuilder::new(chip, xtal_freq)
+        .flash_mode(mode)
+        .flash_size(size)
+        .flash_freq(freq)
+        .min_chip_rev(image_args.min_chip_rev)
+        .mmu_page_size(image_args.mmu_page_size)
+        .flash_base(flash_config_args.flash_base.unwrap_or_default())
+        .reproducible(image_args.reproducible)
+        .build()?;
+
+    Ok(flash_data)
+}
 
-    FlashData::new(
-        flash_settings,
-        image_args.min_chip_rev,
-        image_args.mmu_page_size,
-        chip,
-        xtal_freq,
-    )
+/// Defer the reset after flashing to the monitor, when monitoring over
+/// USB-Serial-JTAG
+///
+/// Output printed by the device while the host is not reading from a
+/// USB-Serial-JTAG port is lost, so resetting right after flashing loses the
+/// start of the boot log. Instead, the monitor resets the device once it is
+/// ready to capture its output.
+pub fn defer_reset_to_monitor(flasher: &mut Flasher, monitor_args: &mut MonitorConfigArgs) {
+    let connection = flasher.connection();
+    if connection.is_using_usb_serial_jtag()
+        && connection.after_operation() == ResetAfterOperation::HardReset
+        && !monitor_args.no_reset
+    {
+        debug!("Deferring the reset after flashing to the monitor");
+        flasher.set_reset_after_flash(false);
+        monitor_args.reset_on_start = true;
+    }
 }
 
 /// Write a binary to the flash memory of a target device
@@ -1166,23 +4506,42 @@ pub fn write_bin(args: WriteBinArgs, config: &Config) -> Result<()> {
     let mut buffer = Vec::with_capacity(size.try_into().into_diagnostic()?);
     f.read_to_end(&mut buffer).into_diagnostic()?;
 
+    let dump_metadata = if let Some(path) = &args.from_dump {
+        let metadata = DumpMetadata::load(path)?;
+        metadata.validate(args.address, &buffer)?;
+        Some(metadata)
+    } else {
+        None
+    };
+
     let mut flasher = connect(&args.connect_args, config, false, false)?;
+    if let Some(size) = args.flash_block_size {
+        flasher.set_flash_block_size(size as usize)?;
+    }
+    flasher.set_auto_baud_backoff(args.auto_baud_backoff);
     print_board_info(&mut flasher)?;
 
     let chip = flasher.chip();
+    if let Some(metadata) = dump_metadata {
+        if metadata.chip != chip {
+            return Err(miette::miette!(
+                "The dump was read from an {}, but the connected chip is an {chip}",
+                metadata.chip
+            ));
+        }
+    }
     let target_xtal_freq = chip.xtal_frequency(flasher.connection())?;
 
+    let mut monitor_args = args.monitor_args;
+    if args.monitor {
+        defer_reset_to_monitor(&mut flasher, &mut monitor_args);
+    }
+
     flasher.write_bin_to_flash(args.address, &buffer, &mut EspflashProgress::default())?;
 
     if args.monitor {
         let pid = flasher.connection().usb_pid();
-        let mut monitor_args = args.monitor_args;
-        if chip == Chip::Esp32c2
-            && target_xtal_freq == XtalFrequency::_26Mhz
-            && monitor_args.monitor_baud == 115_200
-        {
-            monitor_args.monitor_baud = 74_880;
-        }
+        adjust_monitor_baud(chip, target_xtal_freq, &mut monitor_args);
         monitor(
             flasher.into(),
             Vec::new(),
@@ -1199,6 +4558,7 @@ pub fn write_bin(args: WriteBinArgs, config: &Config) -> Result<()> {
 pub fn reset(args: ConnectArgs, config: &Config) -> Result<()> {
     let mut args = args.clone();
     args.no_stub = true;
+    args.stub = false;
     let mut flasher = connect(&args, config, true, true)?;
     info!("Resetting target device");
     flasher.connection().reset()?;
@@ -1214,11 +4574,43 @@ pub fn hold_in_reset(args: ConnectArgs, config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Ensures all loadable ELF segments lie within valid flash or RAM regions of
+/// the chip.
+pub fn check_elf_segments(chip: Chip, elf_data: &[u8], lenient: bool) -> Result<()> {
+    for (addr, size) in invalid_segments(chip, elf_data)? {
+        if !lenient {
+            return Err(Error::InvalidSegmentAddress { addr, size }.into());
+        }
+
+        warn!(
+            "ELF segment at {addr:#010x} ({size} bytes) is outside of any valid flash or RAM region"
+        );
+    }
+
+    Ok(())
+}
+
+/// Adjusts the monitor baud rate for devices whose ROM code assumes a different
+/// crystal frequency than the one in use.
+///
+/// Only the default baud rate is adjusted, as that is the one configured by the
+/// ROM code.
+pub fn adjust_monitor_baud(
+    chip: Chip,
+    xtal_freq: XtalFrequency,
+    monitor_args: &mut MonitorConfigArgs,
+) {
+    if monitor_args.monitor_baud == 115_200 {
+        monitor_args.monitor_baud = chip.rom_baud(xtal_freq);
+    }
+}
+
 /// Ensures the chip is compatible with the ELF file.
 pub fn ensure_chip_compatibility(chip: Chip, elf: Option<&[u8]>) -> Result<()> {
+    ensure_arch_compatibility(chip, elf)?;
+
     let metadata = Metadata::from_bytes(elf);
     let Some(elf_chip) = metadata.chip_name() else {
-        // No chip name in the ELF, assume compatible
         return Ok(());
     };
 
@@ -1232,11 +4624,43 @@ pub fn ensure_chip_compatibility(chip: Chip, elf: Option<&[u8]>) -> Result<()> {
     }
 }
 
+/// Ensures the CPU architecture of the ELF file matches the chip.
+fn ensure_arch_compatibility(chip: Chip, elf: Option<&[u8]>) -> Result<()> {
+    let Some(Ok(object)) = elf.map(object::File::parse) else {
+        return Ok(());
+    };
+
+    let expected = if chip.is_xtensa() {
+        Architecture::Xtensa
+    } else {
+        Architecture::Riscv32
+    };
+    let machine = object.architecture();
+    if machine == expected {
+        return Ok(());
+    }
+
+    let arch_name = |arch| match arch {
+        Architecture::Xtensa => "Xtensa".to_string(),
+        Architecture::Riscv32 => "RISC-V (32-bit)".to_string(),
+        other => format!("{other:?}"),
+    };
+
+    Err(Error::ElfArchitectureMismatch {
+        machine: arch_name(machine),
+        expected: arch_name(expected),
+        chip,
+    })
+    .into_diagnostic()
+}
+
 /// Check if the given arguments are valid for the ESP-IDF format
 pub fn check_idf_args(
     format: ImageFormatKind,
     erase_parts: &Option<Vec<String>>,
     erase_data_parts: &Option<Vec<DataType>>,
+    nvs_keys: &Option<PathBuf>,
+    preserve: &Option<Vec<String>>,
 ) -> Result<()> {
     if format != ImageFormatKind::EspIdf && (erase_parts.is_some() || erase_data_parts.is_some()) {
         return Err(miette::miette!(
@@ -1244,6 +4668,18 @@ pub fn check_idf_args(
         ));
     }
 
+    if format != ImageFormatKind::EspIdf && nvs_keys.is_some() {
+        return Err(miette::miette!(
+            "`nvs-keys` is only supported when using the `esp-idf` format."
+        ));
+    }
+
+    if format != ImageFormatKind::EspIdf && preserve.is_some() {
+        return Err(miette::miette!(
+            "`preserve` is only supported when using the `esp-idf` format."
+        ));
+    }
+
     Ok(())
 }
 
@@ -1258,6 +4694,188 @@ mod test {
         args: IdfFormatArgs,
     }
 
+    #[test]
+    fn test_check_segment_overlap() {
+        let segment = |addr, len| Segment {
+            addr,
+            data: Cow::Owned(vec![0xFF; len]),
+        };
+
+        assert!(check_segment_overlap(&[segment(0x0, 0x1000), segment(0x1000, 0x10)]).is_ok());
+        assert!(check_segment_overlap(&[segment(0x0, 0x1001), segment(0x1000, 0x10)]).is_err());
+        assert!(check_segment_overlap(&[segment(0x1000, 0x10), segment(0x1000, 0x10)]).is_err());
+        assert!(
+            check_segment_overlap(&[segment(0xFFFF_FFF0, 0x20), segment(0xFFFF_FFF8, 0x8)])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_board_info_csv_row() {
+        assert_eq!(csv_field("/dev/ttyUSB0"), "/dev/ttyUSB0");
+        assert_eq!(csv_field("COM3, hub"), "\"COM3, hub\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+
+        let info = DeviceInfo {
+            chip: Chip::Esp32c3,
+            revision: Some((0, 4)),
+            crystal_frequency: XtalFrequency::_40Mhz,
+            uart_clock: None,
+            baud_rate: 115_200,
+            flash_size: FlashSize::_4Mb,
+            features: Vec::new(),
+            mac_address: Some("aa:bb:cc:dd:ee:ff".into()),
+        };
+        assert_eq!(
+            board_info_csv_row(1_700_000_000, "usb,\"hub\"", &info),
+            format!(
+                "1700000000,\"usb,\"\"hub\"\"\",esp32c3,v0.4,aa:bb:cc:dd:ee:ff,{}\n",
+                FlashSize::_4Mb
+            )
+        );
+    }
+
+    #[test]
+    fn test_ensure_arch_compatibility() {
+        let xtensa = fs::read("tests/data/esp32").unwrap();
+        let riscv = fs::read("tests/data/esp32c3").unwrap();
+
+        assert!(ensure_arch_compatibility(Chip::Esp32, Some(&xtensa)).is_ok());
+        assert!(ensure_arch_compatibility(Chip::Esp32s3, Some(&xtensa)).is_ok());
+        assert!(ensure_arch_compatibility(Chip::Esp32c3, Some(&riscv)).is_ok());
+        assert!(ensure_arch_compatibility(Chip::Esp32c3, Some(&xtensa)).is_err());
+        assert!(ensure_arch_compatibility(Chip::Esp32, Some(&riscv)).is_err());
+    }
+
+    #[test]
+    fn test_partition_manifest() {
+        let csv = fs::read("tests/data/partitions.csv").unwrap();
+        let table = PartitionTable::try_from(csv).unwrap();
+
+        // Ends halfway through `phy_init`
+        let mut image = vec![0u8; 0xf800];
+        image[0x9000..0xf000].fill(0xaa);
+
+        let manifest = partition_manifest(&table, &image);
+        assert_eq!(manifest.len(), 3);
+
+        let nvs = &manifest["nvs"];
+        assert_eq!((nvs.offset, nvs.size), (0x9000, 0x6000));
+        assert_eq!(nvs.md5, encode_hex(Md5::digest(vec![0xaa; 0x6000])));
+
+        let mut phy_init = vec![0u8; 0x800];
+        phy_init.extend([0xff; 0x800]);
+        assert_eq!(manifest["phy_init"].md5, encode_hex(Md5::digest(phy_init)));
+
+        let factory = &manifest["factory"];
+        assert_eq!(factory.md5, encode_hex(Md5::digest(vec![0xff; 0x400000])));
+    }
+
+    #[test]
+    fn test_app_digest_region() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let flash_data = FlashDataBuilder::new(Chip::Esp32c3, XtalFrequency::_40Mhz)
+            .flash_base(0x100000)
+            .build()
+            .unwrap();
+        let image = ImageFormat::EspIdf(
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap(),
+        );
+
+        let (offset, len) = app_digest_region(&image).unwrap();
+        assert_eq!(offset, image.app_offset());
+        assert_eq!(offset, 0x110000);
+
+        // The region covers the whole application, including its digest
+        let app = image.app_segment();
+        assert_eq!(len as usize, app.data.len());
+        assert_eq!(
+            verify_appended_digest(&app.data[..len as usize]),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_default_baud() {
+        assert_eq!(default_baud(Some(0x0403), false), Some(921_600));
+        assert_eq!(default_baud(Some(0x10c4), false), Some(460_800));
+        // Native USB
+        assert_eq!(default_baud(Some(0x303a), true), None);
+        assert_eq!(default_baud(Some(0x10c4), true), None);
+        // Unknown adapters
+        assert_eq!(default_baud(Some(0x1234), false), None);
+        assert_eq!(default_baud(None, false), None);
+    }
+
+    #[test]
+    fn test_xtal_is_ambiguous() {
+        assert!(!xtal_is_ambiguous(XtalFrequency::_40Mhz, 40_000_000));
+        assert!(!xtal_is_ambiguous(XtalFrequency::_26Mhz, 26_265_600));
+        assert!(xtal_is_ambiguous(XtalFrequency::_40Mhz, 34_000_000));
+        assert!(xtal_is_ambiguous(XtalFrequency::_26Mhz, 32_000_000));
+    }
+
+    #[test]
+    fn test_base64_lines() {
+        assert!(base64_lines(&[], 76).is_empty());
+        assert_eq!(base64_lines(b"espflash", 76), vec!["ZXNwZmxhc2g="]);
+        assert_eq!(base64_lines(b"espflash", 4), vec!["ZXNw", "Zmxh", "c2g="]);
+    }
+
+    #[test]
+    fn test_complement_regions() {
+        assert_eq!(
+            complement_regions(0x1000, vec![]).unwrap(),
+            vec![(0, 0x1000)]
+        );
+        assert_eq!(
+            complement_regions(0x10000, vec![(0x9000, 0x6000), (0x0, 0x1000)]).unwrap(),
+            vec![(0x1000, 0x8000), (0xf000, 0x1000)]
+        );
+        // Overlapping and out-of-range regions are merged and clamped
+        assert_eq!(
+            complement_regions(
+                0x10000,
+                vec![(0x2000, 0x4000), (0x3000, 0x1000), (0xf000, 0x8000)]
+            )
+            .unwrap(),
+            vec![(0x0, 0x2000), (0x6000, 0x9000)]
+        );
+        // Kept regions which are not sector aligned are rejected
+        assert!(matches!(
+            complement_regions(0x10000, vec![(0x1000, 0x1000), (0x9000, 0x600)]),
+            Err(Error::InvalidEraseRegionArgument {
+                address: 0x9000,
+                size: 0x600
+            })
+        ));
+        assert!(complement_regions(0x10000, vec![(0x8800, 0x1000)]).is_err());
+    }
+
+    #[test]
+    fn test_hexdump_line() {
+        assert_eq!(
+            hexdump_line(0x8000, b"\xe9\x03AB", 4),
+            "00008000  e9 03 41 42  |..AB|"
+        );
+        assert_eq!(hexdump_line(0x8004, b"z", 4), "00008004  7a           |z|");
+    }
+
+    #[test]
+    fn test_pattern_search_across_chunks() {
+        let pattern = parse_byte_pattern("DE ?? BE EF").unwrap();
+        assert_eq!(pattern, vec![Some(0xde), None, Some(0xbe), Some(0xef)]);
+        assert!(parse_byte_pattern("DEA").is_err());
+
+        let mut search = PatternSearch::new(&pattern);
+        assert_eq!(search.feed(0x1000, &[0x00, 0xde, 0xad, 0xbe]), vec![]);
+        assert_eq!(
+            search.feed(0x1004, &[0xef, 0xde, 0x00, 0xbe, 0xef]),
+            vec![0x1001, 0x1005]
+        );
+    }
+
     #[test]
     fn test_parse_hex_partition_table_offset() {
         let command = "command --partition-table-offset 0x8000";
@@ -1285,4 +4903,173 @@ mod test {
         assert!(parse_u32("-123").is_err());
         assert!(parse_u32("12.34").is_err());
     }
+
+    #[test]
+    fn test_parse_hex_bytes() {
+        assert_eq!(
+            parse_hex_bytes("0xdeadbeef"),
+            Ok(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(parse_hex_bytes("52 42 0a"), Ok(vec![0x52, 0x42, 0x0a]));
+        assert!(parse_hex_bytes("de??").is_err());
+        assert!(parse_hex_bytes("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_u8() {
+        assert_eq!(parse_u8("0x00"), Ok(0x00));
+        assert_eq!(parse_u8("0xff"), Ok(0xff));
+        assert_eq!(parse_u8("42"), Ok(42));
+        assert!(parse_u8("0x100").is_err());
+        assert!(parse_u8("256").is_err());
+        assert!(parse_u8("").is_err());
+    }
+
+    #[test]
+    fn test_parse_byte_size() {
+        assert_eq!(parse_byte_size("4096"), Ok(4096));
+        assert_eq!(parse_byte_size("0x1000"), Ok(0x1000));
+        assert_eq!(parse_byte_size("64K"), Ok(64 * 1024));
+        assert_eq!(parse_byte_size("1MiB"), Ok(1 << 20));
+        assert_eq!(parse_byte_size("2 MiB"), Ok(2 << 20));
+        assert!(parse_byte_size("0").is_err());
+        assert!(parse_byte_size("4096M").is_err());
+        assert!(parse_byte_size("1GiB").is_err());
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_parse_usb_filter() {
+        let device = parse_usb_filter("303a:1001").unwrap();
+        assert_eq!((device.vid, device.pid), (0x303a, 0x1001));
+        let device = parse_usb_filter("0x10C4:0xEA60").unwrap();
+        assert_eq!((device.vid, device.pid), (0x10c4, 0xea60));
+
+        assert!(parse_usb_filter("303a").is_err());
+        assert!(parse_usb_filter("303a:").is_err());
+        assert!(parse_usb_filter("12345:1001").is_err());
+    }
+
+    #[test]
+    fn test_parse_extra_elf() {
+        assert_eq!(
+            parse_extra_elf("ulp.elf@0x200000"),
+            Ok((PathBuf::from("ulp.elf"), 0x200000))
+        );
+        assert!(parse_extra_elf("ulp.elf").is_err());
+        assert!(parse_extra_elf("ulp.elf@zz").is_err());
+    }
+
+    #[test]
+    fn test_parse_append_image() {
+        assert_eq!(
+            parse_append_image("0x3f0000=factory.bin"),
+            Ok((0x3f0000, PathBuf::from("factory.bin")))
+        );
+        assert!(parse_append_image("factory.bin").is_err());
+        assert!(parse_append_image("0xzz=factory.bin").is_err());
+    }
+
+    #[test]
+    fn test_name_template() {
+        assert!(parse_name_template("{file}.bin").is_err());
+
+        let template = parse_name_template("{offset:#x}-{index}-{file}").unwrap();
+        assert_eq!(
+            render_name_template(&template, 0x10000, 1, "app.bin"),
+            "0x10000-1-app.bin"
+        );
+        assert_eq!(
+            render_name_template("{offset}.bin", 0x10000, 0, "app.bin"),
+            "65536.bin"
+        );
+    }
+
+    #[test]
+    fn test_diff_bytes() {
+        assert_eq!(diff_bytes(b"abcd", b"abcd"), None);
+        assert_eq!(diff_bytes(b"abcd", b"abxy"), Some((2, 2)));
+        assert_eq!(diff_bytes(b"abcd", b"abcdef"), Some((2, 4)));
+        assert_eq!(diff_bytes(b"xbcd", b"ab"), Some((3, 0)));
+    }
+
+    #[test]
+    fn test_diff_segments() {
+        // Builds an application image with the given segments
+        fn image(segments: &[(u32, &[u8])]) -> Vec<u8> {
+            let mut image = vec![0xe9, segments.len() as u8, 2, 0x20];
+            image.extend_from_slice(&[0; 20]);
+            for (addr, data) in segments {
+                image.extend_from_slice(&addr.to_le_bytes());
+                image.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                image.extend_from_slice(data);
+            }
+            image
+        }
+
+        // Both images have two padding segments at address 0, only the second
+        // of which changes
+        let old = image(&[
+            (0x3c00_0020, b"rodata"),
+            (0, &[0; 8]),
+            (0x4200_0020, b"text"),
+            (0, &[0; 4]),
+        ]);
+        let new = image(&[
+            (0x3c00_0020, b"rodata"),
+            (0, &[0; 8]),
+            (0x4200_0020, b"text"),
+            (0, &[0, 0, 1, 0, 0, 0]),
+        ]);
+        let old = image_segments(&old).unwrap();
+        let new = image_segments(&new).unwrap();
+        assert_eq!(
+            diff_segments(&old, &new),
+            vec![SegmentChange::Changed {
+                addr: 0,
+                old_len: 4,
+                new_len: 6,
+                count: 3,
+                first: 2,
+            }]
+        );
+        assert!(diff_segments(&old, &old).is_empty());
+
+        // A padding segment which only one of the images has is reported as
+        // such, instead of being compared against another padding segment
+        let fewer = image(&[(0, &[0; 8]), (0x4200_0020, b"text")]);
+        let fewer = image_segments(&fewer).unwrap();
+        assert_eq!(
+            diff_segments(&old, &fewer),
+            vec![
+                SegmentChange::Removed { addr: 0, len: 4 },
+                SegmentChange::Removed {
+                    addr: 0x3c00_0020,
+                    len: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dump_metadata_validate() {
+        let data = [0xAAu8; 16];
+        let metadata = DumpMetadata {
+            offset: 0x1000,
+            length: data.len() as u32,
+            chip: Chip::Esp32c3,
+            sha256: encode_hex(Sha256::digest(data)),
+            parts: Vec::new(),
+        };
+
+        assert!(metadata.validate(0x1000, &data).is_ok());
+        assert!(metadata.validate(0x2000, &data).is_err());
+        assert!(metadata.validate(0x1000, &data[..8]).is_err());
+        assert!(metadata.validate(0x1000, &[0x55u8; 16]).is_err());
+    }
 }
@@ -0,0 +1,90 @@
+//! Detection of output received at the wrong baud rate
+
+/// Number of bytes to inspect before deciding whether the output is garbled
+const SAMPLE_SIZE: usize = 256;
+
+/// Baud rates the ROM code and applications commonly use
+///
+/// 74 880 is what 115 200 turns into when the ROM code assumes a 40 MHz
+/// crystal while a 26 MHz one is fitted, as is common with the ESP32-C2.
+const COMMON_BAUDS: [u32; 2] = [115_200, 74_880];
+
+/// Looks at the start of the serial output and checks whether it is mostly
+/// unprintable, which usually means the monitor baud rate is wrong
+pub(super) struct BaudHint {
+    baud: u32,
+    seen: usize,
+    garbled: usize,
+    done: bool,
+}
+
+impl BaudHint {
+    pub(super) fn new(baud: u32) -> Self {
+        Self {
+            baud,
+            seen: 0,
+            garbled: 0,
+            done: false,
+        }
+    }
+
+    /// Feed received bytes, returning a suggested baud rate the first time
+    /// the output looks garbled
+    pub(super) fn feed(&mut self, bytes: &[u8]) -> Option<u32> {
+        if self.done {
+            return None;
+        }
+
+        for &byte in bytes.iter().take(SAMPLE_SIZE - self.seen) {
+            self.seen += 1;
+            if !is_printable(byte) {
+                self.garbled += 1;
+            }
+        }
+
+        if self.seen < SAMPLE_SIZE {
+            return None;
+        }
+        self.done = true;
+
+        // Real output occasionally contains escape sequences or UTF-8, so only
+        // complain if a sizeable part of it is unprintable
+        if self.garbled * 4 < self.seen {
+            return None;
+        }
+
+        COMMON_BAUDS.iter().copied().find(|&baud| baud != self.baud)
+    }
+}
+
+fn is_printable(byte: u8) -> bool {
+    byte.is_ascii_graphic() || byte.is_ascii_whitespace() || byte == 0x1b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readable_output_is_not_reported() {
+        let mut hint = BaudHint::new(115_200);
+        let text = b"I (42) boot: ESP-IDF v5.4 2nd stage bootloader\r\n".repeat(8);
+
+        assert_eq!(hint.feed(&text), None);
+        assert!(hint.done);
+    }
+
+    #[test]
+    fn garbled_output_suggests_other_baud() {
+        let garbage = (0..=255u8).map(|b| b | 0x80).collect::<Vec<_>>();
+
+        let mut hint = BaudHint::new(115_200);
+        assert_eq!(hint.feed(&garbage[..100]), None);
+        assert_eq!(hint.feed(&garbage[100..]), Some(74_880));
+        // Only reported once
+        assert_eq!(hint.feed(&garbage), None);
+
+        let mut hint = BaudHint::new(74_880);
+        assert_eq!(hint.feed(&garbage), Some(115_200));
+    }
+}
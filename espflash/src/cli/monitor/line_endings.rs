@@ -1,61 +1,68 @@
 // Adapted from: https://github.com/derekdreery/normalize-line-endings
 
-struct Normalized<I> {
-    iter: I,
+/// Normalizes line endings to CRLF
+///
+/// The state is kept across chunks of input, so that a CRLF pair split between
+/// two reads is still recognized as a single line ending.
+#[derive(Debug, Default)]
+pub(crate) struct Normalizer {
     prev_was_cr: bool,
-    peeked: Option<u8>,
 }
 
-impl<I> Iterator for Normalized<I>
-where
-    I: Iterator<Item = u8>,
-{
-    type Item = u8;
-
-    fn next(&mut self) -> Option<u8> {
-        if let Some(peeked) = self.peeked.take() {
-            return Some(peeked);
-        }
-
-        match self.iter.next() {
-            Some(b'\n') if !self.prev_was_cr => {
-                self.peeked = Some(b'\n');
-                self.prev_was_cr = false;
-                Some(b'\r')
-            }
-            Some(b'\r') => {
-                self.prev_was_cr = true;
-                Some(b'\r')
-            }
-            any => {
-                self.prev_was_cr = false;
-                any
+impl Normalizer {
+    /// Normalize the line endings of the next chunk of input, appending the
+    /// result to `out`.
+    ///
+    /// If `cr_is_eol` is set, a lone CR is treated as a line ending as well.
+    pub(crate) fn normalize(&mut self, input: &[u8], cr_is_eol: bool, out: &mut Vec<u8>) {
+        for &byte in input {
+            match byte {
+                b'\n' if !self.prev_was_cr => out.extend_from_slice(b"\r\n"),
+                // The LF of a CRLF pair has already been emitted when the CR was seen.
+                b'\n' if cr_is_eol => {}
+                b'\r' if cr_is_eol => out.extend_from_slice(b"\r\n"),
+                any => out.push(any),
             }
+            self.prev_was_cr = byte == b'\r';
         }
     }
 }
 
-/// Normalize line endings to CRLF.
-pub(crate) fn normalized(iter: impl Iterator<Item = u8>) -> impl Iterator<Item = u8> {
-    Normalized {
-        iter,
-        prev_was_cr: false,
-        peeked: None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use std::iter::FromIterator;
-
     use super::*;
 
+    fn normalize(chunks: &[&[u8]], cr_is_eol: bool) -> Vec<u8> {
+        let mut normalizer = Normalizer::default();
+        let mut out = Vec::new();
+        for chunk in chunks {
+            normalizer.normalize(chunk, cr_is_eol, &mut out);
+        }
+        out
+    }
+
     #[test]
     fn test_normalized() {
         let input = b"This is a string \n with \n some \n\r\n random newlines\r\n\n";
         assert_eq!(
-            &Vec::from_iter(normalized(input.iter().copied())),
+            normalize(&[input], false),
             b"This is a string \r\n with \r\n some \r\n\r\n random newlines\r\n\r\n"
         );
     }
+
+    #[test]
+    fn test_cr_normalized() {
+        let input = b"Old \r style \r\n mixed \n\r newlines\r";
+        assert_eq!(
+            normalize(&[input], true),
+            b"Old \r\n style \r\n mixed \r\n\r\n newlines\r\n"
+        );
+    }
+
+    #[test]
+    fn test_split_crlf() {
+        assert_eq!(normalize(&[b"one\r", b"\ntwo\n"], false), b"one\r\ntwo\r\n");
+        assert_eq!(normalize(&[b"one\r", b"\ntwo\r"], true), b"one\r\ntwo\r\n");
+        assert_eq!(normalize(&[b"one\r", b"\r\n"], true), b"one\r\n\r\n");
+    }
 }
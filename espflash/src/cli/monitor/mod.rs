@@ -11,16 +11,19 @@
 //! in our monitor the output is displayed immediately upon reading.
 
 use std::{
+    fs::File,
     io::{self, ErrorKind, Read, Write, stdout},
-    time::{Duration, Instant},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+use baud_hint::BaudHint;
 use crossterm::{
     event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, poll, read},
     terminal::{disable_raw_mode, enable_raw_mode},
 };
 use external_processors::ExternalProcessors;
-use log::{debug, error, warn};
+use log::{debug, error, info, warn};
 use miette::{IntoDiagnostic, Result};
 #[cfg(feature = "serialport")]
 use serialport::SerialPort;
@@ -38,6 +41,7 @@ use crate::{
 pub mod external_processors;
 pub mod parser;
 
+mod baud_hint;
 mod line_endings;
 mod stack_dump;
 mod symbols;
@@ -54,6 +58,74 @@ pub enum LogFormat {
     Serial,
 }
 
+/// Verbosity of an ESP-IDF style log line, most severe first.
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Display, EnumIter, EnumString, VariantNames,
+)]
+#[non_exhaustive]
+#[strum(serialize_all = "lowercase")]
+pub enum LogLevel {
+    /// Errors only (`E`)
+    #[cfg_attr(feature = "cli", value(alias = "e"))]
+    Error,
+    /// Warnings and above (`W`)
+    #[cfg_attr(feature = "cli", value(alias = "w"))]
+    Warn,
+    /// Informational messages and above (`I`)
+    #[cfg_attr(feature = "cli", value(alias = "i"))]
+    Info,
+    /// Debug messages and above (`D`)
+    #[cfg_attr(feature = "cli", value(alias = "d"))]
+    Debug,
+    /// Everything (`V`)
+    #[cfg_attr(feature = "cli", value(alias = "v"))]
+    Verbose,
+}
+
+impl LogLevel {
+    /// The level belonging to the letter ESP-IDF prefixes log lines with.
+    pub fn from_letter(letter: u8) -> Option<Self> {
+        match letter {
+            b'E' => Some(Self::Error),
+            b'W' => Some(Self::Warn),
+            b'I' => Some(Self::Info),
+            b'D' => Some(Self::Debug),
+            b'V' => Some(Self::Verbose),
+            _ => None,
+        }
+    }
+}
+
+/// Line ending convention used by the device.
+///
+/// Controls how received line endings are translated for display and how
+/// typed input is terminated when pressing Enter.
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter, EnumString, VariantNames)]
+#[non_exhaustive]
+#[strum(serialize_all = "lowercase")]
+pub enum LineEnding {
+    /// Lines end with LF, Enter sends LF
+    Lf,
+    /// Lines end with CRLF, Enter sends CRLF
+    Crlf,
+    /// Lines end with CR, Enter sends CR
+    Cr,
+    /// Received data is displayed untranslated, Enter sends CR
+    Raw,
+}
+
+impl LineEnding {
+    fn enter_sequence(line_ending: Option<LineEnding>) -> &'static [u8] {
+        match line_ending {
+            Some(LineEnding::Lf) => b"\n",
+            Some(LineEnding::Crlf) => b"\r\n",
+            _ => b"\r",
+        }
+    }
+}
+
 /// Type that ensures that raw mode is disabled when dropped.
 struct RawModeGuard;
 
@@ -87,9 +159,12 @@ pub fn monitor(
         println!("    CTRL+R    Reset chip");
         println!("    CTRL+C    Exit");
         println!();
-    } else if !monitor_args.no_reset {
-        reset_after_flash(&mut serial, pid).into_diagnostic()?;
     }
+    let reset = if non_interactive {
+        !monitor_args.no_reset
+    } else {
+        monitor_args.reset_on_start
+    };
 
     let baud = monitor_args.monitor_baud;
     debug!("Opening serial monitor with baudrate: {baud}");
@@ -101,6 +176,18 @@ pub fn monitor(
         .set_timeout(Duration::from_millis(5))
         .into_diagnostic()?;
 
+    let mut log_file = None;
+    if monitor_args.append_log_to_elf_dir {
+        match &monitor_args.elf {
+            Some(elf) => {
+                let path = elf_dir_log_path(elf)?;
+                info!("Writing monitor output to '{}'", path.display());
+                log_file = Some(File::create(path).into_diagnostic()?);
+            }
+            None => warn!("No ELF file given, not writing the monitor output to a log file"),
+        }
+    }
+
     // We are in raw mode until `_raw_mode` is dropped (ie. this function returns).
     let _raw_mode = RawModeGuard::new();
 
@@ -110,29 +197,46 @@ pub fn monitor(
         ResolvingPrinter::new_no_addresses(firmware_elf, stdout.lock())
     } else {
         ResolvingPrinter::new(elfs, stdout.lock(), monitor_args.all_addresses)
-    };
+    }
+    .with_line_ending(monitor_args.eol);
 
-    let mut parser: Box<dyn InputParser> = match monitor_args
+    let log_format = monitor_args
         .log_format
-        .unwrap_or_else(|| deduce_log_format(firmware_elf))
-    {
-        LogFormat::Defmt => Box::new(parser::esp_defmt::EspDefmt::new(
-            firmware_elf,
-            monitor_args.output_format,
-        )?),
+        .unwrap_or_else(|| deduce_log_format(firmware_elf));
+    let mut parser: Box<dyn InputParser> = match log_format {
+        LogFormat::Defmt => {
+            if monitor_args.log_level.is_some() {
+                warn!("Log level specified but log format is defmt. Ignoring log level.");
+            }
+            Box::new(parser::esp_defmt::EspDefmt::new(
+                firmware_elf,
+                monitor_args.output_format,
+            )?)
+        }
         LogFormat::Serial => {
             if monitor_args.output_format.is_some() {
                 warn!("Output format specified but log format is serial. Ignoring output format.");
             }
-            Box::new(parser::serial::Serial)
+            match monitor_args.log_level {
+                Some(level) => Box::new(parser::serial::LevelFilter::new(level)),
+                None => Box::new(parser::serial::Serial),
+            }
         }
     };
 
     let mut external_processors =
         ExternalProcessors::new(monitor_args.processors, monitor_args.elf)?;
 
+    // Only reset once everything is set up, so that no output is lost.
+    if reset {
+        reset_after_flash(&mut serial, pid).into_diagnostic()?;
+    }
+
+    // defmt output is binary, so it can't be checked for readability
+    let mut baud_hint = (log_format == LogFormat::Serial).then(|| BaudHint::new(baud));
+
     let mut buff = [0; 1024];
-    let mut user_input_handler = InputHandler::new(pid, non_interactive);
+    let mut user_input_handler = InputHandler::new(pid, non_interactive, monitor_args.eol);
     loop {
         let read_count = match serial.read(&mut buff) {
             Ok(count) => Ok(count),
@@ -141,8 +245,23 @@ pub fn monitor(
             err => err.into_diagnostic(),
         }?;
 
+        if let Some(suggested) = baud_hint
+            .as_mut()
+            .and_then(|h| h.feed(&buff[0..read_count]))
+        {
+            warn!("Output looks garbled; try `--monitor-baud {suggested}`");
+        }
+
         let processed = external_processors.process(&buff[0..read_count]);
-        parser.feed(&processed, &mut stdout);
+        if let Some(log_file) = &mut log_file {
+            // Keep a copy of the decoded output, before any address resolution
+            let mut decoded = Vec::new();
+            parser.feed(&processed, &mut decoded);
+            log_file.write_all(&decoded).into_diagnostic()?;
+            stdout.write_all(&decoded).into_diagnostic()?;
+        } else {
+            parser.feed(&processed, &mut stdout);
+        }
 
         // Don't forget to flush the writer!
         stdout.flush().ok();
@@ -155,17 +274,31 @@ pub fn monitor(
     Ok(())
 }
 
+/// Path of a new log file next to the given ELF image, named after the image
+/// and the current time
+fn elf_dir_log_path(elf: &Path) -> Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .into_diagnostic()?
+        .as_secs();
+    let stem = elf.file_stem().unwrap_or_default().to_string_lossy();
+
+    Ok(elf.with_file_name(format!("{stem}-{timestamp}.log")))
+}
+
 struct InputHandler {
     pid: u16,
     non_interactive: bool,
+    line_ending: Option<LineEnding>,
     flush_deadline: Option<Instant>,
 }
 
 impl InputHandler {
-    fn new(pid: u16, non_interactive: bool) -> Self {
+    fn new(pid: u16, non_interactive: bool, line_ending: Option<LineEnding>) -> Self {
         Self {
             pid,
             non_interactive,
+            line_ending,
             flush_deadline: None,
         }
     }
@@ -215,7 +348,7 @@ impl InputHandler {
             }
 
             self.flush_if_needed(serial)?;
-            if let Some(bytes) = handle_key_event(key) {
+            if let Some(bytes) = handle_key_event(key, self.line_ending) {
                 serial
                     .write_all(&bytes)
                     .ignore_timeout()
@@ -409,7 +542,7 @@ fn deduce_log_format(elf: Option<&[u8]>) -> LogFormat {
 // sequences which are then sent over the serial connection.
 //
 // Adapted from: https://github.com/dhylands/serial-monitor
-fn handle_key_event(key_event: KeyEvent) -> Option<Vec<u8>> {
+fn handle_key_event(key_event: KeyEvent, line_ending: Option<LineEnding>) -> Option<Vec<u8>> {
     // The following escape sequences come from the MicroPython codebase.
     //
     //  Up      ESC [A
@@ -425,7 +558,7 @@ fn handle_key_event(key_event: KeyEvent) -> Option<Vec<u8>> {
 
     let key_str: Option<&[u8]> = match key_event.code {
         KeyCode::Backspace => Some(b"\x08"),
-        KeyCode::Enter => Some(b"\r"),
+        KeyCode::Enter => Some(LineEnding::enter_sequence(line_ending)),
         KeyCode::Left => Some(b"\x1b[D"),
         KeyCode::Right => Some(b"\x1b[C"),
         KeyCode::Home => Some(b"\x1b[H"),
@@ -476,6 +609,7 @@ pub fn check_monitor_args(
             || monitor_args.no_reset
             || monitor_args.no_addresses
             || monitor_args.all_addresses
+            || monitor_args.eol.is_some()
             || monitor_args.monitor_baud != 115_200)
     {
         warn!(
@@ -6,7 +6,7 @@ use crossterm::{
 };
 use regex::Regex;
 
-use crate::cli::monitor::{line_endings::normalized, stack_dump, symbols::Symbols};
+use crate::cli::monitor::{LineEnding, line_endings::Normalizer, stack_dump, symbols::Symbols};
 
 pub mod esp_defmt;
 pub mod serial;
@@ -76,18 +76,26 @@ fn resolve_addresses(
 #[derive(Debug)]
 struct Utf8Merger {
     incomplete_utf8_buffer: Vec<u8>,
+    line_ending: Option<LineEnding>,
+    normalizer: Normalizer,
 }
 
 impl Utf8Merger {
     fn new() -> Self {
         Self {
             incomplete_utf8_buffer: Vec::new(),
+            line_ending: None,
+            normalizer: Normalizer::default(),
         }
     }
 
     fn process_utf8(&mut self, buff: &[u8]) -> String {
         let mut buffer = std::mem::take(&mut self.incomplete_utf8_buffer);
-        buffer.extend(normalized(buff.iter().copied()));
+        match self.line_ending {
+            Some(LineEnding::Raw) => buffer.extend_from_slice(buff),
+            Some(LineEnding::Cr) => self.normalizer.normalize(buff, true, &mut buffer),
+            _ => self.normalizer.normalize(buff, false, &mut buffer),
+        }
 
         // look for longest slice that we can then lossily convert without introducing
         // errors for partial sequences (#457)
@@ -161,6 +169,13 @@ impl<'ctx, W: Write> ResolvingPrinter<'ctx, W> {
             try_resolve_all_addresses: false,
         }
     }
+
+    /// Sets how line endings received from the device are translated for
+    /// display.
+    pub fn with_line_ending(mut self, line_ending: Option<LineEnding>) -> Self {
+        self.merger.line_ending = line_ending;
+        self
+    }
 }
 
 impl<W: Write> Write for ResolvingPrinter<'_, W> {
@@ -169,17 +184,22 @@ impl<W: Write> Write for ResolvingPrinter<'_, W> {
 
         // Split the text into lines, storing the last of which separately if it is
         // incomplete (ie. does not end with '\n') because these need special handling.
-        let mut lines = text.lines().collect::<Vec<_>>();
+        let mut lines = text.split_inclusive('\n').collect::<Vec<_>>();
         let incomplete = if text.ends_with('\n') {
             None
         } else {
             lines.pop()
         };
+        let raw = self.merger.line_ending == Some(LineEnding::Raw);
 
         // Iterate through all *complete* lines (ie. those ending with '\n') ...
-        for line in lines {
-            // ... and print the line.
-            self.writer.queue(Print(line))?;
+        for terminated in lines {
+            let line = terminated.strip_suffix('\n').unwrap_or(terminated);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            // ... and print the line, as received if no translation is requested.
+            self.writer
+                .queue(Print(if raw { terminated } else { line }))?;
 
             // If there is a previous line fragment, that means that the current line must
             // be appended to it in order to form the complete line. Since we want to look
@@ -194,7 +214,9 @@ impl<W: Write> Write for ResolvingPrinter<'_, W> {
             };
 
             // Remember to begin a new line after we have printed this one!
-            self.writer.queue(Print("\r\n"))?;
+            if !raw {
+                self.writer.queue(Print("\r\n"))?;
+            }
 
             // If we have loaded some symbols and address resolution is not disabled...
             if !self.disable_address_resolution {
@@ -241,7 +263,7 @@ impl<W: Write> Write for ResolvingPrinter<'_, W> {
 
 #[cfg(test)]
 mod test {
-    use super::Utf8Merger;
+    use super::{LineEnding, Utf8Merger};
 
     #[test]
     fn returns_valid_strings_immediately() {
@@ -294,6 +316,17 @@ mod test {
         assert_eq!(text, "🙈");
     }
 
+    #[test]
+    fn keeps_line_ending_state_across_reads() {
+        let mut ctx = Utf8Merger::new();
+        ctx.line_ending = Some(LineEnding::Cr);
+
+        let mut result = ctx.process_utf8(b"first\r");
+        result.push_str(&ctx.process_utf8(b"\nsecond\r"));
+        result.push_str(&ctx.process_utf8(b"third\n"));
+        assert_eq!(result, "first\r\nsecond\r\nthird\r\n");
+    }
+
     #[test]
     fn issue_457() {
         let mut ctx = Utf8Merger::new();
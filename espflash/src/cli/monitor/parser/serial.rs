@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use crate::cli::monitor::parser::InputParser;
+use crate::cli::monitor::{LogLevel, parser::InputParser};
 
 /// Serial parser.
 #[derive(Debug)]
@@ -11,3 +11,192 @@ impl InputParser for Serial {
         out.write_all(bytes).unwrap();
     }
 }
+
+/// Serial parser hiding ESP-IDF log lines less severe than a given level.
+///
+/// Lines which don't start with an ESP-IDF log prefix are passed through.
+#[derive(Debug)]
+pub struct LevelFilter {
+    max_level: LogLevel,
+    line: LineState,
+    pending: Vec<u8>,
+}
+
+/// What to do with the rest of the line currently being received
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum LineState {
+    /// Not enough of the line has been seen to know its log level
+    #[default]
+    Undecided,
+    Show,
+    Hide,
+}
+
+/// Outcome of matching the start of a line against the ESP-IDF log prefix
+#[derive(Debug, PartialEq, Eq)]
+enum Prefix {
+    Incomplete,
+    NoMatch,
+    Level(LogLevel),
+}
+
+impl LevelFilter {
+    /// Creates a serial parser hiding ESP-IDF log lines less severe than
+    /// `max_level`.
+    pub fn new(max_level: LogLevel) -> Self {
+        Self {
+            max_level,
+            line: LineState::default(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl InputParser for LevelFilter {
+    fn feed(&mut self, bytes: &[u8], out: &mut dyn Write) {
+        let max_level = self.max_level;
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (chunk, ends_line) = match rest.iter().position(|&b| b == b'\n') {
+                Some(end) => (&rest[..=end], true),
+                None => (rest, false),
+            };
+            rest = &rest[chunk.len()..];
+
+            if self.line == LineState::Undecided {
+                self.pending.extend_from_slice(chunk);
+                self.line = match parse_prefix(&self.pending) {
+                    // A line ending before the prefix is complete cannot be a log line
+                    Prefix::Incomplete if ends_line => LineState::Show,
+                    Prefix::Incomplete => LineState::Undecided,
+                    Prefix::NoMatch => LineState::Show,
+                    Prefix::Level(level) if level <= max_level => LineState::Show,
+                    Prefix::Level(_) => LineState::Hide,
+                };
+
+                if self.line == LineState::Show {
+                    out.write_all(&self.pending).unwrap();
+                }
+                if self.line != LineState::Undecided {
+                    self.pending.clear();
+                }
+            } else if self.line == LineState::Show {
+                out.write_all(chunk).unwrap();
+            }
+
+            if ends_line {
+                self.line = LineState::Undecided;
+                self.pending.clear();
+            }
+        }
+    }
+}
+
+/// Matches `[ESC[..m]X (timestamp) ` at the start of `line`, where `X` is one
+/// of the ESP-IDF log level letters.
+fn parse_prefix(line: &[u8]) -> Prefix {
+    let mut rest = line;
+
+    // Colored output starts with an SGR escape sequence
+    if rest.first() == Some(&0x1b) {
+        match rest.iter().position(|&b| b == b'm') {
+            Some(end) => {
+                if rest.get(1) != Some(&b'[')
+                    || !rest[2..end]
+                        .iter()
+                        .all(|b| b.is_ascii_digit() || *b == b';')
+                {
+                    return Prefix::NoMatch;
+                }
+                rest = &rest[end + 1..];
+            }
+            None if rest
+                .iter()
+                .all(|b| b.is_ascii_digit() || b"\x1b[;".contains(b)) =>
+            {
+                return Prefix::Incomplete;
+            }
+            None => return Prefix::NoMatch,
+        }
+    }
+
+    let Some(&letter) = rest.first() else {
+        return Prefix::Incomplete;
+    };
+    let Some(level) = LogLevel::from_letter(letter) else {
+        return Prefix::NoMatch;
+    };
+
+    match &rest[1..] {
+        [] | [b' '] => return Prefix::Incomplete,
+        [b' ', b'(', ..] => {}
+        _ => return Prefix::NoMatch,
+    }
+
+    // The timestamp is either milliseconds since boot or a wall clock time
+    let timestamp = &rest[3..];
+    let len = timestamp
+        .iter()
+        .take_while(|&&b| b.is_ascii_digit() || b":.".contains(&b))
+        .count();
+    match &timestamp[len..] {
+        [] | [b')'] => Prefix::Incomplete,
+        [b')', b' ', ..] if len > 0 => Prefix::Level(level),
+        _ => Prefix::NoMatch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(max_level: LogLevel, chunks: &[&[u8]]) -> String {
+        let mut parser = LevelFilter::new(max_level);
+        let mut out = Vec::new();
+        for chunk in chunks {
+            parser.feed(chunk, &mut out);
+        }
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn parses_log_prefix() {
+        assert_eq!(
+            parse_prefix(b"W (123) wifi: "),
+            Prefix::Level(LogLevel::Warn)
+        );
+        assert_eq!(
+            parse_prefix(b"\x1b[0;32mI (12:00:01.250) main: "),
+            Prefix::Level(LogLevel::Info)
+        );
+        assert_eq!(parse_prefix(b"\x1b[0;3"), Prefix::Incomplete);
+        assert_eq!(parse_prefix(b"D (12"), Prefix::Incomplete);
+        assert_eq!(parse_prefix(b"Hello"), Prefix::NoMatch);
+        assert_eq!(parse_prefix(b"I () x"), Prefix::NoMatch);
+        assert_eq!(parse_prefix(b"E (1a) x"), Prefix::NoMatch);
+    }
+
+    #[test]
+    fn hides_less_severe_lines() {
+        let output = filter(
+            LogLevel::Warn,
+            &[b"E (1) a: err\r\nI (2) b: info\r\nW (3) c: warn\r\nplain\r\n"],
+        );
+        assert_eq!(output, "E (1) a: err\r\nW (3) c: warn\r\nplain\r\n");
+    }
+
+    #[test]
+    fn handles_lines_split_across_reads() {
+        let output = filter(
+            LogLevel::Info,
+            &[
+                b"D (1",
+                b"0) x: hidden\r",
+                b"\nI (11) x: sh",
+                b"own\r\nprompt> ",
+            ],
+        );
+        assert_eq!(output, "I (11) x: shown\r\nprompt> ");
+    }
+}
@@ -72,6 +72,24 @@ pub fn serial_port_info(matches: &ConnectArgs, config: &Config) -> Result<Serial
     }
 }
 
+/// Returns all USB serial ports of devices matching the given VID and PID, or
+/// of known or configured devices if no filter is given.
+pub(super) fn matching_serial_ports(
+    filter: Option<&UsbDevice>,
+    config: &Config,
+) -> Result<Vec<SerialPortInfo>> {
+    let ports = detect_usb_serial_ports(false)?
+        .into_iter()
+        .filter(|port| match (&port.port_type, filter) {
+            (SerialPortType::UsbPort(info), Some(filter)) => filter.matches(info),
+            (_, Some(_)) => false,
+            (_, None) => known_ports_filter(port, &config.port_config),
+        })
+        .collect();
+
+    Ok(ports)
+}
+
 /// Given a vector of `SerialPortInfo` structs, attempt to find and return one
 /// whose `port_name` field matches the provided `name` argument.
 fn find_serial_port(ports: &[SerialPortInfo], name: &str) -> Result<SerialPortInfo, Error> {
@@ -13,7 +13,7 @@ use std::{
     time::Duration,
 };
 
-use log::{debug, info};
+use log::{debug, info, trace, warn};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serialport::{SerialPort, UsbPortInfo};
@@ -25,7 +25,6 @@ use self::{
     encoder::SlipEncoder,
     reset::{
         ClassicReset,
-        ResetStrategy,
         UsbJtagSerialReset,
         construct_reset_strategy_sequence,
         hard_reset,
@@ -37,16 +36,34 @@ use crate::{
     command::{Command, CommandResponse, CommandResponseValue, CommandType},
     error::{ConnectionError, Error, ResultExt, RomError, RomErrorKind},
     flasher::stubs::CHIP_DETECT_MAGIC_REG_ADDR,
-    target::Chip,
+    target::{Chip, XtalFrequency},
 };
 
 pub(crate) mod reset;
 
-pub use reset::{ResetAfterOperation, ResetBeforeOperation};
+pub use reset::{
+    AdapterInit,
+    CustomReset,
+    ResetAfterOperation,
+    ResetBeforeOperation,
+    ResetSequence,
+    ResetStrategy,
+};
 
 const MAX_CONNECT_ATTEMPTS: usize = 7;
 const MAX_SYNC_ATTEMPTS: usize = 5;
 const USB_SERIAL_JTAG_PID: u16 = 0x1001;
+/// USB vendor ID of FTDI
+const FTDI_VID: u16 = 0x0403;
+/// Product IDs of FTDI's high-speed USB bridges: FT2232H, FT4232H and FT232H
+const FTDI_HIGH_SPEED_PIDS: [u16; 3] = [0x6010, 0x6011, 0x6014];
+/// Maximum bulk packet size of full-speed USB devices
+const USB_FS_PACKET_SIZE: usize = 64;
+/// Maximum bulk packet size of high-speed USB devices
+const USB_HS_PACKET_SIZE: usize = 512;
+
+/// Log target used for tracing the raw protocol exchange
+pub const TRACE_IO_TARGET: &str = "espflash::trace_io";
 
 #[cfg(unix)]
 /// Alias for the serial TTYPort.
@@ -235,6 +252,22 @@ pub struct Connection {
     before_operation: ResetBeforeOperation,
     pub(crate) secure_download_mode: bool,
     pub(crate) baud: u32,
+    trace_io: Option<usize>,
+    usb_align: Option<usize>,
+    op_timeout: Option<Duration>,
+    adapter_init: Option<AdapterInit>,
+    read_only: bool,
+    xtal_freq: Option<XtalFrequency>,
+    reset_strategy: Option<CustomResetStrategy>,
+}
+
+/// A user-provided [ResetStrategy], overriding the default reset sequence
+struct CustomResetStrategy(Box<dyn ResetStrategy + Send>);
+
+impl fmt::Debug for CustomResetStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CustomResetStrategy")
+    }
 }
 
 impl Connection {
@@ -254,34 +287,223 @@ impl Connection {
             before_operation,
             secure_download_mode: false,
             baud,
+            trace_io: None,
+            usb_align: None,
+            op_timeout: None,
+            adapter_init: None,
+            read_only: false,
+            xtal_freq: None,
+            reset_strategy: None,
+        }
+    }
+
+    /// Sets the strategy used to reset the device into download mode, and to
+    /// hard reset it after flashing.
+    ///
+    /// This replaces the default DTR/RTS reset sequences, for example with a
+    /// [CustomReset] driving the reset and boot lines through GPIOs. Like the
+    /// default sequence, it is not used when connecting with
+    /// [ResetBeforeOperation::NoReset] or
+    /// [ResetBeforeOperation::NoResetNoSync].
+    pub fn set_reset_strategy(&mut self, strategy: impl ResetStrategy + Send + 'static) {
+        self.reset_strategy = Some(CustomResetStrategy(Box::new(strategy)));
+    }
+
+    /// Sets an initialization sequence which is run on the serial port before
+    /// connecting, for adapters which need it to pass data on to the device.
+    pub fn set_adapter_init(&mut self, init: Option<AdapterInit>) {
+        self.adapter_init = init;
+    }
+
+    /// Enables logging of the raw protocol exchange.
+    ///
+    /// When enabled, the opcode and payload length of every command sent, and
+    /// the status of every response received, are logged at trace level to
+    /// the [TRACE_IO_TARGET] target, along with a hex dump of the first
+    /// `dump_len` bytes of each packet. Passing `None` disables tracing.
+    pub fn set_trace_io(&mut self, dump_len: Option<usize>) {
+        self.trace_io = dump_len;
+    }
+
+    /// Aligns writes to the serial port to USB packet boundaries.
+    ///
+    /// Each SLIP frame is padded with frame delimiters, which the loader
+    /// ignores, to a whole number of `packet_size` packets and written at
+    /// once, so that no frame ends in a partially filled packet followed by
+    /// the start of the next one. On native USB targets this can improve
+    /// throughput.
+    /// See [Connection::usb_packet_size] for a suitable size. Passing `None`
+    /// disables the alignment.
+    pub fn set_usb_align(&mut self, packet_size: Option<usize>) {
+        self.usb_align = packet_size.filter(|&size| size > 0);
+    }
+
+    /// Overrides the timeout of long-running operations: erasing flash and
+    /// computing MD5 checksums.
+    ///
+    /// By default their timeout scales with the size of the region involved,
+    /// which may not be enough for slow adapters. Passing `None` restores the
+    /// default.
+    pub fn set_op_timeout(&mut self, timeout: Option<Duration>) {
+        self.op_timeout = timeout;
+    }
+
+    /// Refuses all commands which write or erase flash.
+    ///
+    /// Meant for diagnostic deployments which must never modify a device:
+    /// such commands, writing the flash status registers and writing memory
+    /// fail with [Error::ReadOnly] instead. Reading flash, memory and eFuses
+    /// and loading the flasher stub into RAM still work.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Overrides the detected crystal frequency of the device.
+    ///
+    /// Used by [Chip::xtal_frequency], and through it when changing the baud
+    /// rate, for devices whose crystal frequency is misdetected. Passing
+    /// `None` restores detection.
+    pub fn set_xtal_frequency(&mut self, xtal_freq: Option<XtalFrequency>) {
+        self.xtal_freq = xtal_freq;
+    }
+
+    /// The crystal frequency set with [Connection::set_xtal_frequency]
+    pub(crate) fn xtal_frequency_override(&self) -> Option<XtalFrequency> {
+        self.xtal_freq
+    }
+
+    /// Fails with [Error::ReadOnly] if the connection is read-only, see
+    /// [Connection::set_read_only]
+    pub(crate) fn ensure_writable(&self, operation: &str) -> Result<(), Error> {
+        if self.read_only {
+            return Err(Error::ReadOnly(operation.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Timeout for a long-running command acting on `size` bytes, see
+    /// [Connection::set_op_timeout]
+    pub(crate) fn op_timeout(&self, command: CommandType, size: u32) -> Duration {
+        self.op_timeout
+            .unwrap_or_else(|| command.timeout_for_size(size))
+    }
+
+    /// Returns the maximum bulk packet size of the USB device the serial port
+    /// belongs to: 512 bytes for known high-speed bridges, 64 bytes otherwise.
+    pub fn usb_packet_size(&self) -> usize {
+        if self.port_info.vid == FTDI_VID && FTDI_HIGH_SPEED_PIDS.contains(&self.port_info.pid) {
+            USB_HS_PACKET_SIZE
+        } else {
+            USB_FS_PACKET_SIZE
+        }
+    }
+
+    fn trace_dump(&self, data: &[u8]) -> String {
+        match self.trace_io {
+            Some(len) if len > 0 => {
+                let dump = data
+                    .iter()
+                    .take(len)
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let ellipsis = if data.len() > len { " .." } else { "" };
+
+                format!(": {dump}{ellipsis}")
+            }
+            _ => String::new(),
         }
     }
 
     /// Initializes a connection with a device.
     pub fn begin(&mut self) -> Result<(), Error> {
+        if let Some(init) = &self.adapter_init {
+            init.run(&mut self.serial)?;
+        }
+
+        // A custom strategy replaces the default sequence entirely. It is taken
+        // for the duration of the attempts, as they need `self` mutably.
+        let resets = !matches!(
+            self.before_operation,
+            ResetBeforeOperation::NoReset | ResetBeforeOperation::NoResetNoSync
+        );
+        if let Some(custom) = self.reset_strategy.take_if(|_| resets) {
+            let result = self.connect_with(&[custom.0.as_ref()]);
+            self.reset_strategy = Some(custom);
+
+            return result;
+        }
+
         let port_name = self.serial.name().unwrap_or_default();
         let reset_sequence = construct_reset_strategy_sequence(
             &port_name,
             self.port_info.pid,
             self.before_operation,
         );
+        let reset_sequence = reset_sequence
+            .iter()
+            .map(|strategy| strategy.as_ref())
+            .collect::<Vec<_>>();
 
+        self.connect_with(&reset_sequence)
+    }
+
+    /// Attempts to connect, cycling through the given reset strategies.
+    fn connect_with(&mut self, reset_sequence: &[&dyn ResetStrategy]) -> Result<(), Error> {
+        let mut wrong_boot_mode = 0;
+        let mut boot_log_seen = false;
         for (_, reset_strategy) in zip(0..MAX_CONNECT_ATTEMPTS, reset_sequence.iter().cycle()) {
-            match self.connect_attempt(reset_strategy.as_ref()) {
+            match self.connect_attempt(*reset_strategy) {
                 Ok(_) => {
                     return Ok(());
                 }
                 Err(e) => {
+                    if let Error::Connection(err) = &e {
+                        match **err {
+                            ConnectionError::WrongBootMode(_) => {
+                                wrong_boot_mode += 1;
+                                boot_log_seen = true;
+                            }
+                            ConnectionError::NoSyncReply => boot_log_seen = true,
+                            _ => {}
+                        }
+                    }
                     debug!("Failed to reset, error {e:#?}, retrying");
                 }
             }
         }
 
+        self.warn_about_debugger(wrong_boot_mode, boot_log_seen);
+
         Err(Error::Connection(Box::new(
             ConnectionError::ConnectionFailed,
         )))
     }
 
+    /// Warns if the way connecting failed suggests that an attached JTAG
+    /// debugger is in the way.
+    ///
+    /// This is a heuristic: a debugger can hold the strapping pins, so that
+    /// the chip boots normally however it is reset, and a debugger using the
+    /// USB-Serial-JTAG peripheral can keep the chip halted, so that it neither
+    /// prints a boot log nor answers at all.
+    fn warn_about_debugger(&self, wrong_boot_mode: usize, boot_log_seen: bool) {
+        if self.before_operation == ResetBeforeOperation::NoReset {
+            return;
+        }
+
+        if wrong_boot_mode == MAX_CONNECT_ATTEMPTS {
+            warn!(
+                "The chip booted normally after every reset. If a JTAG debugger is connected, it may be holding the strapping pins; try disconnecting it."
+            );
+        } else if !boot_log_seen && self.is_using_usb_serial_jtag() {
+            warn!(
+                "The chip did not respond at all. If a debugger such as OpenOCD or probe-rs is attached over USB-JTAG, it may be holding the chip halted; try stopping it."
+            );
+        }
+    }
+
     /// Connects to a device.
     fn connect_attempt(&mut self, reset_strategy: &dyn ResetStrategy) -> Result<(), Error> {
         // If we're doing no_sync, we're likely communicating as a pass through
@@ -337,12 +559,8 @@ impl Connection {
             };
         }
 
-        for _ in 0..MAX_SYNC_ATTEMPTS {
-            self.flush()?;
-
-            if self.sync().is_ok() {
-                return Ok(());
-            }
+        if self.resync().is_ok() {
+            return Ok(());
         }
 
         if boot_log_detected {
@@ -360,6 +578,20 @@ impl Connection {
         )))
     }
 
+    /// Syncs with a device which may not have responded to the previous
+    /// attempts, retrying a few times.
+    pub(crate) fn resync(&mut self) -> Result<(), Error> {
+        for _ in 0..MAX_SYNC_ATTEMPTS {
+            self.flush()?;
+
+            if self.sync().is_ok() {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Connection(Box::new(ConnectionError::NoSyncReply)))
+    }
+
     /// Syncs with a device.
     pub(crate) fn sync(&mut self) -> Result<(), Error> {
         self.with_timeout(CommandType::Sync.timeout(), |connection| {
@@ -396,7 +628,10 @@ impl Connection {
 
     /// Resets the device.
     pub fn reset(&mut self) -> Result<(), Error> {
-        reset_after_flash(&mut self.serial, self.port_info.pid)?;
+        match &self.reset_strategy {
+            Some(custom) => custom.0.hard_reset(&mut self.serial, self.port_info.pid)?,
+            None => reset_after_flash(&mut self.serial, self.port_info.pid)?,
+        }
 
         Ok(())
     }
@@ -406,7 +641,10 @@ impl Connection {
         let pid = self.usb_pid();
 
         match self.after_operation {
-            ResetAfterOperation::HardReset => hard_reset(&mut self.serial, pid),
+            ResetAfterOperation::HardReset => match &self.reset_strategy {
+                Some(custom) => custom.0.hard_reset(&mut self.serial, pid),
+                None => hard_reset(&mut self.serial, pid),
+            },
             ResetAfterOperation::NoReset => {
                 info!("Staying in bootloader");
                 soft_reset(self, true, is_stub)?;
@@ -466,7 +704,9 @@ impl Connection {
 
     /// Resets the device to flash mode.
     pub fn reset_to_flash(&mut self, extra_delay: bool) -> Result<(), Error> {
-        if self.is_using_usb_serial_jtag() {
+        if let Some(custom) = &self.reset_strategy {
+            custom.0.reset(&mut self.serial)
+        } else if self.is_using_usb_serial_jtag() {
             UsbJtagSerialReset.reset(&mut self.serial)
         } else {
             #[cfg(unix)]
@@ -528,6 +768,14 @@ impl Connection {
         if response.is_empty() {
             return Ok(None);
         }
+        if self.trace_io.is_some() {
+            trace!(
+                target: TRACE_IO_TARGET,
+                "<- flash data, {} bytes{}",
+                response.len(),
+                self.trace_dump(&response)
+            );
+        }
         let value = CommandResponseValue::Vector(response.clone());
 
         let header = CommandResponse {
@@ -593,6 +841,18 @@ impl Connection {
                     status: response[response.len() - status_len],
                 };
 
+                if self.trace_io.is_some() {
+                    trace!(
+                        target: TRACE_IO_TARGET,
+                        "<- opcode {:#04x}, {} bytes, status {:#04x}, error {:#04x}{}",
+                        header.return_op,
+                        response.len(),
+                        header.status,
+                        header.error,
+                        self.trace_dump(&response)
+                    );
+                }
+
                 Ok(Some(header))
             }
         }
@@ -600,29 +860,77 @@ impl Connection {
 
     /// Writes raw data to the serial port.
     pub fn write_raw(&mut self, data: u32) -> Result<(), Error> {
-        let mut binding = Box::new(&mut self.serial);
-        let serial = binding.as_mut();
-        serial.clear(serialport::ClearBuffer::Input)?;
-        let mut writer = BufWriter::new(serial);
-        let mut encoder = SlipEncoder::new(&mut writer)?;
-        encoder.write_all(&data.to_le_bytes())?;
-        encoder.finish()?;
-        writer.flush()?;
-        Ok(())
+        if self.trace_io.is_some() {
+            trace!(
+                target: TRACE_IO_TARGET,
+                "-> raw, 4 bytes{}",
+                self.trace_dump(&data.to_le_bytes())
+            );
+        }
+        self.write_frame(&data.to_le_bytes())
     }
 
     /// Writes a command to the serial port.
     pub fn write_command(&mut self, command: Command<'_>) -> Result<(), Error> {
+        match command {
+            // Beginning an empty write is how the device is soft reset
+            Command::FlashBegin { size: 0, .. } => {}
+            Command::FlashBegin { .. }
+            | Command::FlashData { .. }
+            | Command::FlashDeflBegin { .. }
+            | Command::FlashDeflData { .. } => self.ensure_writable("write flash")?,
+            Command::EraseFlash | Command::EraseRegion { .. } => {
+                self.ensure_writable("erase flash")?
+            }
+            _ => {}
+        }
+
         debug!("Writing command: {command:02x?}");
+        let mut packet = Vec::new();
+        command.write(&mut packet)?;
+        if self.trace_io.is_some() {
+            // The packet starts with a direction byte, the opcode, a 16-bit payload
+            // length and a 32-bit checksum.
+            let ty = command.command_type();
+            trace!(
+                target: TRACE_IO_TARGET,
+                "-> {ty:?} (opcode {:#04x}), {} byte payload{}",
+                ty as u8,
+                packet.len().saturating_sub(8),
+                self.trace_dump(&packet)
+            );
+        }
+
+        self.write_frame(&packet)
+    }
+
+    /// SLIP encodes the data and writes it to the serial port.
+    fn write_frame(&mut self, data: &[u8]) -> Result<(), Error> {
         let mut binding = Box::new(&mut self.serial);
         let serial = binding.as_mut();
 
         serial.clear(serialport::ClearBuffer::Input)?;
-        let mut writer = BufWriter::new(serial);
-        let mut encoder = SlipEncoder::new(&mut writer)?;
-        command.write(&mut encoder)?;
+
+        let Some(packet_size) = self.usb_align else {
+            let mut writer = BufWriter::new(serial);
+            let mut encoder = SlipEncoder::new(&mut writer)?;
+            encoder.write_all(data)?;
+            encoder.finish()?;
+            writer.flush()?;
+            return Ok(());
+        };
+
+        let mut frame = Vec::with_capacity(data.len().next_multiple_of(packet_size));
+        let mut encoder = SlipEncoder::new(&mut frame)?;
+        encoder.write_all(data)?;
         encoder.finish()?;
-        writer.flush()?;
+
+        // Fill up the last packet with frame delimiters, which the loader reads
+        // as empty frames and ignores
+        frame.resize(frame.len().next_multiple_of(packet_size), 0xC0);
+
+        serial.write_all(&frame)?;
+        serial.flush()?;
         Ok(())
     }
 
@@ -720,6 +1028,11 @@ impl Connection {
         self.port_info.pid
     }
 
+    /// Returns the name of the serial port, if known.
+    pub fn port_name(&self) -> Option<String> {
+        self.serial.name()
+    }
+
     /// Returns if the connection is using USB serial JTAG.
     pub(crate) fn is_using_usb_serial_jtag(&self) -> bool {
         self.port_info.pid == USB_SERIAL_JTAG_PID
@@ -850,3 +1163,96 @@ mod encoder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn read_only_refuses_writes() {
+        let (serial, _device) = Port::pair().unwrap();
+        let port_info = UsbPortInfo {
+            vid: 0,
+            pid: 0,
+            serial_number: None,
+            manufacturer: None,
+            product: None,
+        };
+        let mut connection = Connection::new(
+            serial,
+            port_info,
+            ResetAfterOperation::HardReset,
+            ResetBeforeOperation::NoResetNoSync,
+            115_200,
+        );
+        connection.set_read_only(true);
+
+        let begin = |size| Command::FlashBegin {
+            size,
+            blocks: 1,
+            block_size: 0x400,
+            offset: 0x10000,
+            supports_encryption: false,
+        };
+        let refused = [
+            begin(0x400),
+            Command::FlashData {
+                data: &[0xff; 4],
+                pad_to: 0,
+                pad_byte: 0xff,
+                sequence: 0,
+            },
+            Command::FlashDeflBegin {
+                size: 0x400,
+                blocks: 1,
+                block_size: 0x400,
+                offset: 0x10000,
+                supports_encryption: false,
+            },
+            Command::FlashDeflData {
+                data: &[0x78, 0x9c],
+                pad_to: 0,
+                pad_byte: 0xff,
+                sequence: 0,
+            },
+            Command::EraseFlash,
+            Command::EraseRegion {
+                offset: 0x10000,
+                size: 0x1000,
+            },
+        ];
+        for command in refused {
+            assert!(
+                matches!(connection.write_command(command), Err(Error::ReadOnly(_))),
+                "{command:?} was not refused"
+            );
+        }
+
+        // Beginning an empty write only soft resets the device, and reads are
+        // always allowed
+        let allowed = [
+            begin(0),
+            Command::ReadFlash {
+                offset: 0,
+                size: 0x1000,
+                block_size: 0x1000,
+                max_in_flight: 64,
+            },
+            Command::FlashMd5 {
+                offset: 0,
+                size: 0x1000,
+            },
+            Command::ReadReg { address: 0 },
+        ];
+        for command in allowed {
+            assert!(
+                connection.write_command(command).is_ok(),
+                "{command:?} was refused"
+            );
+        }
+
+        connection.set_read_only(false);
+        assert!(connection.write_command(begin(0x400)).is_ok());
+    }
+}
@@ -7,7 +7,7 @@
 
 #[cfg(unix)]
 use std::{io, os::fd::AsRawFd};
-use std::{thread::sleep, time::Duration};
+use std::{io::Write, str::FromStr, thread::sleep, time::Duration};
 
 #[cfg(unix)]
 use libc::ioctl;
@@ -29,9 +29,21 @@ const DEFAULT_RESET_DELAY: u64 = 50; // ms
 const EXTRA_RESET_DELAY: u64 = 500; // ms
 
 /// Reset strategies for resetting a target device.
+///
+/// Implement this trait to drive the reset and boot lines of fixtures which do
+/// not use the conventional DTR/RTS auto-reset circuit, and pass it to
+/// [Connection::set_reset_strategy].
 pub trait ResetStrategy {
+    /// Reset the target device into download mode.
     fn reset(&self, serial_port: &mut Port) -> Result<(), Error>;
 
+    /// Reset the target device to run the application, after flashing.
+    ///
+    /// Defaults to [hard_reset].
+    fn hard_reset(&self, serial_port: &mut Port, pid: u16) -> Result<(), Error> {
+        hard_reset(serial_port, pid)
+    }
+
     fn set_dtr(&self, serial_port: &mut Port, level: bool) -> Result<(), Error> {
         serial_port.write_data_terminal_ready(level)?;
 
@@ -205,6 +217,242 @@ impl ResetStrategy for UsbJtagSerialReset {
     }
 }
 
+/// Closure resetting the target device, as used by [CustomReset]
+type ResetFn = Box<dyn Fn(&mut Port) -> Result<(), Error> + Send>;
+
+/// Reset strategy backed by a closure, for hardware-specific reset sequences.
+pub struct CustomReset<F> {
+    reset: F,
+    hard_reset: Option<ResetFn>,
+}
+
+impl<F> CustomReset<F>
+where
+    F: Fn(&mut Port) -> Result<(), Error>,
+{
+    /// Create a new `CustomReset` strategy which calls `reset` to reset the
+    /// target device into download mode.
+    pub fn new(reset: F) -> Self {
+        Self {
+            reset,
+            hard_reset: None,
+        }
+    }
+
+    /// Calls `hard_reset` to reset the target device to run the application
+    /// after flashing, instead of the default [hard_reset].
+    pub fn with_hard_reset(
+        mut self,
+        hard_reset: impl Fn(&mut Port) -> Result<(), Error> + Send + 'static,
+    ) -> Self {
+        self.hard_reset = Some(Box::new(hard_reset));
+        self
+    }
+}
+
+impl<F> ResetStrategy for CustomReset<F>
+where
+    F: Fn(&mut Port) -> Result<(), Error>,
+{
+    fn reset(&self, serial_port: &mut Port) -> Result<(), Error> {
+        debug!("Using custom reset strategy");
+
+        (self.reset)(serial_port)
+    }
+
+    fn hard_reset(&self, serial_port: &mut Port, pid: u16) -> Result<(), Error> {
+        match &self.hard_reset {
+            Some(reset) => {
+                debug!("Using custom hard reset strategy");
+                reset(serial_port)
+            }
+            None => hard_reset(serial_port, pid),
+        }
+    }
+}
+
+/// A single step of a [ResetSequence]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ResetStep {
+    /// Set DTR (`D0`/`D1`)
+    Dtr(bool),
+    /// Set RTS (`R0`/`R1`)
+    Rts(bool),
+    /// Set DTR and RTS at the same time where supported (`U0,1`)
+    DtrRts(bool, bool),
+    /// Wait for the given number of seconds (`W0.1`)
+    Wait(Duration),
+}
+
+/// Reset sequence driving the DTR and RTS lines, in the format of esptool's
+/// `custom_reset_sequence` option
+///
+/// Steps are separated by `|`: `D0`/`D1` and `R0`/`R1` set DTR and RTS,
+/// `U0,1` sets DTR and RTS at the same time, and `W0.1` waits for the given
+/// number of seconds. For example, the classic reset is
+/// `D0|R1|W0.1|D1|R0|W0.05|D0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResetSequence(Vec<ResetStep>);
+
+impl ResetSequence {
+    /// Runs the sequence on the serial port
+    pub fn run(&self, serial_port: &mut Port) -> Result<(), Error> {
+        debug!("Using custom reset sequence: {self:?}");
+
+        for step in &self.0 {
+            match *step {
+                ResetStep::Dtr(level) => serial_port.write_data_terminal_ready(level)?,
+                ResetStep::Rts(level) => serial_port.write_request_to_send(level)?,
+                #[cfg(unix)]
+                ResetStep::DtrRts(dtr, rts) => {
+                    UnixTightReset::new(false).set_dtr_rts(serial_port, dtr, rts)?
+                }
+                #[cfg(not(unix))]
+                ResetStep::DtrRts(dtr, rts) => {
+                    serial_port.write_data_terminal_ready(dtr)?;
+                    serial_port.write_request_to_send(rts)?;
+                }
+                ResetStep::Wait(duration) => sleep(duration),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ResetSequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn level(value: &str) -> Result<bool, String> {
+            match value.trim() {
+                "0" => Ok(false),
+                "1" => Ok(true),
+                _ => Err(format!("invalid line level '{value}', expected 0 or 1")),
+            }
+        }
+
+        s.split('|')
+            .map(|step| {
+                let step = step.trim();
+                let (command, value) = step.split_at(step.chars().next().map_or(0, char::len_utf8));
+                match command {
+                    "D" => level(value).map(ResetStep::Dtr),
+                    "R" => level(value).map(ResetStep::Rts),
+                    "U" => {
+                        let (dtr, rts) = value
+                            .split_once(',')
+                            .ok_or_else(|| format!("invalid step '{step}', expected U<dtr>,<rts>"))?;
+                        Ok(ResetStep::DtrRts(level(dtr)?, level(rts)?))
+                    }
+                    "W" => value
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .map(ResetStep::Wait)
+                        .ok_or_else(|| format!("invalid wait time '{value}'")),
+                    _ => Err(format!(
+                        "invalid step '{step}', expected one of D<level>, R<level>, U<dtr>,<rts> or W<secs>"
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+/// Initialization sequence for serial adapters which need their control lines
+/// or a preamble set up before they pass data on to the target device
+///
+/// Parsed from one of the profile names, or from `hex:` followed by the bytes
+/// of a preamble, e.g. `hex:55aa`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AdapterInit {
+    /// Deassert DTR and RTS and let the lines settle, for adapters which
+    /// use RTS to enable their RS-485 transmitter (`release-lines`)
+    ReleaseLines,
+    /// Pulse DTR and RTS, for adapters which only enable their outputs after
+    /// seeing the control lines change (`toggle-lines`)
+    ToggleLines,
+    /// Send a single 0x00 byte at 9600 baud, for adapters which need some
+    /// traffic to wake up (`wake-byte`)
+    WakeByte,
+    /// Send raw bytes at the connection's baud rate
+    Preamble(Vec<u8>),
+}
+
+impl AdapterInit {
+    /// Time to let the adapter settle after the initialization sequence
+    const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+    /// Runs the initialization sequence on the serial port
+    pub(crate) fn run(&self, serial_port: &mut Port) -> Result<(), Error> {
+        debug!("Running adapter initialization sequence: {self:?}");
+
+        match self {
+            AdapterInit::ReleaseLines => {
+                serial_port.write_data_terminal_ready(false)?;
+                serial_port.write_request_to_send(false)?;
+            }
+            AdapterInit::ToggleLines => {
+                serial_port.write_data_terminal_ready(true)?;
+                serial_port.write_request_to_send(true)?;
+                sleep(Duration::from_millis(DEFAULT_RESET_DELAY));
+                serial_port.write_data_terminal_ready(false)?;
+                serial_port.write_request_to_send(false)?;
+            }
+            AdapterInit::WakeByte => {
+                let baud = serial_port.baud_rate()?;
+                serial_port.set_baud_rate(9600)?;
+                serial_port.write_all(&[0x00])?;
+                serial_port.flush()?;
+                sleep(Duration::from_millis(DEFAULT_RESET_DELAY));
+                serial_port.set_baud_rate(baud)?;
+            }
+            AdapterInit::Preamble(bytes) => {
+                serial_port.write_all(bytes)?;
+                serial_port.flush()?;
+            }
+        }
+
+        sleep(Self::SETTLE_TIME);
+        serial_port.clear(serialport::ClearBuffer::Input)?;
+
+        Ok(())
+    }
+}
+
+impl FromStr for AdapterInit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "release-lines" => return Ok(Self::ReleaseLines),
+            "toggle-lines" => return Ok(Self::ToggleLines),
+            "wake-byte" => return Ok(Self::WakeByte),
+            _ => {}
+        }
+
+        let Some(hex) = s.strip_prefix("hex:") else {
+            return Err(format!(
+                "unknown adapter profile '{s}', expected one of `release-lines`, `toggle-lines`, `wake-byte` or `hex:<bytes>`"
+            ));
+        };
+        if hex.is_empty() || hex.len() % 2 != 0 {
+            return Err("the preamble must consist of whole hex bytes".into());
+        }
+
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::Preamble)
+            .map_err(|_| format!("invalid hex preamble '{hex}'"))
+    }
+}
+
 /// Resets the target device.
 pub fn reset_after_flash(serial: &mut Port, pid: u16) -> Result<(), serialport::Error> {
     sleep(Duration::from_millis(100));
@@ -303,7 +551,8 @@ pub fn soft_reset(
 /// Constructs a sequence of reset strategies based on the OS and chip.
 ///
 /// Returns a [Vec] containing one or more reset strategies to be attempted
-/// sequentially.
+/// sequentially. This is the default used by [Connection::begin] when no
+/// custom strategy has been set.
 #[allow(unused_variables)]
 pub fn construct_reset_strategy_sequence(
     port_name: &str,
@@ -397,3 +646,97 @@ pub enum ResetAfterOperation {
     /// Hard-resets the chip by triggering an internal watchdog reset.
     WatchdogReset,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reset_sequence() {
+        let sequence = "D0|R1|W0.1|D1|R0|W0.05|D0|U0,1"
+            .parse::<ResetSequence>()
+            .unwrap();
+        assert_eq!(
+            sequence.0,
+            [
+                ResetStep::Dtr(false),
+                ResetStep::Rts(true),
+                ResetStep::Wait(Duration::from_millis(100)),
+                ResetStep::Dtr(true),
+                ResetStep::Rts(false),
+                ResetStep::Wait(Duration::from_millis(50)),
+                ResetStep::Dtr(false),
+                ResetStep::DtrRts(false, true),
+            ]
+        );
+
+        assert!("".parse::<ResetSequence>().is_err());
+        assert!("D2".parse::<ResetSequence>().is_err());
+        assert!("U1".parse::<ResetSequence>().is_err());
+        assert!("W-1".parse::<ResetSequence>().is_err());
+        assert!("X1".parse::<ResetSequence>().is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn custom_reset_strategy() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        use serialport::UsbPortInfo;
+
+        use crate::target::Chip;
+
+        fn connection(
+            before: ResetBeforeOperation,
+        ) -> (Connection, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+            let (serial, _) = Port::pair().unwrap();
+            let port_info = UsbPortInfo {
+                vid: 0,
+                pid: 0,
+                serial_number: None,
+                manufacturer: None,
+                product: None,
+            };
+            let mut connection = Connection::new(
+                serial,
+                port_info,
+                ResetAfterOperation::HardReset,
+                before,
+                115_200,
+            );
+
+            let resets = Arc::new(AtomicUsize::new(0));
+            let hard_resets = Arc::new(AtomicUsize::new(0));
+            let (r, h) = (resets.clone(), hard_resets.clone());
+            connection.set_reset_strategy(
+                CustomReset::new(move |_: &mut Port| {
+                    r.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                })
+                .with_hard_reset(move |_| {
+                    h.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }),
+            );
+
+            (connection, resets, hard_resets)
+        }
+
+        // The custom strategy is used to reset into download mode and after
+        // flashing
+        let (mut conn, resets, hard_resets) = connection(ResetBeforeOperation::DefaultReset);
+        conn.reset_to_flash(false).unwrap();
+        assert_eq!(resets.load(Ordering::SeqCst), 1);
+        conn.reset_after(true, Chip::Esp32c3).unwrap();
+        conn.reset().unwrap();
+        assert_eq!(hard_resets.load(Ordering::SeqCst), 2);
+
+        // ... but not when connecting without a reset
+        let (mut conn, resets, _) = connection(ResetBeforeOperation::NoResetNoSync);
+        conn.begin().unwrap();
+        assert_eq!(resets.load(Ordering::SeqCst), 0);
+    }
+}
@@ -16,6 +16,7 @@ use crate::cli::monitor::parser::esp_defmt::DefmtError;
 use crate::command::CommandType;
 use crate::{
     flasher::{FlashFrequency, FlashSize},
+    image_format::partitions::PartitionTableFormat,
     target::Chip,
 };
 
@@ -32,11 +33,71 @@ pub enum Error {
     #[diagnostic(code(espflash::app_partition_not_found))]
     AppPartitionNotFound,
 
+    /// The partition table has no app partition for the requested OTA slot
+    #[error("No app partition found for OTA slot {0}")]
+    #[diagnostic(
+        code(espflash::ota_slot_not_found),
+        help("The partition table needs an `ota_{0}` app partition")
+    )]
+    OtaSlotNotFound(u8),
+
+    /// The partition table has no `otadata` partition
+    #[error("No otadata partition found")]
+    #[diagnostic(
+        code(espflash::ota_data_partition_not_found),
+        help("Selecting an OTA slot requires a `data, ota` partition in the partition table")
+    )]
+    OtaDataPartitionNotFound,
+
+    /// The application image does not fit in the target app partition
+    #[error(
+        "Application image of {app_size} bytes does not fit in the '{label}' partition of {part_size} bytes, it overflows by {overflow} bytes"
+    )]
+    #[diagnostic(
+        code(espflash::app_too_big_for_partition),
+        help("Reduce the size of the binary or increase the size of the app partition."),
+        url(
+            "https://docs.espressif.com/projects/esp-idf/en/latest/esp32/api-guides/partition-tables.html#built-in-partition-tables"
+        )
+    )]
+    AppTooBigForPartition {
+        /// Label of the target app partition
+        label: String,
+        /// Size of the application image
+        app_size: u32,
+        /// Size of the target app partition
+        part_size: u32,
+        /// Number of bytes by which the image exceeds the partition
+        overflow: u32,
+    },
+
+    /// A flash offset relocated by the flash base is past the end of the
+    /// address space
+    #[error("Flash offset {offset:#x} relocated by the flash base {flash_base:#x} overflows")]
+    #[diagnostic(
+        code(espflash::flash_base_overflow),
+        help("Use a smaller `--flash-base`")
+    )]
+    FlashBaseOverflow {
+        /// Base address added to the offset
+        flash_base: u32,
+        /// Offset which was relocated
+        offset: u32,
+    },
+
     /// Operation was cancelled by the user
     #[error("Operation was cancelled by the user")]
     #[diagnostic(code(espflash::cancelled))]
     Cancelled,
 
+    /// A write or erase was attempted on a read-only connection
+    #[error("Refusing to {0} in read-only mode")]
+    #[diagnostic(
+        code(espflash::read_only),
+        help("Remove `--read-only`, or unset `ESPFLASH_READ_ONLY`, to allow modifying the device")
+    )]
+    ReadOnly(String),
+
     /// Unable to detect chip type
     #[error("{0}")]
     #[diagnostic(
@@ -92,19 +153,6 @@ pub enum Error {
     )]
     ElfNotRamLoadable,
 
-    /// The supplied ELF image is too large for the configured app partition
-    #[error(
-        "Supplied ELF image of {0}B is too big, and doesn't fit configured app partition of {1}B"
-    )]
-    #[diagnostic(
-        code(espflash::image_too_big),
-        help("Reduce the size of the binary or increase the size of the app partition."),
-        url(
-            "https://docs.espressif.com/projects/esp-idf/en/latest/esp32/api-guides/partition-tables.html#built-in-partition-tables"
-        )
-    )]
-    ElfTooBig(u32, u32),
-
     /// Failed to connect to on-device flash
     #[error("Failed to connect to on-device flash")]
     #[diagnostic(code(espflash::flash_connect))]
@@ -124,11 +172,73 @@ pub enum Error {
     #[error("The provided bootloader binary is invalid")]
     InvalidBootloader,
 
+    /// The provided application binary is not an ESP-IDF image
+    #[error("The provided application binary is not a valid ESP-IDF image")]
+    #[diagnostic(code(espflash::invalid_app_image))]
+    InvalidAppImage,
+
+    /// A combination of flash data options is invalid
+    #[error("Invalid flash data: {0}")]
+    #[diagnostic(code(espflash::invalid_flash_data))]
+    InvalidFlashData(String),
+
+    /// The application image has more segments than the bootloader accepts
+    #[error("The application image has {count} segments, but the bootloader accepts at most {max}")]
+    #[diagnostic(
+        code(espflash::too_many_segments),
+        help("Reduce the number of loadable sections, e.g. by merging them in the linker script")
+    )]
+    TooManySegments {
+        /// Number of segments in the image
+        count: usize,
+        /// Maximum number of segments
+        max: usize,
+    },
+
+    /// The provided bootloader binary was built for a different chip
+    #[error("The provided bootloader was built for {bootloader}, but the target chip is {chip}")]
+    #[diagnostic(
+        code(espflash::bootloader_chip_mismatch),
+        help(
+            "Use a bootloader built for {chip}; it will be written at offset {:#x}",
+            chip.boot_address()
+        )
+    )]
+    BootloaderChipMismatch {
+        /// Chip the bootloader was built for
+        bootloader: String,
+        /// Chip which is being targeted
+        chip: Chip,
+    },
+
     /// Specified bootloader path is not a `.bin` file
     #[error("Specified bootloader path is not a .bin file")]
     #[diagnostic(code(espflash::invalid_bootloader_path))]
     InvalidBootloaderPath,
 
+    /// Invalid flash write block size provided
+    #[error("The flash block size {size:#x} is invalid")]
+    #[diagnostic(
+        code(espflash::invalid_flash_block_size),
+        help("The block size must be a power of two between {min:#x} and {max:#x}")
+    )]
+    InvalidFlashBlockSize {
+        /// Requested block size
+        size: usize,
+        /// Minimum supported block size
+        min: usize,
+        /// Maximum supported block size for the current loader
+        max: usize,
+    },
+
+    /// Invalid flash encryption key provided
+    #[error("Flash encryption key of {0} bytes is invalid")]
+    #[diagnostic(
+        code(espflash::invalid_flash_encryption_key),
+        help("The key must be 32 bytes long for XTS-AES-128, or 64 bytes long for XTS-AES-256")
+    )]
+    InvalidFlashEncryptionKey(usize),
+
     /// Invalid flash size provided
     #[error("The flash size '{0}' is invalid")]
     #[diagnostic(
@@ -137,6 +247,87 @@ pub enum Error {
     )]
     InvalidFlashSize(String),
 
+    /// The SHA256 digest appended to an image does not match its contents
+    #[error("The SHA256 digest appended to the {0} does not match its contents")]
+    #[diagnostic(
+        code(espflash::image_format::digest_mismatch),
+        help("The ROM or bootloader will refuse to boot this image")
+    )]
+    AppendedDigestMismatch(String),
+
+    /// A partition table file is not in the format it was declared to be in
+    #[error("The partition table is not in {0} format")]
+    #[diagnostic(
+        code(espflash::partition_table::format_mismatch),
+        help("Check the `--partition-table-format` option, or omit it to detect the format")
+    )]
+    PartitionTableFormatMismatch(PartitionTableFormat),
+
+    /// A partition table read from the device is damaged
+    #[error("The partition table on the device is corrupt: {0}")]
+    #[diagnostic(
+        code(espflash::partition_table::corrupt),
+        help("The table may have been partially written or damaged; flash it again")
+    )]
+    CorruptPartitionTable(String),
+
+    /// The partition table would overlap the bootloader or a partition
+    #[error("The partition table offset {offset:#x} is invalid: {reason}")]
+    #[diagnostic(code(espflash::partition_table::invalid_offset))]
+    InvalidPartitionTableOffset {
+        /// Offset of the partition table
+        offset: u32,
+        /// Why the offset is invalid
+        reason: String,
+    },
+
+    /// A partition could not be added to the partition table
+    #[error("Partition '{name}' is invalid: {reason}")]
+    #[diagnostic(code(espflash::partition_table::invalid_partition))]
+    InvalidPartition {
+        /// Name of the partition
+        name: String,
+        /// Why the partition is invalid
+        reason: String,
+    },
+
+    /// An ELF section describing extra data to flash is invalid
+    #[error("Extra data section '{section}' is invalid: {reason}")]
+    #[diagnostic(code(espflash::invalid_extra_section))]
+    InvalidExtraSection {
+        /// Name of the section
+        section: String,
+        /// Why the section is invalid
+        reason: String,
+    },
+
+    /// An extra ELF file to flash alongside the application is invalid
+    #[error("Extra ELF image at {addr:#x} is invalid: {reason}")]
+    #[diagnostic(code(espflash::invalid_extra_elf))]
+    InvalidExtraElf {
+        /// Flash address the image was to be written to
+        addr: u32,
+        /// Why the image is invalid
+        reason: String,
+    },
+
+    /// An ELF segment is not mapped to a valid memory region of the chip
+    #[error(
+        "ELF segment at {addr:#010x} ({size} bytes) is outside of any valid flash or RAM region"
+    )]
+    #[diagnostic(
+        code(espflash::invalid_segment_address),
+        help(
+            "Check the linker script used to build the image, or use `--lenient` to only emit a warning"
+        )
+    )]
+    InvalidSegmentAddress {
+        /// Address of the segment
+        addr: u32,
+        /// Size of the segment
+        size: u32,
+    },
+
     /// IO error
     #[cfg(not(feature = "serialport"))]
     #[error(transparent)]
@@ -166,10 +357,30 @@ pub enum Error {
     #[error("This command requires using the RAM stub")]
     #[diagnostic(
         code(espflash::stub_required),
-        help("Don't use the `--no-stub` option with the command")
+        help(
+            "Don't use the `--no-stub` option with the command. If the stub is disabled with `ESPFLASH_NO_STUB` or `no_stub` in the configuration file, pass `--stub`"
+        )
     )]
     StubRequired,
 
+    /// The flasher stub could not be loaded onto the target device
+    #[error("Failed to load the flash stub: {0}")]
+    #[diagnostic(
+        code(espflash::stub_load_failed),
+        help(
+            "Use `--no-stub` to use the ROM loader instead, or `--stub-fallback` to fall back to it when loading the stub fails"
+        )
+    )]
+    StubLoadFailed(Box<Error>),
+
+    /// The flash block protection bits could not be cleared
+    #[error("Failed to clear the flash write protection, status register is 0x{0:04x}")]
+    #[diagnostic(
+        code(espflash::flash_unlock_failed),
+        help("The status register may be locked by its protect bits or the WP pin")
+    )]
+    FlashUnlockFailed(u16),
+
     /// Serial port could not be found
     #[error("The serial port '{0}' could not be found")]
     #[diagnostic(
@@ -247,6 +458,16 @@ pub enum Error {
     #[error("Communication error while flashing device")]
     Flashing(CoreError),
 
+    /// Communication was lost in a way consistent with the device browning out
+    #[error("Lost communication with the device while flashing")]
+    #[diagnostic(
+        code(espflash::possible_brownout),
+        help(
+            "This may indicate insufficient power; try a shorter/better USB cable or an external supply"
+        )
+    )]
+    PossibleBrownout(CoreError),
+
     /// Supplied ELF image is not valid
     #[error("Supplied ELF image is not valid")]
     #[diagnostic(
@@ -284,6 +505,16 @@ pub enum Error {
     #[diagnostic(code(espflash::verify_failed))]
     VerifyFailed,
 
+    /// The bootloader read back from flash does not match the one written
+    #[error("The bootloader written at {0:#x} does not match the flash contents")]
+    #[diagnostic(
+        code(espflash::bootloader_verify_failed),
+        help(
+            "The device will most likely not boot. Check whether the flash is write protected (see `--unlock-flash`) and flash again"
+        )
+    )]
+    BootloaderVerifyFailed(u32),
+
     /// Error during user interaction
     #[cfg(feature = "cli")]
     #[error(transparent)]
@@ -328,6 +559,36 @@ pub enum Error {
         size: u32,
     },
 
+    /// A memory region to write is not writable RAM of the chip
+    #[error("Memory region {addr:#010x} ({size} bytes) is not writable RAM of the {chip}")]
+    #[diagnostic(
+        code(espflash::invalid_memory_region),
+        help(
+            "The address must be 4-byte aligned, and the region must lie within one of the chip's RAM regions, including RTC memory"
+        )
+    )]
+    InvalidMemoryRegion {
+        /// Start address of the region
+        addr: u32,
+        /// Size of the region in bytes
+        size: u32,
+        /// The connected chip
+        chip: Chip,
+    },
+
+    /// The region to encrypt or decrypt is not aligned
+    #[error("Encrypted region at {address:#x} of {size:#x} bytes is not aligned")]
+    #[diagnostic(
+        code(espflash::unaligned_encrypted_region),
+        help("Both the address and the size must be multiples of 16 bytes")
+    )]
+    UnalignedEncryptedRegion {
+        /// Start address of the region
+        address: u32,
+        /// Size of the region
+        size: u32,
+    },
+
     /// Firmware was built for a chip other than what was detected
     #[error("The firmware was built for {elf}, but the detected chip is {detected}")]
     #[diagnostic(
@@ -343,6 +604,21 @@ pub enum Error {
         detected: Chip,
     },
 
+    /// The ELF file was built for a different CPU architecture than the chip's
+    #[error("The ELF file targets the {machine} architecture, but {chip} uses {expected}")]
+    #[diagnostic(
+        code(espflash::elf_architecture_mismatch),
+        help("Make sure the `--chip` option matches the target the firmware was built for")
+    )]
+    ElfArchitectureMismatch {
+        /// Machine type of the ELF file
+        machine: String,
+        /// Architecture of the selected chip
+        expected: String,
+        /// The selected chip
+        chip: Chip,
+    },
+
     /// The partition table does not fit into the flash
     #[error("The partition table does not fit into the flash ({0})")]
     #[diagnostic(
@@ -368,6 +644,19 @@ pub enum Error {
     #[error("specified eFuse block does not exist: {0}")]
     InvalidEfuseBlock(u32),
 
+    /// The named eFuse field is not defined for the chip
+    #[error("The {chip} has no eFuse field named '{name}'")]
+    #[diagnostic(
+        code(espflash::unknown_efuse_field),
+        help("Field names are taken from the chip's eFuse table, e.g. `WAFER_VERSION_MAJOR`")
+    )]
+    UnknownEfuseField {
+        /// The requested field name
+        name: String,
+        /// The chip the field was looked up for
+        chip: Chip,
+    },
+
     /// Unsupported crystal frequency
     #[error("Unsupported crystal frequency: {0}")]
     UnsupportedXtalFrequency(String),
@@ -575,14 +864,27 @@ impl From<SlipError> for ConnectionError {
 #[cfg(feature = "serialport")]
 pub(crate) struct TimedOutCommand {
     command: Option<CommandType>,
+    spi_opcode: Option<u8>,
+}
+
+#[cfg(feature = "serialport")]
+impl TimedOutCommand {
+    /// A command sent to the flash chip through the SPI peripheral
+    pub(crate) fn spi(opcode: u8) -> Self {
+        TimedOutCommand {
+            command: None,
+            spi_opcode: Some(opcode),
+        }
+    }
 }
 
 #[cfg(feature = "serialport")]
 impl Display for TimedOutCommand {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match &self.command {
-            Some(command) => write!(f, "{command} "),
-            None => Ok(()),
+        match (&self.command, self.spi_opcode) {
+            (Some(command), _) => write!(f, "{command} "),
+            (None, Some(opcode)) => write!(f, "SPI flash 0x{opcode:02X} "),
+            (None, None) => Ok(()),
         }
     }
 }
@@ -590,7 +892,10 @@ impl Display for TimedOutCommand {
 #[cfg(feature = "serialport")]
 impl From<CommandType> for TimedOutCommand {
     fn from(ct: CommandType) -> Self {
-        TimedOutCommand { command: Some(ct) }
+        TimedOutCommand {
+            command: Some(ct),
+            spi_opcode: None,
+        }
     }
 }
 
@@ -734,6 +1039,9 @@ pub(crate) trait ResultExt {
     fn flashing(self) -> Self;
     /// Mark the command from which this error originates.
     fn for_command(self, command: CommandType) -> Self;
+    /// Mark an error as a possible brownout if the device stopped responding
+    /// in a way that suggests it lost power.
+    fn brownout_hint(self) -> Self;
 }
 
 #[cfg(feature = "serialport")]
@@ -764,6 +1072,35 @@ impl<T> ResultExt for Result<T, Error> {
             res => res,
         }
     }
+
+    fn brownout_hint(self) -> Self {
+        // Erasing draws the most current, so a device which stops responding
+        // during an erase, or disappears from the bus entirely, has most
+        // likely reset due to a brownout.
+        fn is_brownout(err: &CoreError) -> bool {
+            match err.downcast_ref::<ConnectionError>() {
+                Some(ConnectionError::Timeout(TimedOutCommand {
+                    command: Some(command),
+                    ..
+                })) => matches!(
+                    command,
+                    CommandType::FlashBegin
+                        | CommandType::FlashDeflBegin
+                        | CommandType::EraseFlash
+                        | CommandType::EraseRegion
+                ),
+                Some(ConnectionError::DeviceNotFound) => true,
+                _ => false,
+            }
+        }
+
+        match self {
+            Err(Error::Connection(err) | Error::Flashing(err)) if is_brownout(&err) => {
+                Err(Error::PossibleBrownout(err))
+            }
+            res => res,
+        }
+    }
 }
 
 #[cfg(feature = "serialport")]
@@ -0,0 +1,151 @@
+//! Software implementation of the flash encryption scheme
+//!
+//! Devices with flash encryption enabled transparently encrypt data written to
+//! and decrypt data read from flash. Data read using the `read-flash` command
+//! is returned in its encrypted form, so it has to be decrypted in software
+//! given the flash encryption key.
+//!
+//! All supported chips except the ESP32 use XTS-AES, operating on 128 byte
+//! blocks with the flash address of each block as the tweak. See:
+//! <https://docs.espressif.com/projects/esp-idf/en/latest/esp32c3/security/flash-encryption.html>
+
+use aes::{Aes128, Aes256, cipher::KeyInit};
+use xts_mode::Xts128;
+
+use crate::{Error, target::Chip};
+
+/// Size of the blocks the XTS-AES flash encryption operates on
+const XTS_BLOCK_SIZE: usize = 0x80;
+
+/// Decrypt data read from the flash of a device with flash encryption enabled
+///
+/// `key` is the raw flash encryption key as burned into the eFuses, either 256
+/// bits (XTS-AES-128) or 512 bits (XTS-AES-256). `address` is the flash
+/// offset the data was read from; both `address` and the length of `data` must
+/// be multiples of 16 bytes.
+pub fn decrypt_flash_data(
+    chip: Chip,
+    key: &[u8],
+    address: u32,
+    data: &[u8],
+) -> Result<Vec<u8>, Error> {
+    xts_operation(chip, key, address, data, false)
+}
+
+/// Encrypt data the same way a device with flash encryption enabled does
+///
+/// See [decrypt_flash_data] for the requirements on the arguments.
+pub fn encrypt_flash_data(
+    chip: Chip,
+    key: &[u8],
+    address: u32,
+    data: &[u8],
+) -> Result<Vec<u8>, Error> {
+    xts_operation(chip, key, address, data, true)
+}
+
+fn xts_operation(
+    chip: Chip,
+    key: &[u8],
+    address: u32,
+    data: &[u8],
+    encrypt: bool,
+) -> Result<Vec<u8>, Error> {
+    if chip == Chip::Esp32 {
+        return Err(Error::UnsupportedFeature {
+            chip,
+            feature: "software flash encryption".into(),
+        });
+    }
+
+    if address % 16 != 0 || data.len() % 16 != 0 {
+        return Err(Error::UnalignedEncryptedRegion {
+            address,
+            size: data.len() as u32,
+        });
+    }
+
+    let xts = XtsCipher::new(key)?;
+
+    // Pad the data so that it is made up of whole blocks, aligned to the block
+    // size in flash.
+    let pad_left = address as usize % XTS_BLOCK_SIZE;
+    let pad_right = (XTS_BLOCK_SIZE - (pad_left + data.len()) % XTS_BLOCK_SIZE) % XTS_BLOCK_SIZE;
+
+    let mut buffer = vec![0u8; pad_left];
+    buffer.extend_from_slice(data);
+    buffer.resize(buffer.len() + pad_right, 0);
+
+    let mut block_address = address & !(XTS_BLOCK_SIZE as u32 - 1);
+    for block in buffer.chunks_mut(XTS_BLOCK_SIZE) {
+        let mut tweak = [0u8; 16];
+        tweak[..4].copy_from_slice(&block_address.to_le_bytes());
+
+        // The hardware processes the data in reverse byte order.
+        block.reverse();
+        xts.process(block, tweak, encrypt);
+        block.reverse();
+
+        block_address += XTS_BLOCK_SIZE as u32;
+    }
+
+    Ok(buffer[pad_left..pad_left + data.len()].to_vec())
+}
+
+enum XtsCipher {
+    Aes128(Xts128<Aes128>),
+    Aes256(Xts128<Aes256>),
+}
+
+impl XtsCipher {
+    fn new(key: &[u8]) -> Result<Self, Error> {
+        let cipher = match key.len() {
+            32 => XtsCipher::Aes128(Xts128::new(
+                Aes128::new_from_slice(&key[..16]).unwrap(),
+                Aes128::new_from_slice(&key[16..]).unwrap(),
+            )),
+            64 => XtsCipher::Aes256(Xts128::new(
+                Aes256::new_from_slice(&key[..32]).unwrap(),
+                Aes256::new_from_slice(&key[32..]).unwrap(),
+            )),
+            len => return Err(Error::InvalidFlashEncryptionKey(len)),
+        };
+
+        Ok(cipher)
+    }
+
+    fn process(&self, block: &mut [u8], tweak: [u8; 16], encrypt: bool) {
+        match (self, encrypt) {
+            (XtsCipher::Aes128(xts), true) => xts.encrypt_sector(block, tweak),
+            (XtsCipher::Aes128(xts), false) => xts.decrypt_sector(block, tweak),
+            (XtsCipher::Aes256(xts), true) => xts.encrypt_sector(block, tweak),
+            (XtsCipher::Aes256(xts), false) => xts.decrypt_sector(block, tweak),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = [0x5Au8; 32];
+        let data = (0..0x120).map(|i| i as u8).collect::<Vec<_>>();
+
+        let encrypted = encrypt_flash_data(Chip::Esp32c3, &key, 0x10010, &data).unwrap();
+        assert_ne!(encrypted, data);
+
+        let decrypted = decrypt_flash_data(Chip::Esp32c3, &key, 0x10010, &encrypted).unwrap();
+        assert_eq!(decrypted, data);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let data = [0u8; 32];
+
+        assert!(decrypt_flash_data(Chip::Esp32c3, &[0u8; 16], 0x1000, &data).is_err());
+        assert!(decrypt_flash_data(Chip::Esp32c3, &[0u8; 32], 0x1001, &data).is_err());
+        assert!(decrypt_flash_data(Chip::Esp32, &[0u8; 32], 0x1000, &data).is_err());
+    }
+}
@@ -6,9 +6,9 @@
 
 #[cfg(feature = "serialport")]
 use std::fs::OpenOptions;
-use std::str::FromStr;
 #[cfg(feature = "serialport")]
-use std::{borrow::Cow, io::Write, path::PathBuf, thread::sleep, time::Duration};
+use std::{borrow::Cow, io::Write, thread::sleep, time::Duration};
+use std::{fs, path::PathBuf, str::FromStr};
 
 #[cfg(feature = "serialport")]
 use log::{debug, info, warn};
@@ -19,6 +19,10 @@ use object::{Endianness, read::elf::ElfFile32 as ElfFile};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator, VariantNames};
 
+#[cfg(feature = "serialport")]
+use self::status::{FlashStatus, StatusLayout};
+#[cfg(feature = "serialport")]
+pub use self::stubs::FlashStub;
 #[cfg(feature = "serialport")]
 use crate::connection::Port;
 // Re-export SecurityInfo from connection module for backward compatibility
@@ -26,25 +30,23 @@ use crate::connection::Port;
 #[cfg(feature = "serialport")]
 pub use crate::connection::SecurityInfo;
 #[cfg(feature = "serialport")]
-use crate::target::{DefaultProgressCallback, ProgressCallbacks};
+use crate::target::{DefaultProgressCallback, Esp32Target, FlashTarget, ProgressCallbacks};
 use crate::{
     Error,
+    image_format::partitions::DEFAULT_PARTITION_TABLE_OFFSET,
     target::{Chip, XtalFrequency},
 };
 #[cfg(feature = "serialport")]
 use crate::{
     command::{Command, CommandType},
     connection::{Connection, reset::ResetBeforeOperation},
-    error::{ConnectionError, ResultExt as _},
-    flasher::stubs::{
-        CHIP_DETECT_MAGIC_REG_ADDR,
-        DEFAULT_TIMEOUT,
-        EXPECTED_STUB_HANDSHAKE,
-        FlashStub,
-    },
+    error::{ConnectionError, ResultExt as _, TimedOutCommand},
+    flasher::stubs::{CHIP_DETECT_MAGIC_REG_ADDR, DEFAULT_TIMEOUT, EXPECTED_STUB_HANDSHAKE},
     image_format::{ImageFormat, Segment, ram_segments, rom_segments},
 };
 
+pub mod encryption;
+pub mod status;
 #[cfg(feature = "serialport")]
 pub(crate) mod stubs;
 
@@ -53,9 +55,55 @@ pub(crate) mod stubs;
 pub(crate) const TRY_SPI_PARAMS: [SpiAttachParams; 2] =
     [SpiAttachParams::default(), SpiAttachParams::esp32_pico_d4()];
 
-#[cfg(feature = "serialport")]
 pub(crate) const FLASH_SECTOR_SIZE: usize = 0x1000;
+
+/// SPI flash opcodes used to access the flash status registers
+#[cfg(feature = "serialport")]
+const SPI_FLASH_WRSR: u8 = 0x01;
+#[cfg(feature = "serialport")]
+const SPI_FLASH_RDSR: u8 = 0x05;
+#[cfg(feature = "serialport")]
+const SPI_FLASH_WREN: u8 = 0x06;
+#[cfg(feature = "serialport")]
+const SPI_FLASH_WRDI: u8 = 0x04;
+#[cfg(feature = "serialport")]
+const SPI_FLASH_RDSR2: u8 = 0x35;
+/// SPI flash opcode to read the configuration register of Macronix chips
+#[cfg(feature = "serialport")]
+const SPI_FLASH_RDCR: u8 = 0x15;
+/// SPI flash opcode to read the unique ID, followed by four dummy bytes
+#[cfg(feature = "serialport")]
+const SPI_FLASH_RDUID: u8 = 0x4B;
+
+/// Write-in-progress bit of the first status register
+#[cfg(feature = "serialport")]
+const STATUS_WIP: u32 = 1 << 0;
+
+/// Block protection bits of the status registers for the given layout
+///
+/// For chips with an unknown layout only BP0-BP2, which all chips share, are
+/// considered.
+#[cfg(feature = "serialport")]
+fn status_protect_mask(layout: Option<StatusLayout>) -> u16 {
+    match layout {
+        // BP0-BP2, TB and SEC in the first register, CMP in the second one
+        Some(StatusLayout::Winbond) => 0x7C | (1 << 14),
+        // BP0-BP3
+        Some(StatusLayout::Macronix) => 0x3C,
+        None => 0x1C,
+    }
+}
 pub(crate) const FLASH_WRITE_SIZE: usize = 0x400;
+pub(crate) const MAX_STUB_FLASH_WRITE_SIZE: usize = 0x4000;
+pub(crate) const MIN_FLASH_WRITE_SIZE: usize = 0x100;
+
+/// Baud rates to step down through when automatic baud backoff is enabled
+#[cfg(feature = "serialport")]
+const BAUD_BACKOFF_STEPS: [u32; 4] = [921_600, 460_800, 230_400, 115_200];
+/// Number of communication errors within one segment after which automatic
+/// baud backoff steps down to the next lower baud rate
+#[cfg(feature = "serialport")]
+const BAUD_BACKOFF_ERROR_THRESHOLD: usize = 3;
 
 /// Supported flash frequencies
 ///
@@ -121,7 +169,7 @@ impl FlashFrequency {
 /// Supported flash modes
 #[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 #[derive(
-    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, VariantNames, Serialize, Deserialize,
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, VariantNames, EnumIter, Serialize, Deserialize,
 )]
 #[non_exhaustive]
 #[strum(serialize_all = "lowercase")]
@@ -222,6 +270,27 @@ impl FlashSize {
         Ok(encoded)
     }
 
+    /// Decodes the flash size from the format used by the bootloader, see
+    /// [FlashSize::encode_flash_size]
+    pub const fn decode_flash_size(value: u8) -> Result<FlashSize, Error> {
+        use FlashSize::*;
+
+        let size = match value {
+            0 => _1Mb,
+            1 => _2Mb,
+            2 => _4Mb,
+            3 => _8Mb,
+            4 => _16Mb,
+            5 => _32Mb,
+            6 => _64Mb,
+            7 => _128Mb,
+            8 => _256Mb,
+            _ => return Err(Error::UnsupportedFlash(value)),
+        };
+
+        Ok(size)
+    }
+
     /// Create a [FlashSize] from an [u8]
     ///
     /// [source](https://github.com/espressif/esptool/blob/f4d2510/esptool/cmds.py#L42)
@@ -309,7 +378,7 @@ impl FlashSettings {
 }
 
 /// Flash data and configuration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct FlashData {
     /// Flash settings.
@@ -322,6 +391,28 @@ pub struct FlashData {
     pub chip: Chip,
     /// Crystal frequency.
     pub xtal_freq: XtalFrequency,
+    /// Base address added to every flash write address.
+    ///
+    /// Used to relocate the whole flash layout (bootloader, partition table and
+    /// application), e.g. to reserve a region at `0x0` for a custom loader.
+    pub flash_base: u32,
+    /// Clear the compile time and date in the app descriptor.
+    ///
+    /// These are the only fields which differ between builds of the same
+    /// sources, so clearing them makes the generated image reproducible.
+    pub reproducible: bool,
+    /// Entry point of the application, overriding the one from the ELF file.
+    pub entry: Option<u32>,
+    /// Revision of the connected chip, in format `major * 100 + minor`.
+    ///
+    /// Used to select a bundled bootloader which supports the revision.
+    pub chip_revision: Option<u16>,
+    /// Path to a bootloader to use instead of the bundled one.
+    pub bootloader: Option<PathBuf>,
+    /// Path to a partition table to use instead of the default one.
+    pub partition_table: Option<PathBuf>,
+    /// Offset of the partition table, relative to the flash base.
+    pub partition_table_offset: Option<u32>,
 }
 
 impl FlashData {
@@ -339,10 +430,189 @@ impl FlashData {
             mmu_page_size,
             chip,
             xtal_freq,
+            flash_base: 0,
+            reproducible: false,
+            entry: None,
+            chip_revision: None,
+            bootloader: None,
+            partition_table: None,
+            partition_table_offset: None,
         }
     }
 }
 
+/// Builder for [FlashData]
+///
+/// Only the chip and its crystal frequency are required, everything else
+/// falls back to the same defaults as [FlashData::new]. Combinations of
+/// options are validated by [FlashDataBuilder::build].
+#[derive(Debug, Clone)]
+pub struct FlashDataBuilder {
+    flash_data: FlashData,
+}
+
+impl FlashDataBuilder {
+    /// Creates a builder for the given chip and crystal frequency
+    pub fn new(chip: Chip, xtal_freq: XtalFrequency) -> Self {
+        Self {
+            flash_data: FlashData::new(FlashSettings::default(), 0, None, chip, xtal_freq),
+        }
+    }
+
+    /// Sets the flash mode written to the image header
+    pub fn flash_mode(mut self, mode: impl Into<Option<FlashMode>>) -> Self {
+        self.flash_data.flash_settings.mode = mode.into();
+        self
+    }
+
+    /// Sets the flash size written to the image header
+    pub fn flash_size(mut self, size: impl Into<Option<FlashSize>>) -> Self {
+        self.flash_data.flash_settings.size = size.into();
+        self
+    }
+
+    /// Sets the flash frequency written to the image header
+    pub fn flash_freq(mut self, freq: impl Into<Option<FlashFrequency>>) -> Self {
+        self.flash_data.flash_settings.freq = freq.into();
+        self
+    }
+
+    /// Sets the minimum chip revision, in format `major * 100 + minor`
+    pub fn min_chip_rev(mut self, min_chip_rev: u16) -> Self {
+        self.flash_data.min_chip_rev = min_chip_rev;
+        self
+    }
+
+    /// Sets the MMU page size, overriding the one from the app descriptor
+    pub fn mmu_page_size(mut self, mmu_page_size: impl Into<Option<u32>>) -> Self {
+        self.flash_data.mmu_page_size = mmu_page_size.into();
+        self
+    }
+
+    /// Sets the base address added to every flash write address
+    pub fn flash_base(mut self, flash_base: u32) -> Self {
+        self.flash_data.flash_base = flash_base;
+        self
+    }
+
+    /// Clears the compile time and date in the app descriptor
+    pub fn reproducible(mut self, reproducible: bool) -> Self {
+        self.flash_data.reproducible = reproducible;
+        self
+    }
+
+    /// Sets the entry point, overriding the one from the ELF file
+    pub fn entry(mut self, entry: impl Into<Option<u32>>) -> Self {
+        self.flash_data.entry = entry.into();
+        self
+    }
+
+    /// Sets the revision of the connected chip, in format `major * 100 +
+    /// minor`
+    pub fn chip_revision(mut self, chip_revision: impl Into<Option<u16>>) -> Self {
+        self.flash_data.chip_revision = chip_revision.into();
+        self
+    }
+
+    /// Sets the path of a bootloader to use instead of the bundled one
+    pub fn bootloader(mut self, path: impl Into<Option<PathBuf>>) -> Self {
+        self.flash_data.bootloader = path.into();
+        self
+    }
+
+    /// Sets the path of a partition table to use instead of the default one
+    pub fn partition_table(mut self, path: impl Into<Option<PathBuf>>) -> Self {
+        self.flash_data.partition_table = path.into();
+        self
+    }
+
+    /// Sets the offset of the partition table, relative to the flash base
+    pub fn partition_table_offset(mut self, offset: impl Into<Option<u32>>) -> Self {
+        self.flash_data.partition_table_offset = offset.into();
+        self
+    }
+
+    /// Validates the options and builds the [FlashData]
+    pub fn build(self) -> Result<FlashData, Error> {
+        let flash_data = self.flash_data;
+        let invalid = |reason: String| Err(Error::InvalidFlashData(reason));
+
+        if let Some(mmu_page_size) = flash_data.mmu_page_size {
+            let valid = crate::image_format::idf::valid_mmu_page_sizes(flash_data.chip);
+            if !valid.contains(&mmu_page_size) {
+                return invalid(format!(
+                    "MMU page size {mmu_page_size:#x} is not supported by the {}",
+                    flash_data.chip
+                ));
+            }
+        }
+
+        if flash_data.flash_base % FLASH_SECTOR_SIZE as u32 != 0 {
+            return invalid(format!(
+                "flash base {:#x} is not aligned to the {FLASH_SECTOR_SIZE:#x} byte sector size",
+                flash_data.flash_base
+            ));
+        }
+
+        // The flash size is only known if it was given, otherwise whatever is
+        // fitted may be large enough
+        if let Some(size) = flash_data
+            .flash_settings
+            .size
+            .filter(|size| flash_data.flash_base >= size.size())
+        {
+            return invalid(format!(
+                "flash base {:#x} lies outside of the {size} flash",
+                flash_data.flash_base
+            ));
+        }
+
+        // The bootloader is written at the boot address of the chip, and has to
+        // end before the partition table starts
+        let boot_address = flash_data.chip.boot_address();
+        if let Some(offset) = flash_data.partition_table_offset {
+            if offset % FLASH_SECTOR_SIZE as u32 != 0 {
+                return invalid(format!(
+                    "partition table offset {offset:#x} is not aligned to the \
+                    {FLASH_SECTOR_SIZE:#x} byte sector size"
+                ));
+            }
+            if offset <= boot_address {
+                return invalid(format!(
+                    "partition table offset {offset:#x} leaves no room for the bootloader at \
+                    {boot_address:#x}"
+                ));
+            }
+        }
+
+        // Without an explicit offset, a custom partition table is placed right
+        // before its first partition, which is only known once it is parsed
+        let table_offset = match (
+            &flash_data.partition_table,
+            flash_data.partition_table_offset,
+        ) {
+            (_, Some(offset)) => Some(offset),
+            (None, None) => Some(DEFAULT_PARTITION_TABLE_OFFSET),
+            (Some(_), None) => None,
+        };
+        if let (Some(path), Some(table_offset)) = (&flash_data.bootloader, table_offset) {
+            let size = fs::metadata(path)
+                .map_err(|e| Error::FileOpenError(path.display().to_string(), e))?
+                .len();
+            let room = table_offset - boot_address;
+            if size > room as u64 {
+                return invalid(format!(
+                    "bootloader {} ({size} bytes) at {boot_address:#x} overlaps the partition \
+                    table at {table_offset:#x}",
+                    path.display()
+                ));
+            }
+        }
+
+        Ok(flash_data)
+    }
+}
+
 /// Parameters of the attached SPI flash chip (sizes, etc).
 ///
 /// See: <https://github.com/espressif/esptool/blob/da31d9d/esptool.py#L655>
@@ -450,6 +720,13 @@ pub struct DeviceInfo {
     pub revision: Option<(u32, u32)>,
     /// The crystal frequency of the chip
     pub crystal_frequency: XtalFrequency,
+    /// The measured frequency of the clock driving the UART in Hz, for chips
+    /// whose crystal frequency is detected at runtime
+    #[serde(default)]
+    pub uart_clock: Option<u32>,
+    /// The baud rate of the connection
+    #[serde(default)]
+    pub baud_rate: u32,
     /// The total available flash size
     pub flash_size: FlashSize,
     /// Device features
@@ -507,6 +784,105 @@ impl DeviceInfo {
     }
 }
 
+/// A region of flash covered by an image segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlashRegion {
+    /// Flash offset of the region
+    pub offset: u32,
+    /// Length of the region in bytes
+    pub length: u32,
+    /// MD5 digest of the region's contents
+    pub md5: [u8; 16],
+}
+
+/// A region of flash erased while writing an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErasedRegion {
+    /// Flash offset of the region
+    pub offset: u32,
+    /// Length of the region in bytes
+    pub length: u32,
+}
+
+/// Report of the flash regions touched while writing an image
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FlashReport {
+    /// Regions which were written
+    pub written: Vec<FlashRegion>,
+    /// Regions which were skipped, as the flash already held their contents
+    pub skipped: Vec<FlashRegion>,
+    /// Regions which were erased before being written, rounded up to whole
+    /// sectors
+    pub erased: Vec<ErasedRegion>,
+}
+
+/// Forwards progress updates, recording whether the segment was skipped
+#[cfg(feature = "serialport")]
+struct RecordingProgress<'a> {
+    inner: &'a mut dyn ProgressCallbacks,
+    skipped: bool,
+}
+
+#[cfg(feature = "serialport")]
+impl ProgressCallbacks for RecordingProgress<'_> {
+    fn init(&mut self, addr: u32, total: usize) {
+        self.skipped = false;
+        self.inner.init(addr, total);
+    }
+
+    fn update(&mut self, current: usize) {
+        self.inner.update(current);
+    }
+
+    fn verifying(&mut self) {
+        self.inner.verifying();
+    }
+
+    fn finish(&mut self, skipped: bool) {
+        self.skipped = skipped;
+        self.inner.finish(skipped);
+    }
+
+    fn is_cancelled(&mut self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
+
+/// Decides when automatic baud backoff steps down the baud rate
+///
+/// Single communication errors are retried at the same baud rate, only a
+/// cluster of [BAUD_BACKOFF_ERROR_THRESHOLD] errors while writing one segment
+/// steps down to the next lower rate, until the lowest one also fails.
+#[cfg(feature = "serialport")]
+#[derive(Debug, Default)]
+struct BaudBackoff {
+    errors: usize,
+}
+
+#[cfg(feature = "serialport")]
+impl BaudBackoff {
+    /// Record an error at the current baud rate, returning the baud rate to
+    /// retry at, or `None` to give up
+    fn error(&mut self, current: u32) -> Option<u32> {
+        self.errors += 1;
+        if self.errors < BAUD_BACKOFF_ERROR_THRESHOLD {
+            return Some(current);
+        }
+
+        self.errors = 0;
+        BAUD_BACKOFF_STEPS
+            .iter()
+            .copied()
+            .find(|&baud| baud < current)
+    }
+
+    /// Record a successfully written segment
+    fn success(&mut self) {
+        self.errors = 0;
+    }
+}
+
 /// Connect to and flash a target device
 #[cfg(feature = "serialport")]
 #[derive(Debug)]
@@ -523,8 +899,22 @@ pub struct Flasher {
     use_stub: bool,
     /// Indicate verifying flash contents after flashing
     verify: bool,
+    /// Only verify the application when flashing an image
+    verify_app_only: bool,
+    /// Always verify the bootloader when flashing an image
+    verify_bootloader: bool,
+    /// Compress the data written to the bootloader region
+    compress_bootloader: bool,
     /// Indicate skipping of already flashed regions
     skip: bool,
+    /// Size of each block of data sent while writing flash
+    flash_write_size: usize,
+    /// Step down the baud rate and retry on communication errors while writing
+    auto_baud_backoff: bool,
+    /// Reset the target after writing to flash
+    reset_after_flash: bool,
+    /// Write the bootloader and partition table of an image twice
+    redundant_write: bool,
 }
 
 #[cfg(feature = "serialport")]
@@ -532,12 +922,75 @@ impl Flasher {
     /// The serial port's baud rate should be 115_200 to connect. After
     /// connecting, Flasher will change the baud rate to the `baud`
     /// parameter.
+    ///
+    /// If `chip` is provided, it is the chip expected to be connected: when the
+    /// chip is detected and turns out to be a different one, connecting fails
+    /// with [Error::ChipMismatch] instead of continuing with the wrong chip's
+    /// parameters. With [ResetBeforeOperation::NoResetNoSync] no detection
+    /// takes place and `chip` is required.
     pub fn connect(
+        connection: Connection,
+        use_stub: bool,
+        verify: bool,
+        skip: bool,
+        chip: Option<Chip>,
+        baud: Option<u32>,
+    ) -> Result<Self, Error> {
+        Self::connect_impl(connection, use_stub, verify, skip, chip, None, baud)
+    }
+
+    /// Detect which chip is connected, without uploading the stub or touching
+    /// the flash.
+    ///
+    /// The device is reset into download mode to synchronize with the ROM
+    /// loader, and the reset configured as the connection's
+    /// [ResetAfterOperation](crate::connection::ResetAfterOperation) is
+    /// performed once the chip has been detected. The connection is closed
+    /// afterwards.
+    pub fn detect_chip(mut connection: Connection) -> Result<Chip, Error> {
+        connection.begin()?;
+        connection.set_timeout(DEFAULT_TIMEOUT)?;
+
+        let chip = connection.detect_chip(false)?;
+        connection.reset_after(false, chip)?;
+
+        Ok(chip)
+    }
+
+    /// Connect to a device whose chip and flash size are already known.
+    ///
+    /// Works like [Flasher::connect], but skips the detection of the chip and
+    /// of the flash size, trusting the provided parameters instead. The device
+    /// is still synchronized with, and the stub uploaded if enabled.
+    pub fn connect_without_autodetect(
+        connection: Connection,
+        use_stub: bool,
+        verify: bool,
+        skip: bool,
+        chip: Chip,
+        flash_size: FlashSize,
+        baud: Option<u32>,
+    ) -> Result<Self, Error> {
+        Self::connect_impl(
+            connection,
+            use_stub,
+            verify,
+            skip,
+            Some(chip),
+            Some(flash_size),
+            baud,
+        )
+    }
+
+    /// Connect to the device, detecting the flash size unless `flash_size` is
+    /// provided, in which case `chip` must be provided too.
+    fn connect_impl(
         mut connection: Connection,
         use_stub: bool,
         verify: bool,
         skip: bool,
         chip: Option<Chip>,
+        flash_size: Option<FlashSize>,
         baud: Option<u32>,
     ) -> Result<Self, Error> {
         // The connection should already be established with the device using the
@@ -547,8 +1000,10 @@ impl Flasher {
 
         detect_sdm(&mut connection);
 
-        let detected_chip = if connection.before_operation() != ResetBeforeOperation::NoResetNoSync
-        {
+        let detected_chip = if let (Some(chip), Some(_)) = (chip, flash_size) {
+            debug!("Skipping chip detection, using {chip}");
+            chip
+        } else if connection.before_operation() != ResetBeforeOperation::NoResetNoSync {
             // Detect which chip we are connected to.
             let detected_chip = connection.detect_chip(use_stub)?;
             if let Some(chip) = chip {
@@ -575,7 +1030,14 @@ impl Flasher {
             spi_params: SpiAttachParams::default(),
             use_stub,
             verify,
+            verify_app_only: false,
+            verify_bootloader: true,
+            compress_bootloader: true,
             skip,
+            flash_write_size: detected_chip.flash_write_size(),
+            auto_baud_backoff: false,
+            reset_after_flash: true,
+            redundant_write: false,
         };
 
         if flasher.connection.before_operation() == ResetBeforeOperation::NoResetNoSync {
@@ -586,10 +1048,15 @@ impl Flasher {
             // Load flash stub if enabled.
             if use_stub {
                 info!("Using flash stub");
-                flasher.load_stub()?;
+                flasher
+                    .load_stub()
+                    .map_err(|err| Error::StubLoadFailed(Box::new(err)))?;
             }
             // Flash size autodetection doesn't work in Secure Download Mode.
-            flasher.spi_autodetect()?;
+            match flash_size {
+                Some(flash_size) => flasher.spi_attach(flash_size)?,
+                None => flasher.spi_autodetect()?,
+            }
         } else if use_stub {
             warn!("Stub is not supported in Secure Download Mode, setting --no-stub");
             flasher.use_stub = false;
@@ -607,9 +1074,126 @@ impl Flasher {
         Ok(flasher)
     }
 
-    /// Set the flash size.
-    pub fn set_flash_size(&mut self, flash_size: FlashSize) {
+    /// Set the flash size, and pass it on to the loader.
+    ///
+    /// Overrides the detected flash size, or the one given to
+    /// [Flasher::connect_without_autodetect].
+    pub fn set_flash_size(&mut self, flash_size: FlashSize) -> Result<(), Error> {
         self.flash_size = flash_size;
+        self.spi_set_params()
+    }
+
+    /// Send the flash size to the loader, which it checks writes and erases
+    /// against
+    fn spi_set_params(&mut self) -> Result<(), Error> {
+        let spi_set_params = SpiSetParams::default(self.flash_size.size());
+        self.connection
+            .with_timeout(CommandType::SpiSetParams.timeout(), |connection| {
+                connection.command(Command::SpiSetParams {
+                    spi_params: spi_set_params,
+                })
+            })?;
+
+        Ok(())
+    }
+
+    /// Set the size of each block of data sent while writing flash.
+    ///
+    /// Larger blocks reduce protocol overhead and improve throughput on
+    /// reliable links, while smaller blocks lose less work when a block has to
+    /// be retried over a noisy connection. The size must be a power of two of
+    /// at least 256 bytes, and no larger than
+    /// [Chip::max_flash_write_size] for the current loader.
+    pub fn set_flash_block_size(&mut self, size: usize) -> Result<(), Error> {
+        let max = self.chip.max_flash_write_size(self.use_stub);
+        if !size.is_power_of_two() || !(MIN_FLASH_WRITE_SIZE..=max).contains(&size) {
+            return Err(Error::InvalidFlashBlockSize {
+                size,
+                min: MIN_FLASH_WRITE_SIZE,
+                max,
+            });
+        }
+
+        self.flash_write_size = size;
+
+        Ok(())
+    }
+
+    /// Enable or disable automatic baud rate backoff while writing flash.
+    ///
+    /// When enabled, a communication error while writing a segment resyncs
+    /// with the device and retries the segment, instead of failing
+    /// immediately. Repeated errors while writing the same segment step the
+    /// baud rate down (921,600 → 460,800 → 230,400 → 115,200), until writing
+    /// at 115,200 baud fails as well.
+    pub fn set_auto_baud_backoff(&mut self, enabled: bool) {
+        self.auto_baud_backoff = enabled;
+    }
+
+    /// Limit verification to the application when flashing an image.
+    ///
+    /// When enabled, only the application segment written by
+    /// [Flasher::load_image_to_flash] is verified, by comparing its MD5
+    /// digest. The bootloader, partition table and any other data are not
+    /// verified. Has no effect if verification is disabled.
+    pub fn set_verify_app_only(&mut self, enabled: bool) {
+        self.verify_app_only = enabled;
+    }
+
+    /// Enable or disable verifying the bootloader when flashing an image.
+    ///
+    /// When enabled (the default), the bootloader written by
+    /// [Flasher::load_image_to_flash] is verified by comparing its MD5 digest
+    /// even if verification is otherwise disabled, as a device with a corrupt
+    /// bootloader won't boot at all.
+    pub fn set_verify_bootloader(&mut self, enabled: bool) {
+        self.verify_bootloader = enabled;
+    }
+
+    /// Enable or disable compressing the data written to the bootloader region.
+    ///
+    /// Compression is enabled by default. Disabling it only for the bootloader
+    /// works around flash chips on which compressed writes to that region are
+    /// unreliable, while keeping the speed benefit for everything else.
+    pub fn set_compress_bootloader(&mut self, enabled: bool) {
+        self.compress_bootloader = enabled;
+    }
+
+    /// Enable or disable resetting the target after writing to flash.
+    ///
+    /// When enabled (the default), the reset configured by the connection's
+    /// [ResetAfterOperation](crate::connection::ResetAfterOperation) is
+    /// performed once writing has completed. Disabling it allows the caller to
+    /// reset the target at a later point.
+    pub fn set_reset_after_flash(&mut self, enabled: bool) {
+        self.reset_after_flash = enabled;
+    }
+
+    /// Enable or disable writing the critical regions of an image twice.
+    ///
+    /// When enabled, the bootloader and partition table written by
+    /// [Flasher::load_image_to_flash] are verified, written a second time and
+    /// verified again, failing if either write doesn't verify. This is meant
+    /// for critical-region reliability on high-value units, not general use.
+    pub fn set_redundant_write(&mut self, enabled: bool) {
+        self.redundant_write = enabled;
+    }
+
+    /// The flash target to write with, `boot_addr` being the address of the
+    /// bootloader when writing an image
+    fn flash_target(&self, boot_addr: Option<u32>) -> Box<dyn FlashTarget> {
+        Box::new(
+            Esp32Target::new(
+                self.chip,
+                self.spi_params,
+                self.use_stub,
+                self.verify && !self.verify_app_only,
+                self.skip,
+            )
+            .with_flash_write_size(self.flash_write_size)
+            .with_bootloader_compression(self.compress_bootloader)
+            .with_boot_address(boot_addr.unwrap_or(self.chip.boot_address())),
+        )
     }
 
     /// Disable the watchdog timer.
@@ -679,6 +1263,18 @@ impl Flasher {
         Ok(())
     }
 
+    /// Enable the flash with the default SPI parameters, assuming the given
+    /// flash size instead of detecting it.
+    fn spi_attach(&mut self, flash_size: FlashSize) -> Result<(), Error> {
+        // As with autodetection, this may fail while the flash connection succeeds
+        if let Err(_e) = self.enable_flash(SpiAttachParams::default()) {
+            debug!("Flash enable failed");
+        }
+
+        self.flash_size = flash_size;
+        self.spi_set_params()
+    }
+
     fn spi_autodetect(&mut self) -> Result<(), Error> {
         // Loop over all available SPI parameters until we find one that successfully
         // reads the flash size.
@@ -698,16 +1294,7 @@ impl Flasher {
                 // return.
                 self.flash_size = flash_size;
                 self.spi_params = spi_params;
-
-                let spi_set_params = SpiSetParams::default(self.flash_size.size());
-                self.connection.with_timeout(
-                    CommandType::SpiSetParams.timeout(),
-                    |connection| {
-                        connection.command(Command::SpiSetParams {
-                            spi_params: spi_set_params,
-                        })
-                    },
-                )?;
+                self.spi_set_params()?;
 
                 return Ok(());
             }
@@ -725,7 +1312,7 @@ impl Flasher {
     pub fn flash_detect(&mut self) -> Result<Option<FlashSize>, Error> {
         const FLASH_RETRY: u8 = 0xFF;
 
-        let flash_id = self.spi_command(CommandType::FlashDetect, &[], 24)?;
+        let flash_id = self.spi_command(CommandType::FlashDetect as u8, &[], 24)?;
         let size_id = (flash_id >> 16) as u8;
 
         // This value indicates that an alternate detection method should be tried.
@@ -746,6 +1333,131 @@ impl Flasher {
         Ok(Some(flash_size))
     }
 
+    /// Read the JEDEC ID of the flash chip
+    ///
+    /// The manufacturer ID is returned in the low byte, followed by the
+    /// memory type and capacity bytes.
+    pub fn flash_jedec_id(&mut self) -> Result<u32, Error> {
+        self.spi_command(CommandType::FlashDetect as u8, &[], 24)
+    }
+
+    /// Read the 64-bit unique ID of the flash chip
+    ///
+    /// The first byte sent by the flash chip is the most significant byte of
+    /// the result. Not all flash chips support this command. Those that don't
+    /// usually read back as all ones or all zeros, in which case `None` is
+    /// returned.
+    pub fn flash_unique_id(&mut self) -> Result<Option<u64>, Error> {
+        // The bytes are received into the data registers in little-endian order
+        let id = self
+            .spi_command_wide(SPI_FLASH_RDUID, &[0; 4], 64)?
+            .swap_bytes();
+
+        Ok((id != 0 && id != u64::MAX).then_some(id))
+    }
+
+    /// Read the flash chip's status register
+    ///
+    /// The first status register is returned in the low byte. The second one,
+    /// or the configuration register for Macronix chips, is returned in the
+    /// high byte for chips known to have it, see [StatusLayout]; for other
+    /// chips the high byte is zero.
+    pub fn read_flash_status(&mut self) -> Result<u16, Error> {
+        let layout = self.flash_status_layout()?;
+        self.read_status_registers(layout)
+    }
+
+    /// Read and decode the flash chip's status registers
+    ///
+    /// The layout of the registers is selected based on the manufacturer ID.
+    pub fn flash_status(&mut self) -> Result<FlashStatus, Error> {
+        let manufacturer = self.flash_jedec_id()? as u8;
+        let status = self.read_status_registers(StatusLayout::from_manufacturer(manufacturer))?;
+
+        Ok(FlashStatus::new(status, manufacturer))
+    }
+
+    /// Write the flash chip's status registers, see
+    /// [Flasher::read_flash_status] for the layout of `status`
+    ///
+    /// The high byte is only written to chips known to have a second status
+    /// register, the configuration register of Macronix chips is left
+    /// untouched.
+    pub fn write_flash_status(&mut self, status: u16) -> Result<(), Error> {
+        let layout = self.flash_status_layout()?;
+        self.write_status_registers(layout, status)
+    }
+
+    /// Whether any of the flash chip's write protection bits are set
+    pub fn is_flash_protected(&mut self) -> Result<bool, Error> {
+        let layout = self.flash_status_layout()?;
+        Ok(self.read_status_registers(layout)? & status_protect_mask(layout) != 0)
+    }
+
+    /// Clear the block protection bits of the flash chip's status register,
+    /// leaving all other bits untouched
+    pub fn unlock_flash(&mut self) -> Result<(), Error> {
+        let layout = self.flash_status_layout()?;
+        let mask = status_protect_mask(layout);
+
+        let status = self.read_status_registers(layout)?;
+        self.write_status_registers(layout, status & !mask)?;
+
+        let status = self.read_status_registers(layout)?;
+        if status & mask != 0 {
+            return Err(Error::FlashUnlockFailed(status));
+        }
+
+        Ok(())
+    }
+
+    /// Layout of the flash chip's status registers, if known
+    fn flash_status_layout(&mut self) -> Result<Option<StatusLayout>, Error> {
+        Ok(StatusLayout::from_manufacturer(self.flash_jedec_id()? as u8))
+    }
+
+    fn read_status_registers(&mut self, layout: Option<StatusLayout>) -> Result<u16, Error> {
+        let status1 = self.spi_command(SPI_FLASH_RDSR, &[], 8)?;
+        let status2 = match layout {
+            Some(StatusLayout::Winbond) => self.spi_command(SPI_FLASH_RDSR2, &[], 8)?,
+            Some(StatusLayout::Macronix) => self.spi_command(SPI_FLASH_RDCR, &[], 8)?,
+            None => 0,
+        };
+
+        Ok(((status2 as u16 & 0xFF) << 8) | (status1 as u16 & 0xFF))
+    }
+
+    fn write_status_registers(
+        &mut self,
+        layout: Option<StatusLayout>,
+        status: u16,
+    ) -> Result<(), Error> {
+        self.connection
+            .ensure_writable("write the flash status registers")?;
+
+        let bytes = status.to_le_bytes();
+        let data = match layout {
+            Some(StatusLayout::Winbond) => &bytes[..],
+            _ => &bytes[..1],
+        };
+
+        self.spi_command(SPI_FLASH_WREN, &[], 0)?;
+        self.spi_command(SPI_FLASH_WRSR, data, 0)?;
+
+        // Writing the status register is a non-volatile operation, wait for it
+        // to complete.
+        for _ in 0..100 {
+            if self.spi_command(SPI_FLASH_RDSR, &[], 8)? & STATUS_WIP == 0 {
+                return self.spi_command(SPI_FLASH_WRDI, &[], 0).map(|_| ());
+            }
+            sleep(Duration::from_millis(10));
+        }
+
+        Err(Error::Connection(Box::new(ConnectionError::Timeout(
+            TimedOutCommand::spi(SPI_FLASH_WRSR),
+        ))))
+    }
+
     fn enable_flash(&mut self, spi_params: SpiAttachParams) -> Result<(), Error> {
         self.connection
             .with_timeout(CommandType::SpiAttach.timeout(), |connection| {
@@ -759,13 +1471,16 @@ impl Flasher {
         Ok(())
     }
 
-    fn spi_command(
-        &mut self,
-        command: CommandType,
-        data: &[u8],
-        read_bits: u32,
-    ) -> Result<u32, Error> {
+    fn spi_command(&mut self, opcode: u8, data: &[u8], read_bits: u32) -> Result<u32, Error> {
         assert!(read_bits < 32);
+
+        self.spi_command_wide(opcode, data, read_bits)
+            .map(|result| result as u32)
+    }
+
+    /// Like [Flasher::spi_command], but allows reading back up to 64 bits
+    fn spi_command_wide(&mut self, opcode: u8, data: &[u8], read_bits: u32) -> Result<u64, Error> {
+        assert!(read_bits <= 64);
         assert!(data.len() < 64);
 
         let spi_registers = self.chip.spi_registers();
@@ -784,7 +1499,7 @@ impl Flasher {
         self.connection
             .write_reg(spi_registers.usr(), flags, None)?;
         self.connection
-            .write_reg(spi_registers.usr2(), (7 << 28) | command as u32, None)?;
+            .write_reg(spi_registers.usr2(), (7 << 28) | opcode as u32, None)?;
 
         if let (Some(mosi_data_length), Some(miso_data_length)) =
             (spi_registers.mosi_length(), spi_registers.miso_length())
@@ -835,12 +1550,16 @@ impl Flasher {
             i += 1;
             if i > 10 {
                 return Err(Error::Connection(Box::new(ConnectionError::Timeout(
-                    command.into(),
+                    TimedOutCommand::spi(opcode),
                 ))));
             }
         }
 
-        let result = self.connection.read_reg(spi_registers.w0())?;
+        let mut result = self.connection.read_reg(spi_registers.w0())? as u64;
+        if read_bits > 32 {
+            let high = self.connection.read_reg(spi_registers.w0() + 4)?;
+            result |= (high as u64) << 32;
+        }
         self.connection
             .write_reg(spi_registers.usr(), old_spi_usr, None)?;
         self.connection
@@ -859,6 +1578,16 @@ impl Flasher {
         self.chip
     }
 
+    /// The flash size of the connected device, as detected or overridden
+    pub fn flash_size(&self) -> FlashSize {
+        self.flash_size
+    }
+
+    /// Whether the RAM stub loader is in use, rather than the ROM loader
+    pub fn is_using_stub(&self) -> bool {
+        self.use_stub
+    }
+
     /// Read and print any information we can about the connected device
     pub fn device_info(&mut self) -> Result<DeviceInfo, Error> {
         let chip = self.chip();
@@ -868,6 +1597,8 @@ impl Flasher {
             .transpose()?;
 
         let crystal_frequency = chip.xtal_frequency(self.connection())?;
+        let uart_clock = chip.uart_clock_frequency(self.connection())?;
+        let baud_rate = self.connection.baud()?;
         let features = chip
             .chip_features(self.connection())?
             .iter()
@@ -882,6 +1613,8 @@ impl Flasher {
             chip,
             revision,
             crystal_frequency,
+            uart_clock,
+            baud_rate,
             flash_size: self.flash_size,
             features,
             mac_address,
@@ -919,16 +1652,14 @@ impl Flasher {
     }
 
     /// Load an ELF image to flash and execute it
+    ///
+    /// Returns a report of the regions which were written, skipped and
+    /// erased.
     pub fn load_image_to_flash<'a>(
         &mut self,
         progress: &mut dyn ProgressCallbacks,
         image_format: ImageFormat<'a>,
-    ) -> Result<(), Error> {
-        let mut target =
-            self.chip
-                .flash_target(self.spi_params, self.use_stub, self.verify, self.skip);
-        target.begin(&mut self.connection).flashing()?;
-
+    ) -> Result<FlashReport, Error> {
         // When the `cli` feature is enabled, display the image size information.
         #[cfg(feature = "cli")]
         {
@@ -941,18 +1672,59 @@ impl Flasher {
             }
         }
 
-        for segment in image_format.flash_segments() {
-            target
-                .write_segment(&mut self.connection, segment, progress)
-                .flashing()?;
-        }
+        let app_offset = image_format.app_offset();
+        let boot_addr = image_format.boot_address();
+        let critical = [boot_addr, image_format.partition_table_offset()];
+        self.write_flash_segments(
+            &image_format.flash_segments(),
+            progress,
+            Some(app_offset),
+            Some(boot_addr),
+            &critical,
+        )
+    }
 
-        target.finish(&mut self.connection, true).flashing()?;
+    /// Load an application image which is already in the ESP-IDF binary
+    /// format to flash
+    ///
+    /// This is the counterpart of [Flasher::load_image_to_flash] for images
+    /// which were saved with `save-image`, or built elsewhere. The flash mode,
+    /// size and frequency in the image header are patched with the given
+    /// settings, falling back to the detected flash size and the chip's
+    /// default frequency, and the appended SHA256 digest is updated to match.
+    /// The image is verified like the application of an ELF image.
+    pub fn load_bin_image_to_flash(
+        &mut self,
+        progress: &mut dyn ProgressCallbacks,
+        app_offset: u32,
+        image: &[u8],
+        flash_settings: FlashSettings,
+    ) -> Result<FlashReport, Error> {
+        let mut image = image.to_vec();
+        crate::image_format::idf::patch_flash_settings(
+            &mut image,
+            flash_settings.mode,
+            flash_settings.size.unwrap_or(self.flash_size),
+            flash_settings
+                .freq
+                .unwrap_or(self.chip.default_flash_frequency()),
+            self.chip,
+        )?;
 
-        Ok(())
+        let segment = Segment {
+            addr: app_offset,
+            data: Cow::Owned(image),
+        };
+        self.write_flash_segments(&[segment], progress, Some(app_offset), None, &[])
     }
 
     /// Load an bin image to flash at a specific address
+    ///
+    /// The region is erased by the device itself. The ROM loader erases the
+    /// whole region when the write begins. The flasher stub instead erases just
+    /// ahead of the data, in the background while the next block is received,
+    /// so erasing and writing already overlap without a sector being written
+    /// before it is erased.
     pub fn write_bin_to_flash(
         &mut self,
         addr: u32,
@@ -994,17 +1766,210 @@ impl Flasher {
             });
         }
 
-        let mut target =
-            self.chip
-                .flash_target(self.spi_params, self.use_stub, self.verify, self.skip);
+        self.write_flash_segments(segments, progress, None, None, &[])?;
 
-        target.begin(&mut self.connection).flashing()?;
+        Ok(())
+    }
 
-        for segment in segments {
-            target.write_segment(&mut self.connection, segment.borrow(), progress)?;
+    /// Write segments to flash, retrying at a lower baud rate on communication
+    /// errors if automatic baud backoff is enabled.
+    ///
+    /// `app_offset` is the address of the application segment when writing an
+    /// image, which is the only one verified when
+    /// [Flasher::set_verify_app_only] is enabled. `boot_addr` is the address of
+    /// the bootloader when writing an image, which is verified separately, see
+    /// [Flasher::set_verify_bootloader].
+    ///
+    /// Segments at one of the `critical` addresses are written twice when
+    /// [Flasher::set_redundant_write] is enabled.
+    fn write_flash_segments(
+        &mut self,
+        segments: &[Segment<'_>],
+        progress: &mut dyn ProgressCallbacks,
+        app_offset: Option<u32>,
+        boot_addr: Option<u32>,
+        critical: &[u32],
+    ) -> Result<FlashReport, Error> {
+        let mut target = self.flash_target(boot_addr);
+        target
+            .begin(&mut self.connection)
+            .flashing()
+            .brownout_hint()?;
+
+        let mut progress = RecordingProgress {
+            inner: progress,
+            skipped: false,
+        };
+        let mut report = FlashReport::default();
+
+        let mut backoff = BaudBackoff::default();
+        let mut backed_off = false;
+        let mut index = 0;
+        while let Some(segment) = segments.get(index) {
+            match target
+                .write_segment(&mut self.connection, segment.borrow(), &mut progress)
+                .flashing()
+            {
+                Ok(()) => {
+                    let region = FlashRegion {
+                        offset: segment.addr,
+                        length: segment.data.len() as u32,
+                        md5: Md5::digest(&segment.data).into(),
+                    };
+                    if progress.skipped {
+                        report.skipped.push(region);
+                    } else {
+                        if self.verify && self.verify_app_only && app_offset == Some(segment.addr) {
+                            let md5 = self.checksum_md5(region.offset, region.length)?;
+                            if md5.to_be_bytes() != region.md5 {
+                                return Err(Error::VerifyFailed);
+                            }
+                            debug!("Application at address '0x{:x}' verified", region.offset);
+                        }
+
+                        // Already verified by the flash target when verifying everything
+                        let verified = self.verify && !self.verify_app_only;
+                        if self.verify_bootloader && !verified && boot_addr == Some(segment.addr) {
+                            let md5 = self.checksum_md5(region.offset, region.length)?;
+                            if md5.to_be_bytes() != region.md5 {
+                                return Err(Error::BootloaderVerifyFailed(region.offset));
+                            }
+                            debug!("Bootloader at address '0x{:x}' verified", region.offset);
+                        }
+
+                        if self.redundant_write && critical.contains(&segment.addr) {
+                            self.rewrite_segment(segment, &region, boot_addr, &mut progress)?;
+                        }
+
+                        report.written.push(region);
+                        report.erased.push(ErasedRegion {
+                            offset: segment.addr,
+                            length: segment.data.len().next_multiple_of(FLASH_SECTOR_SIZE) as u32,
+                        });
+                    }
+                    backoff.success();
+                    index += 1;
+                }
+                Err(Error::Flashing(err)) if self.auto_baud_backoff => {
+                    let current = self.connection.baud()?;
+                    let Some(baud) = backoff.error(current) else {
+                        return Err(Error::Flashing(err)).brownout_hint();
+                    };
+
+                    if baud == current {
+                        warn!("Communication error while flashing ({err}), retrying");
+                    } else {
+                        warn!(
+                            "Repeated communication errors while flashing ({err}), retrying at {baud} baud"
+                        );
+                    }
+
+                    // Discard whatever is left of the failed transfer and make sure the device
+                    // responds again before sending it any further commands
+                    sleep(Duration::from_millis(100));
+                    self.connection.resync()?;
+                    if baud != current {
+                        self.change_baud(baud)?;
+                        backed_off = true;
+                    }
+
+                    // Restart the write of the failed segment
+                    target = self.flash_target(boot_addr);
+                    target
+                        .begin(&mut self.connection)
+                        .flashing()
+                        .brownout_hint()?;
+                }
+                Err(Error::Cancelled) => {
+                    info!("Flashing cancelled, resetting the device");
+                    self.connection.reset()?;
+                    return Err(Error::Cancelled);
+                }
+                Err(err) => return Err(err).brownout_hint(),
+            }
         }
 
-        target.finish(&mut self.connection, true).flashing()?;
+        if let Some(boot_addr) =
+            boot_addr.filter(|&addr| segments.iter().any(|segment| segment.addr == addr))
+        {
+            if let Err(err) = self.check_bootloader_flash_size(boot_addr) {
+                debug!("Could not check the flash size in the bootloader header: {err}");
+            }
+        }
+
+        target
+            .finish(&mut self.connection, self.reset_after_flash)
+            .flashing()?;
+
+        if backed_off {
+            info!("Flashing completed at {} baud", self.connection.baud()?);
+        }
+
+        Ok(report)
+    }
+
+    /// Verify a segment which was just written, then write it a second time
+    /// and verify it again
+    fn rewrite_segment(
+        &mut self,
+        segment: &Segment<'_>,
+        region: &FlashRegion,
+        boot_addr: Option<u32>,
+        progress: &mut dyn ProgressCallbacks,
+    ) -> Result<(), Error> {
+        let md5 = self.checksum_md5(region.offset, region.length)?;
+        if md5.to_be_bytes() != region.md5 {
+            return Err(Error::VerifyFailed);
+        }
+
+        info!("Writing the region at {:#x} a second time", segment.addr);
+
+        // The flash target is already attached, and this one always verifies
+        // and never skips the write
+        let mut target = Esp32Target::new(self.chip, self.spi_params, self.use_stub, true, false)
+            .with_flash_write_size(self.flash_write_size)
+            .with_bootloader_compression(self.compress_bootloader)
+            .with_boot_address(boot_addr.unwrap_or(self.chip.boot_address()));
+        target
+            .write_segment(&mut self.connection, segment.borrow(), progress)
+            .flashing()?;
+
+        debug!("Region at address '0x{:x}' written twice", segment.addr);
+
+        Ok(())
+    }
+
+    /// Read back the header of the bootloader and warn if the flash size in it
+    /// doesn't match the detected size of the flash
+    ///
+    /// A bootloader built for a larger flash than is fitted is one of the most
+    /// common reasons for a device not booting after flashing.
+    fn check_bootloader_flash_size(&mut self, boot_addr: u32) -> Result<(), Error> {
+        // Reading flash is only supported by the stub
+        if !self.use_stub {
+            return Ok(());
+        }
+        let Some(detected) = self.flash_detect()? else {
+            return Ok(());
+        };
+
+        let header = self.read_flash_data(boot_addr, 4, 4, 1)?;
+        if header[0] != crate::image_format::idf::ESP_MAGIC {
+            warn!("No valid bootloader found at {boot_addr:#x} after flashing");
+            return Ok(());
+        }
+
+        let header_size = FlashSize::decode_flash_size(header[3] >> 4)?;
+        if header_size != detected {
+            warn!(
+                "The bootloader header specifies {header_size} of flash, but {detected} was detected"
+            );
+            warn!(
+                "The device may fail to boot, rebuild the bootloader or flash with `--flash-size {detected}`"
+            );
+        } else {
+            debug!("Bootloader flash size {header_size} matches the detected flash size");
+        }
 
         Ok(())
     }
@@ -1012,7 +1977,7 @@ impl Flasher {
     /// Get MD5 of region
     pub fn checksum_md5(&mut self, addr: u32, length: u32) -> Result<u128, Error> {
         self.connection.with_timeout(
-            CommandType::FlashMd5.timeout_for_size(length),
+            self.connection.op_timeout(CommandType::FlashMd5, length),
             |connection| {
                 connection
                     .command(Command::FlashMd5 {
@@ -1041,13 +2006,13 @@ impl Flasher {
 
         let xtal_freq = self.chip.xtal_frequency(&mut self.connection)?;
 
-        // Probably this is just a temporary solution until the next chip revision.
-        //
-        // The ROM code thinks it uses a 40 MHz XTAL. Recompute the baud rate in order
-        // to trick the ROM code to set the correct baud rate for a 26 MHz XTAL.
+        // The ROM code may assume a different XTAL than the one actually in use.
+        // Recompute the baud rate in order to trick the ROM code into setting the
+        // correct baud rate for the actual XTAL.
         let mut new_baud = baud;
-        if self.chip == Chip::Esp32c2 && !self.use_stub && xtal_freq == XtalFrequency::_26Mhz {
-            new_baud = new_baud * 40 / 26;
+        let rom_xtal_freq = self.chip.rom_xtal_frequency(xtal_freq);
+        if !self.use_stub && rom_xtal_freq != xtal_freq {
+            new_baud = new_baud * rom_xtal_freq.mhz() / xtal_freq.mhz();
         }
 
         self.connection
@@ -1068,23 +2033,60 @@ impl Flasher {
     pub fn erase_region(&mut self, offset: u32, size: u32) -> Result<(), Error> {
         debug!("Erasing region of 0x{size:x}B at 0x{offset:08x}");
 
-        self.connection.with_timeout(
-            CommandType::EraseRegion.timeout_for_size(size),
-            |connection| connection.command(Command::EraseRegion { offset, size }),
-        )?;
+        self.connection
+            .with_timeout(
+                self.connection.op_timeout(CommandType::EraseRegion, size),
+                |connection| connection.command(Command::EraseRegion { offset, size }),
+            )
+            .brownout_hint()?;
         std::thread::sleep(Duration::from_secs_f32(0.05));
         self.connection.flush()?;
         Ok(())
     }
 
+    /// Write data to the chip's memory, e.g. to RTC memory before running
+    /// code from RAM.
+    ///
+    /// The data is written a word at a time and zero-padded to a multiple of
+    /// 4 bytes. The address must be 4-byte aligned, and the region must lie
+    /// within one of the chip's RAM regions.
+    pub fn write_memory(&mut self, addr: u32, data: &[u8]) -> Result<(), Error> {
+        let size = data.len().next_multiple_of(4) as u32;
+        let end = addr.checked_add(size.max(1) - 1);
+        let in_ram =
+            end.is_some_and(|end| self.chip.addr_is_ram(addr) && self.chip.addr_is_ram(end));
+        if addr % 4 != 0 || !in_ram {
+            return Err(Error::InvalidMemoryRegion {
+                addr,
+                size,
+                chip: self.chip,
+            });
+        }
+
+        self.connection.ensure_writable("write memory")?;
+        debug!("Writing 0x{size:x}B to memory at 0x{addr:08x}");
+
+        for (i, word) in data.chunks(4).enumerate() {
+            let mut bytes = [0u8; 4];
+            bytes[..word.len()].copy_from_slice(word);
+
+            self.connection
+                .write_reg(addr + i as u32 * 4, u32::from_le_bytes(bytes), None)?;
+        }
+
+        Ok(())
+    }
+
     /// Erase entire flash.
     pub fn erase_flash(&mut self) -> Result<(), Error> {
         debug!("Erasing the entire flash");
 
         self.connection
-            .with_timeout(CommandType::EraseFlash.timeout(), |connection| {
-                connection.command(Command::EraseFlash)
-            })?;
+            .with_timeout(
+                self.connection.op_timeout(CommandType::EraseFlash, 0),
+                |connection| connection.command(Command::EraseFlash),
+            )
+            .brownout_hint()?;
         sleep(Duration::from_secs_f32(0.05));
         self.connection.flush()?;
 
@@ -1092,6 +2094,9 @@ impl Flasher {
     }
 
     /// Read the flash ROM and write it to a file.
+    ///
+    /// The read can be aborted between blocks with
+    /// [ProgressCallbacks::is_cancelled].
     pub fn read_flash_rom(
         &mut self,
         offset: u32,
@@ -1099,6 +2104,7 @@ impl Flasher {
         block_size: u32,
         max_in_flight: u32,
         file_path: PathBuf,
+        progress: &mut dyn ProgressCallbacks,
     ) -> Result<(), Error> {
         // ROM read limit per command
         const BLOCK_LEN: usize = 64;
@@ -1113,6 +2119,8 @@ impl Flasher {
 
         let mut correct_offset = offset;
 
+        progress.init(offset, (size as usize).div_ceil(BLOCK_LEN));
+
         while data.len() < size as usize {
             let block_len = std::cmp::min(BLOCK_LEN, size as usize - data.len());
 
@@ -1137,8 +2145,12 @@ impl Flasher {
             // command always returns 64 byte buffer,
             // regardless of how many bytes were actually read from flash
             data.append(&mut payload[..block_len].to_vec());
+
+            progress.update(data.len().div_ceil(BLOCK_LEN));
+            self.check_read_cancelled(progress)?;
         }
 
+        progress.finish(false);
         file.write_all(&data)?;
 
         info!(
@@ -1150,6 +2162,9 @@ impl Flasher {
     }
 
     /// Read the flash and write it to a file.
+    ///
+    /// The read can be aborted between blocks with
+    /// [ProgressCallbacks::is_cancelled].
     pub fn read_flash(
         &mut self,
         offset: u32,
@@ -1157,17 +2172,64 @@ impl Flasher {
         block_size: u32,
         max_in_flight: u32,
         file_path: PathBuf,
+        progress: &mut dyn ProgressCallbacks,
     ) -> Result<(), Error> {
-        debug!("Reading 0x{size:x}B from 0x{offset:08x}");
-
-        let mut data = Vec::new();
-
         let mut file = OpenOptions::new()
             .write(true)
             .truncate(true)
             .create(true)
             .open(&file_path)?;
 
+        let data =
+            self.read_flash_data_with_progress(offset, size, block_size, max_in_flight, progress)?;
+        file.write_all(&data)?;
+
+        info!(
+            "Flash content successfully read and written to '{}'!",
+            file_path.display()
+        );
+
+        Ok(())
+    }
+
+    /// Read the flash and return its contents.
+    ///
+    /// Requires the flasher stub.
+    pub fn read_flash_data(
+        &mut self,
+        offset: u32,
+        size: u32,
+        block_size: u32,
+        max_in_flight: u32,
+    ) -> Result<Vec<u8>, Error> {
+        self.read_flash_data_with_progress(
+            offset,
+            size,
+            block_size,
+            max_in_flight,
+            &mut DefaultProgressCallback,
+        )
+    }
+
+    /// Read the flash and return its contents, reporting the progress for
+    /// every block read.
+    ///
+    /// If [ProgressCallbacks::is_cancelled] returns `true` after a block, the
+    /// device is reset and [Error::Cancelled] is returned.
+    ///
+    /// Requires the flasher stub.
+    pub fn read_flash_data_with_progress(
+        &mut self,
+        offset: u32,
+        size: u32,
+        block_size: u32,
+        max_in_flight: u32,
+        progress: &mut dyn ProgressCallbacks,
+    ) -> Result<Vec<u8>, Error> {
+        debug!("Reading 0x{size:x}B from 0x{offset:08x}");
+
+        let mut data = Vec::new();
+
         self.connection
             .with_timeout(CommandType::ReadFlash.timeout(), |connection| {
                 connection.command(Command::ReadFlash {
@@ -1178,6 +2240,9 @@ impl Flasher {
                 })
             })?;
 
+        let blocks = (size as usize).div_ceil(block_size as usize);
+        progress.init(offset, blocks);
+
         while data.len() < size as usize {
             let response = self.connection.read_flash_response()?;
             let chunk: Vec<u8> = if let Some(response) = response {
@@ -1193,6 +2258,9 @@ impl Flasher {
             }
 
             self.connection.write_raw(data.len() as u32)?;
+
+            progress.update(data.len().div_ceil(block_size as usize).min(blocks));
+            self.check_read_cancelled(progress)?;
         }
 
         if data.len() > size as usize {
@@ -1218,12 +2286,21 @@ impl Flasher {
             return Err(Error::DigestMismatch(digest, checksum_md5.to_vec()));
         }
 
-        file.write_all(&data)?;
+        progress.finish(false);
 
-        info!(
-            "Flash content successfully read and written to '{}'!",
-            file_path.display()
-        );
+        Ok(data)
+    }
+
+    /// Abort a read if the progress callbacks ask for it
+    ///
+    /// The device keeps sending the data which was requested, so it is reset
+    /// to leave it in a known state.
+    fn check_read_cancelled(&mut self, progress: &mut dyn ProgressCallbacks) -> Result<(), Error> {
+        if progress.is_cancelled() {
+            info!("Reading cancelled, resetting the device");
+            self.connection.reset()?;
+            return Err(Error::Cancelled);
+        }
 
         Ok(())
     }
@@ -1280,3 +2357,214 @@ impl From<Flasher> for Port {
         connection.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flash_data_builder_validates_options() {
+        let builder = FlashDataBuilder::new(Chip::Esp32c3, XtalFrequency::_40Mhz);
+
+        let flash_data = builder
+            .clone()
+            .flash_size(FlashSize::_4Mb)
+            .flash_base(0x10000)
+            .min_chip_rev(3)
+            .build()
+            .unwrap();
+        assert_eq!(flash_data.flash_base, 0x10000);
+        assert_eq!(flash_data.flash_settings.size, Some(FlashSize::_4Mb));
+        assert_eq!(flash_data.min_chip_rev, 3);
+
+        assert!(builder.clone().flash_base(0x1234).build().is_err());
+        // Without a known flash size, any sector aligned base is accepted
+        assert!(builder.clone().flash_base(0x400000).build().is_ok());
+        assert!(
+            builder
+                .clone()
+                .flash_size(FlashSize::_1Mb)
+                .flash_base(0x100000)
+                .build()
+                .is_err()
+        );
+        assert!(builder.clone().mmu_page_size(0x8000).build().is_err());
+    }
+
+    #[test]
+    fn flash_data_builder_validates_bootloader_offset() {
+        let builder = FlashDataBuilder::new(Chip::Esp32, XtalFrequency::_40Mhz);
+
+        // The ESP32 bootloader is written at 0x1000
+        assert!(
+            builder
+                .clone()
+                .partition_table_offset(0x1000)
+                .build()
+                .is_err()
+        );
+        assert!(
+            builder
+                .clone()
+                .partition_table_offset(0x8800)
+                .build()
+                .is_err()
+        );
+
+        // The bundled bootloader is 0x66c0 bytes long, ending at 0x76c0
+        let builder =
+            builder.bootloader(PathBuf::from("resources/bootloaders/esp32-bootloader.bin"));
+        let flash_data = builder.clone().build().unwrap();
+        assert_eq!(
+            flash_data.bootloader.as_deref(),
+            Some(std::path::Path::new(
+                "resources/bootloaders/esp32-bootloader.bin"
+            ))
+        );
+        assert!(
+            builder
+                .clone()
+                .partition_table_offset(0x8000)
+                .build()
+                .is_ok()
+        );
+        assert!(
+            builder
+                .clone()
+                .partition_table_offset(0x7000)
+                .build()
+                .is_err()
+        );
+        // The offset of a custom table is only known once the table is parsed
+        assert!(
+            builder
+                .clone()
+                .partition_table(PathBuf::from("tests/data/partitions.csv"))
+                .build()
+                .is_ok()
+        );
+
+        assert!(
+            builder
+                .bootloader(PathBuf::from("resources/bootloaders/missing.bin"))
+                .build()
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "serialport")]
+    #[test]
+    fn baud_backoff_steps_down_on_clustered_errors() {
+        let mut backoff = BaudBackoff::default();
+
+        // Isolated errors are retried at the same baud rate
+        assert_eq!(backoff.error(921_600), Some(921_600));
+        backoff.success();
+        assert_eq!(backoff.error(921_600), Some(921_600));
+        assert_eq!(backoff.error(921_600), Some(921_600));
+        backoff.success();
+
+        // A cluster of errors steps down, all the way to 115200
+        for (current, next) in [
+            (1_500_000, 921_600),
+            (921_600, 460_800),
+            (460_800, 230_400),
+            (230_400, 115_200),
+        ] {
+            for _ in 1..BAUD_BACKOFF_ERROR_THRESHOLD {
+                assert_eq!(backoff.error(current), Some(current));
+            }
+            assert_eq!(backoff.error(current), Some(next));
+        }
+
+        // Writing at 115200 is retried as well, before giving up
+        for _ in 1..BAUD_BACKOFF_ERROR_THRESHOLD {
+            assert_eq!(backoff.error(115_200), Some(115_200));
+        }
+        assert_eq!(backoff.error(115_200), None);
+    }
+
+    #[cfg(all(unix, feature = "serialport"))]
+    #[test]
+    fn read_flash_cancelled() {
+        use std::{
+            io::Write,
+            sync::{
+                Arc,
+                atomic::{AtomicUsize, Ordering},
+            },
+        };
+
+        use serialport::UsbPortInfo;
+
+        use crate::connection::{CustomReset, ResetAfterOperation};
+
+        /// Cancels once the given number of blocks has been read
+        struct CancelAfter(usize, usize);
+
+        impl ProgressCallbacks for CancelAfter {
+            fn init(&mut self, _addr: u32, _total: usize) {}
+            fn update(&mut self, current: usize) {
+                self.1 = current;
+            }
+            fn verifying(&mut self) {}
+            fn finish(&mut self, _skipped: bool) {}
+            fn is_cancelled(&mut self) -> bool {
+                self.1 >= self.0
+            }
+        }
+
+        let (serial, mut device) = Port::pair().unwrap();
+        let port_info = UsbPortInfo {
+            vid: 0,
+            pid: 0,
+            serial_number: None,
+            manufacturer: None,
+            product: None,
+        };
+        let mut connection = Connection::new(
+            serial,
+            port_info,
+            ResetAfterOperation::HardReset,
+            ResetBeforeOperation::NoResetNoSync,
+            115_200,
+        );
+        let resets = Arc::new(AtomicUsize::new(0));
+        let r = resets.clone();
+        connection.set_reset_strategy(CustomReset::new(|_: &mut Port| Ok(())).with_hard_reset(
+            move |_| {
+                r.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            },
+        ));
+
+        // The stub acknowledges the read, then sends the first of two blocks
+        let mut frames = vec![0xc0, 0x01, 0xd2, 0, 0, 0, 0, 0, 0, 0, 0, 0xc0];
+        frames.push(0xc0);
+        frames.extend([0x55; 0x100]);
+        frames.push(0xc0);
+        device.write_all(&frames).unwrap();
+
+        let mut flasher = Flasher {
+            connection,
+            chip: Chip::Esp32c3,
+            flash_size: FlashSize::_4Mb,
+            spi_params: SpiAttachParams::default(),
+            use_stub: true,
+            verify: false,
+            verify_app_only: false,
+            verify_bootloader: false,
+            compress_bootloader: true,
+            skip: false,
+            flash_write_size: FLASH_WRITE_SIZE,
+            auto_baud_backoff: false,
+            reset_after_flash: false,
+            redundant_write: false,
+        };
+
+        let result =
+            flasher.read_flash_data_with_progress(0, 0x200, 0x100, 64, &mut CancelAfter(1, 0));
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert_eq!(resets.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,137 @@
+//! Decoding of the SPI flash status registers
+//!
+//! The meaning of the status register bits differs between flash vendors.
+//! Most chips follow the two-register layout introduced by Winbond, while
+//! Macronix and ISSI chips use a single status register with a different
+//! layout, along with a configuration register. The layout is selected from
+//! the JEDEC manufacturer ID, and unknown chips are only reported as raw
+//! values.
+
+/// Layout of the flash status registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatusLayout {
+    /// Two status registers, with BP0-BP2, TB, SEC and SRP0 in the first one
+    /// and SRP1, QE and CMP in the second one
+    ///
+    /// Used by Winbond, GigaDevice, XMC, Boya, Puya and Zbit chips.
+    Winbond,
+    /// A single status register with BP0-BP3, QE and SRWD, and a
+    /// configuration register with TB
+    ///
+    /// Used by Macronix and ISSI chips.
+    Macronix,
+}
+
+impl StatusLayout {
+    /// Selects the layout for a JEDEC manufacturer ID
+    pub fn from_manufacturer(manufacturer: u8) -> Option<Self> {
+        match manufacturer {
+            0xEF | 0xC8 | 0x20 | 0x68 | 0x85 | 0x5E => Some(Self::Winbond),
+            0xC2 | 0x9D => Some(Self::Macronix),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded flash status registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FlashStatus {
+    /// Raw value, with the first status register in the low byte and the
+    /// second one, or the configuration register, in the high byte
+    pub raw: u16,
+    /// Layout used to decode the registers, `None` for unknown chips
+    pub layout: Option<StatusLayout>,
+}
+
+impl FlashStatus {
+    /// Decodes the status registers of a chip with the given JEDEC
+    /// manufacturer ID
+    pub fn new(raw: u16, manufacturer: u8) -> Self {
+        Self {
+            raw,
+            layout: StatusLayout::from_manufacturer(manufacturer),
+        }
+    }
+
+    /// Block protection bits, BP0 in the lowest bit
+    pub fn block_protect(&self) -> Option<u8> {
+        match self.layout? {
+            StatusLayout::Winbond => Some((self.raw >> 2) as u8 & 0x7),
+            StatusLayout::Macronix => Some((self.raw >> 2) as u8 & 0xF),
+        }
+    }
+
+    /// Whether the protected blocks are counted from the bottom of the flash
+    pub fn top_bottom(&self) -> Option<bool> {
+        match self.layout? {
+            StatusLayout::Winbond => self.bit(StatusLayout::Winbond, 5),
+            StatusLayout::Macronix => self.bit(StatusLayout::Macronix, 11),
+        }
+    }
+
+    /// Whether the block protection is inverted
+    pub fn complement(&self) -> Option<bool> {
+        self.bit(StatusLayout::Winbond, 14)
+    }
+
+    /// Whether quad mode is enabled
+    ///
+    /// In quad mode the /WP and /HOLD pins are used as data lines, so the
+    /// hardware write protection is disabled.
+    pub fn quad_enable(&self) -> Option<bool> {
+        match self.layout? {
+            StatusLayout::Winbond => self.bit(StatusLayout::Winbond, 9),
+            StatusLayout::Macronix => self.bit(StatusLayout::Macronix, 6),
+        }
+    }
+
+    /// Whether the status register is protected by the /WP pin
+    ///
+    /// While set, the status register cannot be written as long as /WP is
+    /// held low, unless quad mode is enabled.
+    pub fn status_register_protect(&self) -> Option<bool> {
+        match self.layout? {
+            StatusLayout::Winbond => self.bit(StatusLayout::Winbond, 7),
+            StatusLayout::Macronix => self.bit(StatusLayout::Macronix, 7),
+        }
+    }
+
+    /// Whether any of the block protection bits are set
+    pub fn is_protected(&self) -> Option<bool> {
+        Some(self.block_protect()? != 0 || self.complement().unwrap_or(false))
+    }
+
+    fn bit(&self, layout: StatusLayout, bit: u16) -> Option<bool> {
+        (self.layout? == layout).then_some(self.raw & (1 << bit) != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_common_layouts() {
+        // Winbond, BP1 and SRP0 set, quad mode enabled
+        let status = FlashStatus::new(0x0288, 0xEF);
+        assert_eq!(status.block_protect(), Some(0b010));
+        assert_eq!(status.status_register_protect(), Some(true));
+        assert_eq!(status.quad_enable(), Some(true));
+        assert_eq!(status.complement(), Some(false));
+        assert_eq!(status.is_protected(), Some(true));
+
+        // Macronix, QE set, TB set in the configuration register
+        let status = FlashStatus::new(0x0840, 0xC2);
+        assert_eq!(status.block_protect(), Some(0));
+        assert_eq!(status.quad_enable(), Some(true));
+        assert_eq!(status.top_bottom(), Some(true));
+        assert_eq!(status.complement(), None);
+        assert_eq!(status.is_protected(), Some(false));
+
+        let status = FlashStatus::new(0x001C, 0x01);
+        assert_eq!(status.layout, None);
+        assert_eq!(status.block_protect(), None);
+    }
+}
@@ -7,11 +7,12 @@ use std::{
     fs,
     io::Write,
     iter::once,
-    mem::size_of,
+    mem::{offset_of, size_of},
+    ops::Range,
     path::Path,
 };
 
-use bytemuck::{Pod, Zeroable, bytes_of, from_bytes, pod_read_unaligned};
+use bytemuck::{Pod, Zeroable, pod_read_unaligned};
 use esp_idf_part::{AppType, DataType, Flags, Partition, PartitionTable, SubType, Type};
 use log::warn;
 use miette::{IntoDiagnostic, Result};
@@ -26,23 +27,39 @@ use object::{
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use super::{Segment, ram_segments, rom_segments};
+use super::{
+    Segment,
+    flat_binary,
+    partitions::{IDF_TABLE_LEN, PartitionTableFormat},
+    ram_segments,
+    rom_segments,
+    segments,
+};
 use crate::{
     Error,
     error::AppDescriptorError,
-    flasher::{FlashData, FlashFrequency, FlashMode, FlashSize},
+    flasher::{FLASH_SECTOR_SIZE, FlashData, FlashFrequency, FlashMode, FlashSize},
     target::{Chip, XtalFrequency},
 };
 
 const ESP_CHECKSUM_MAGIC: u8 = 0xEF;
-const ESP_MAGIC: u8 = 0xE9;
+pub(crate) const ESP_MAGIC: u8 = 0xE9;
 const IROM_ALIGN: u32 = 0x10000;
 const SEG_HEADER_LEN: u32 = 8;
 const WP_PIN_DISABLED: u8 = 0xEE;
 
+/// Maximum number of segments the bootloader accepts in an image, see
+/// `ESP_IMAGE_MAX_SEGMENTS` in ESP-IDF
+const MAX_SEGMENTS: usize = 16;
+
 /// Max partition size is 16 MB
 const MAX_PARTITION_SIZE: u32 = 16 * 1000 * 1024;
 
+/// Subtype of the `ota_0` app partition, the other OTA slots follow it
+pub(crate) const OTA_SUBTYPE_MIN: u8 = 0x10;
+/// Subtype of the `ota_15` app partition, the last OTA slot
+pub(crate) const OTA_SUBTYPE_MAX: u8 = 0x1f;
+
 const BOOTLOADER_ESP32_26MHZ: &[u8] =
     include_bytes!("../../resources/bootloaders/esp32_26-bootloader.bin");
 const BOOTLOADER_ESP32_40MHZ: &[u8] =
@@ -69,10 +86,10 @@ const BOOTLOADER_ESP32S3: &[u8] =
     include_bytes!("../../resources/bootloaders/esp32s3-bootloader.bin");
 
 /// Get the default bootloader for the given chip and crystal frequency
-pub(crate) fn default_bootloader(
+fn bundled_bootloaders(
     chip: Chip,
     xtal_freq: XtalFrequency,
-) -> Result<&'static [u8], Error> {
+) -> Result<&'static [&'static [u8]], Error> {
     let error = Error::UnsupportedFeature {
         chip,
         feature: "the selected crystal frequency".into(),
@@ -80,46 +97,117 @@ pub(crate) fn default_bootloader(
 
     match chip {
         Chip::Esp32 => match xtal_freq {
-            XtalFrequency::_26Mhz => Ok(BOOTLOADER_ESP32_26MHZ),
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32_40MHZ),
+            XtalFrequency::_26Mhz => Ok(&[BOOTLOADER_ESP32_26MHZ]),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32_40MHZ]),
             _ => Err(error),
         },
         Chip::Esp32c2 => match xtal_freq {
-            XtalFrequency::_26Mhz => Ok(BOOTLOADER_ESP32C2_26MHZ),
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32C2_40MHZ),
+            XtalFrequency::_26Mhz => Ok(&[BOOTLOADER_ESP32C2_26MHZ]),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32C2_40MHZ]),
             _ => Err(error),
         },
         Chip::Esp32c3 => match xtal_freq {
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32C3),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32C3]),
             _ => Err(error),
         },
         Chip::Esp32c5 => match xtal_freq {
-            XtalFrequency::_40Mhz | XtalFrequency::_48Mhz => Ok(BOOTLOADER_ESP32C5),
+            XtalFrequency::_40Mhz | XtalFrequency::_48Mhz => Ok(&[BOOTLOADER_ESP32C5]),
             _ => Err(error),
         },
         Chip::Esp32c6 => match xtal_freq {
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32C6),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32C6]),
             _ => Err(error),
         },
         Chip::Esp32h2 => match xtal_freq {
-            XtalFrequency::_32Mhz => Ok(BOOTLOADER_ESP32H2),
+            XtalFrequency::_32Mhz => Ok(&[BOOTLOADER_ESP32H2]),
             _ => Err(error),
         },
         Chip::Esp32p4 => match xtal_freq {
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32P4),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32P4]),
             _ => Err(error),
         },
         Chip::Esp32s2 => match xtal_freq {
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32S2),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32S2]),
             _ => Err(error),
         },
         Chip::Esp32s3 => match xtal_freq {
-            XtalFrequency::_40Mhz => Ok(BOOTLOADER_ESP32S3),
+            XtalFrequency::_40Mhz => Ok(&[BOOTLOADER_ESP32S3]),
             _ => Err(error),
         },
     }
 }
 
+pub(crate) fn default_bootloader(
+    chip: Chip,
+    xtal_freq: XtalFrequency,
+) -> Result<&'static [u8], Error> {
+    Ok(bundled_bootloaders(chip, xtal_freq)?[0])
+}
+
+/// Select the bundled bootloader supporting the given chip revision, in format
+/// `major * 100 + minor`
+///
+/// The range of revisions supported by each bootloader is read from its image
+/// header. If none of the bundled bootloaders supports the revision, a warning
+/// is logged and the default bootloader is used.
+pub(crate) fn bootloader_for_revision(
+    chip: Chip,
+    xtal_freq: XtalFrequency,
+    revision: u16,
+) -> Result<&'static [u8], Error> {
+    let bootloaders = bundled_bootloaders(chip, xtal_freq)?;
+    if let Some(bootloader) = bootloaders
+        .iter()
+        .find(|bootloader| bootloader_supports_revision(bootloader, chip, revision))
+    {
+        return Ok(bootloader);
+    }
+
+    warn!(
+        "None of the bundled bootloaders for the {chip} supports chip revision v{}.{}, the device may fail to boot",
+        revision / 100,
+        revision % 100
+    );
+
+    Ok(bootloaders[0])
+}
+
+/// Whether a bootloader image was built for the given chip and supports its
+/// revision, in format `major * 100 + minor`, according to its image header
+pub(crate) fn bootloader_supports_revision(bootloader: &[u8], chip: Chip, revision: u16) -> bool {
+    ImageHeader::from_bytes(bootloader).is_some_and(|header| {
+        header.chip_id == chip.id()
+            && (header.min_chip_rev_full..=header.max_chip_rev_full).contains(&revision)
+    })
+}
+
+/// Get the ESP-IDF version the default bootloader for the given chip and
+/// crystal frequency was built from, if it can be determined
+pub fn default_bootloader_version(chip: Chip, xtal_freq: XtalFrequency) -> Option<String> {
+    bootloader_version(default_bootloader(chip, xtal_freq).ok()?)
+}
+
+/// Read the ESP-IDF version from the bootloader descriptor
+/// (`esp_bootloader_desc_t`), which is placed at the start of the first
+/// segment of the bootloader image.
+fn bootloader_version(bootloader: &[u8]) -> Option<String> {
+    const DESC_OFFSET: usize = size_of::<ImageHeader>() + SEG_HEADER_LEN as usize;
+    const DESC_MAGIC_BYTE: u8 = 0x50;
+
+    let desc = bootloader.get(DESC_OFFSET..DESC_OFFSET + 40)?;
+    if desc[0] != DESC_MAGIC_BYTE {
+        return None;
+    }
+
+    let idf_ver = &desc[8..];
+    let len = idf_ver
+        .iter()
+        .position(|&b| b == 0)
+        .unwrap_or(idf_ver.len());
+
+    String::from_utf8(idf_ver[..len].to_vec()).ok()
+}
+
 /// Firmware header used by the ESP-IDF bootloader.
 ///
 /// ## Header documentation:
@@ -178,6 +266,43 @@ impl Default for ImageHeader {
 }
 
 impl ImageHeader {
+    /// Size of the header in bytes
+    const LEN: usize = size_of::<Self>();
+
+    /// Parses a header from the start of `bytes`
+    ///
+    /// Multi-byte fields are stored little-endian regardless of the host.
+    /// Returns `None` if there are not enough bytes, or if `append_digest` is
+    /// neither 0 nor 1, which no known header version produces.
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut header: Self = pod_read_unaligned(bytes.get(..Self::LEN)?);
+        header.entry = u32::from_le(header.entry);
+        header.chip_id = u16::from_le(header.chip_id);
+        header.min_chip_rev_full = u16::from_le(header.min_chip_rev_full);
+        header.max_chip_rev_full = u16::from_le(header.max_chip_rev_full);
+
+        if header.append_digest > 1 {
+            return None;
+        }
+        if header.reserved != [0; 4] {
+            log::debug!("Image header has non-zero reserved bytes: {:02x?}", {
+                header.reserved
+            });
+        }
+
+        Some(header)
+    }
+
+    /// Serializes the header, with multi-byte fields in little-endian order
+    fn to_bytes(mut self) -> [u8; Self::LEN] {
+        self.entry = self.entry.to_le();
+        self.chip_id = self.chip_id.to_le();
+        self.min_chip_rev_full = self.min_chip_rev_full.to_le();
+        self.max_chip_rev_full = self.max_chip_rev_full.to_le();
+
+        bytemuck::cast(self)
+    }
+
     /// Updates flash size and baud filed.
     pub fn write_flash_config(
         &mut self,
@@ -201,6 +326,30 @@ struct SegmentHeader {
     length: u32,
 }
 
+impl SegmentHeader {
+    /// Size of the header in bytes
+    const LEN: usize = size_of::<Self>();
+
+    /// Parses a segment header from the start of `bytes`, reading its fields
+    /// as little-endian
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let header: Self = pod_read_unaligned(bytes.get(..Self::LEN)?);
+
+        Some(Self {
+            addr: u32::from_le(header.addr),
+            length: u32::from_le(header.length),
+        })
+    }
+
+    /// Serializes the segment header, with its fields in little-endian order
+    fn to_bytes(self) -> [u8; Self::LEN] {
+        bytemuck::cast(Self {
+            addr: self.addr.to_le(),
+            length: self.length.to_le(),
+        })
+    }
+}
+
 /// Application descriptor used by the ESP-IDF bootloader.
 ///
 /// [Documentation](https://docs.espressif.com/projects/esp-idf/en/stable/esp32/api-reference/system/app_image_format.html#application-description)
@@ -239,6 +388,377 @@ struct AppDescriptor {
 
 impl AppDescriptor {
     const ESP_APP_DESC_MAGIC_WORD: u32 = 0xABCD5432;
+
+    /// Size of the descriptor in bytes
+    const LEN: usize = size_of::<Self>();
+
+    /// Parses an application descriptor from the start of `bytes`
+    ///
+    /// Multi-byte fields are stored little-endian regardless of the host.
+    /// Returns `None` if there are not enough bytes.
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut descriptor: Self = pod_read_unaligned(bytes.get(..Self::LEN)?);
+        descriptor.magic_word = u32::from_le(descriptor.magic_word);
+        descriptor.secure_version = u32::from_le(descriptor.secure_version);
+        descriptor.reserv1 = { descriptor.reserv1 }.map(u32::from_le);
+        descriptor.min_efuse_blk_rev_full = u16::from_le(descriptor.min_efuse_blk_rev_full);
+        descriptor.max_efuse_blk_rev_full = u16::from_le(descriptor.max_efuse_blk_rev_full);
+        descriptor.reserv2 = { descriptor.reserv2 }.map(u32::from_le);
+
+        Some(descriptor)
+    }
+}
+
+/// Information about an application, read from its application descriptor
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AppInfo {
+    /// Project name
+    pub project_name: String,
+    /// Application version
+    pub version: String,
+    /// ESP-IDF version the application was built with
+    pub idf_version: String,
+    /// Compile date
+    pub date: String,
+    /// Compile time
+    pub time: String,
+    /// Secure version
+    pub secure_version: u32,
+}
+
+impl AppInfo {
+    /// Number of bytes at the start of an application image needed to read
+    /// its application descriptor
+    pub const IMAGE_PREFIX_SIZE: usize = ImageHeader::LEN + SegmentHeader::LEN + AppDescriptor::LEN;
+
+    /// Read the application info from the start of an ESP-IDF application
+    /// image, e.g. as read back from an app partition.
+    ///
+    /// The application descriptor is placed at the start of the first
+    /// segment. Returns `None` if the data does not start with an image
+    /// containing one.
+    pub fn from_image(image: &[u8]) -> Option<Self> {
+        if image.first() != Some(&ESP_MAGIC) {
+            return None;
+        }
+
+        let offset = ImageHeader::LEN + SegmentHeader::LEN;
+        let descriptor = AppDescriptor::from_bytes(image.get(offset..)?)?;
+        if descriptor.magic_word != AppDescriptor::ESP_APP_DESC_MAGIC_WORD {
+            return None;
+        }
+
+        Some(Self {
+            project_name: c_string(&{ descriptor.project_name }),
+            version: c_string(&{ descriptor.version }),
+            idf_version: c_string(&{ descriptor.idf_ver }),
+            date: c_string(&{ descriptor.date }),
+            time: c_string(&{ descriptor.time }),
+            secure_version: descriptor.secure_version,
+        })
+    }
+}
+
+/// The fields of the header of an ESP-IDF image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ImageHeaderInfo {
+    /// Magic byte, 0xE9 for valid images
+    pub magic: u8,
+    /// Number of segments in the image
+    pub segment_count: u8,
+    /// Encoded flash mode
+    pub flash_mode: u8,
+    /// Encoded flash size in the high nibble and frequency in the low nibble
+    pub flash_config: u8,
+    /// Entry point address
+    pub entry: u32,
+    /// GPIO used as the flash's /WP pin, 0xEE if disabled
+    pub wp_pin: u8,
+    /// ID of the chip the image was built for
+    pub chip_id: u16,
+    /// Minimum chip revision, in format `major * 100 + minor`
+    pub min_chip_rev_full: u16,
+    /// Maximum chip revision, in format `major * 100 + minor`
+    pub max_chip_rev_full: u16,
+    /// Whether a SHA256 digest is appended to the image
+    pub append_digest: bool,
+}
+
+impl ImageHeaderInfo {
+    /// Size of the header in bytes
+    pub const SIZE: usize = size_of::<ImageHeader>();
+
+    /// Read the header from the start of an ESP-IDF image
+    ///
+    /// Returns `None` if the data does not start with a valid image header.
+    pub fn from_image(image: &[u8]) -> Option<Self> {
+        let header = ImageHeader::from_bytes(image)?;
+        if header.magic != ESP_MAGIC {
+            return None;
+        }
+
+        Some(Self {
+            magic: header.magic,
+            segment_count: header.segment_count,
+            flash_mode: header.flash_mode,
+            flash_config: header.flash_config,
+            entry: header.entry,
+            wp_pin: header.wp_pin,
+            chip_id: header.chip_id,
+            min_chip_rev_full: header.min_chip_rev_full,
+            max_chip_rev_full: header.max_chip_rev_full,
+            append_digest: header.append_digest == 1,
+        })
+    }
+
+    /// Decoded flash mode, if valid
+    pub fn flash_mode(&self) -> Option<FlashMode> {
+        match self.flash_mode {
+            0 => Some(FlashMode::Qio),
+            1 => Some(FlashMode::Qout),
+            2 => Some(FlashMode::Dio),
+            3 => Some(FlashMode::Dout),
+            _ => None,
+        }
+    }
+
+    /// Decoded flash size, if valid
+    pub fn flash_size(&self) -> Option<FlashSize> {
+        FlashSize::decode_flash_size(self.flash_config >> 4).ok()
+    }
+
+    /// Decoded flash frequency, if valid for the given chip
+    pub fn flash_freq(&self, chip: Chip) -> Option<FlashFrequency> {
+        chip.flash_frequency_encodings()
+            .into_iter()
+            .find(|&(_, encoded)| encoded == self.flash_config & 0xF)
+            .map(|(freq, _)| freq)
+    }
+}
+
+/// Split an ESP-IDF image into its segments, each at its load address
+///
+/// Returns `None` if the data is not a valid image.
+pub fn image_segments(image: &[u8]) -> Option<Vec<Segment<'_>>> {
+    let header = ImageHeader::from_bytes(image)?;
+    if header.magic != ESP_MAGIC {
+        return None;
+    }
+
+    let mut offset = size_of::<ImageHeader>();
+    let mut segments = Vec::with_capacity(header.segment_count as usize);
+    for _ in 0..header.segment_count {
+        let segment = SegmentHeader::from_bytes(image.get(offset..)?)?;
+        offset += size_of::<SegmentHeader>();
+
+        let data = image.get(offset..offset + segment.length as usize)?;
+        segments.push(Segment::new(segment.addr, data));
+        offset += data.len();
+    }
+
+    Some(segments)
+}
+
+/// The app partition an application is written to when none is selected
+///
+/// The default partition table contains the "factory" partition, and if a user
+/// provides a partition table via command-line then the validation step
+/// confirms that at least one "app" partition is present. We prefer the
+/// "factory" partition, and use any available "app" partitions if not present.
+fn default_app_partition(partition_table: &PartitionTable) -> Result<&Partition, Error> {
+    partition_table
+        .find("factory")
+        .or_else(|| partition_table.find_by_type(Type::App))
+        .ok_or(Error::AppPartitionNotFound)
+}
+
+/// MMU page sizes supported by the chip, the application's flash segments
+/// are aligned to one of these
+pub(crate) fn valid_mmu_page_sizes(chip: Chip) -> &'static [u32] {
+    chip.valid_mmu_page_sizes().unwrap_or(&[IROM_ALIGN])
+}
+
+/// The partition table offset used when none is specified
+///
+/// We need to assume that the partition table is in the sector right before
+/// the first partition, since this is the most common case. An empty table is
+/// placed at the ESP-IDF default of 0x8000. A partition at offset 0 leaves no
+/// room for the table, which is then placed at 0 and rejected as overlapping
+/// the bootloader.
+fn default_partition_table_offset(partition_table: &PartitionTable) -> u32 {
+    partition_table
+        .partitions()
+        .iter()
+        .map(Partition::offset)
+        .min()
+        .unwrap_or(0x9000)
+        .saturating_sub(FLASH_SECTOR_SIZE as u32)
+}
+
+/// Add the flash base to an offset, failing if the address space overflows
+fn relocate(flash_base: u32, offset: u32) -> Result<u32, Error> {
+    flash_base
+        .checked_add(offset)
+        .ok_or(Error::FlashBaseOverflow { flash_base, offset })
+}
+
+/// Location and size of a region in flash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LayoutRegion {
+    /// Flash address of the start of the region
+    pub offset: u32,
+    /// Size of the region in bytes
+    pub size: u32,
+}
+
+/// Where the regions of an ESP-IDF image are written in flash, see
+/// [compute_flash_layout]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct FlashLayout {
+    /// The space available to the bootloader, up to the partition table
+    pub bootloader: LayoutRegion,
+    /// The partition table, which is at most 0xC00 bytes
+    pub partition_table: LayoutRegion,
+    /// Label of the app partition the application is written to
+    pub app_label: String,
+    /// The app partition the application is written to
+    pub app: LayoutRegion,
+}
+
+/// Compute where the bootloader, partition table and application are written
+/// for a chip and partition table, without needing an application ELF
+///
+/// The application is written to the "factory" partition, or the first app
+/// partition if there is none, like [IdfBootloaderFormat::new] does when no
+/// partition is selected. If `partition_table_offset` is `None`, the table is
+/// assumed to be in the sector right before the first partition.
+pub fn compute_flash_layout(
+    chip: Chip,
+    partition_table: &PartitionTable,
+    partition_table_offset: Option<u32>,
+) -> Result<FlashLayout, Error> {
+    let app = default_app_partition(partition_table)?;
+    let offset =
+        partition_table_offset.unwrap_or_else(|| default_partition_table_offset(partition_table));
+
+    let invalid = |reason: String| Error::InvalidPartitionTableOffset { offset, reason };
+    let boot_address = chip.boot_address();
+    if offset % FLASH_SECTOR_SIZE as u32 != 0 {
+        return Err(invalid("it is not aligned to a flash sector".into()));
+    }
+    if offset <= boot_address {
+        return Err(invalid(format!(
+            "it is not past the bootloader at {boot_address:#x}"
+        )));
+    }
+    let end = offset
+        .checked_add(FLASH_SECTOR_SIZE as u32)
+        .ok_or_else(|| invalid("it is past the end of the flash address space".into()))?;
+    if let Some(partition) = partition_table
+        .partitions()
+        .iter()
+        .find(|p| p.offset() < end)
+    {
+        return Err(invalid(format!(
+            "partition '{}' at {:#x} starts before the end of the table",
+            partition.name(),
+            partition.offset()
+        )));
+    }
+
+    Ok(FlashLayout {
+        bootloader: LayoutRegion {
+            offset: boot_address,
+            size: offset - boot_address,
+        },
+        partition_table: LayoutRegion {
+            offset,
+            size: IDF_TABLE_LEN as u32,
+        },
+        app_label: app.name(),
+        app: LayoutRegion {
+            offset: app.offset(),
+            size: app.size(),
+        },
+    })
+}
+
+/// Location of the SHA256 digest appended to an ESP-IDF image
+///
+/// Returns `None` if the image has no appended digest, or is not a valid
+/// image.
+fn appended_digest_range(image: &[u8]) -> Option<Range<usize>> {
+    let header = ImageHeader::from_bytes(image)?;
+    if header.magic != ESP_MAGIC || header.append_digest != 1 {
+        return None;
+    }
+
+    let mut offset = size_of::<ImageHeader>();
+    for _ in 0..header.segment_count {
+        let segment = SegmentHeader::from_bytes(image.get(offset..)?)?;
+        offset += size_of::<SegmentHeader>() + segment.length as usize;
+    }
+
+    // The segments are followed by a 1-byte checksum, padded to a 16-byte
+    // boundary, and then the digest
+    let start = (offset + 1).next_multiple_of(16);
+    let range = start..start + 32;
+    image.get(range.clone())?;
+
+    Some(range)
+}
+
+/// Check the SHA256 digest appended to an ESP-IDF image
+///
+/// Returns `None` if the image has no appended digest, and whether the digest
+/// matches the contents of the image otherwise.
+pub fn verify_appended_digest(image: &[u8]) -> Option<bool> {
+    let range = appended_digest_range(image)?;
+
+    Some(Sha256::digest(&image[..range.start]).as_slice() == &image[range])
+}
+
+/// Write the given flash settings into the header of an ESP-IDF image, and
+/// update its appended SHA256 digest, if any, to match
+pub fn patch_flash_settings(
+    image: &mut [u8],
+    mode: Option<FlashMode>,
+    size: FlashSize,
+    freq: FlashFrequency,
+    chip: Chip,
+) -> Result<(), Error> {
+    let header_len = size_of::<ImageHeader>();
+    if image_segments(image).is_none() {
+        return Err(Error::InvalidAppImage);
+    }
+
+    let mut header = ImageHeader::from_bytes(image).ok_or(Error::InvalidAppImage)?;
+    if let Some(mode) = mode {
+        header.flash_mode = mode as u8;
+    }
+    header.write_flash_config(size, freq, chip)?;
+    image[..header_len].copy_from_slice(&header.to_bytes());
+
+    if let Some(range) = appended_digest_range(image) {
+        let hash = Sha256::digest(&image[..range.start]);
+        image[range].copy_from_slice(&hash);
+    }
+
+    Ok(())
+}
+
+/// Convert a NUL-terminated C string field into a [String]
+fn c_string(chars: &[c_char]) -> String {
+    let bytes = chars
+        .iter()
+        .map(|&c| c as u8)
+        .take_while(|&b| b != 0)
+        .collect::<Vec<_>>();
+
+    String::from_utf8_lossy(&bytes).into_owned()
 }
 
 /// Image format for ESP32 family chips using the second-stage bootloader from
@@ -252,24 +772,38 @@ pub struct IdfBootloaderFormat<'a> {
     app_size: u32,
     partition_table_size: u32,
     partition_table_offset: u32,
+    flash_base: u32,
+    flash_size: FlashSize,
+    extra_segments: Vec<Segment<'a>>,
 }
 
 impl<'a> IdfBootloaderFormat<'a> {
     /// Create a new [`IdfBootloaderFormat`].
+    ///
+    /// The partition table, its offset and the bootloader fall back to the ones
+    /// set in the [`FlashData`] when not given.
     pub fn new(
         elf_data: &'a [u8],
         flash_data: &FlashData,
         partition_table_path: Option<&Path>,
+        partition_table_format: Option<PartitionTableFormat>,
         bootloader_path: Option<&Path>,
         partition_table_offset: Option<u32>,
         target_app_partition: Option<&str>,
     ) -> Result<Self, Error> {
         let elf = ElfFile::parse(elf_data)?;
 
+        let partition_table_path = partition_table_path.or(flash_data.partition_table.as_deref());
+        let bootloader_path = bootloader_path.or(flash_data.bootloader.as_deref());
+        let partition_table_offset = partition_table_offset.or(flash_data.partition_table_offset);
+
         let partition_table = if let Some(partition_table_path) = partition_table_path {
             let data = fs::read(partition_table_path)
                 .map_err(|e| Error::FileOpenError(partition_table_path.display().to_string(), e))?;
-            PartitionTable::try_from(data)?
+            match partition_table_format {
+                Some(format) => format.parse(data)?,
+                None => PartitionTable::try_from(data)?,
+            }
         } else {
             default_partition_table(
                 flash_data.chip,
@@ -293,7 +827,12 @@ impl<'a> IdfBootloaderFormat<'a> {
             let bootloader = fs::read(bootloader_path)?;
             Cow::Owned(bootloader)
         } else {
-            let default_bootloader = default_bootloader(flash_data.chip, flash_data.xtal_freq)?;
+            let default_bootloader = match flash_data.chip_revision {
+                Some(revision) => {
+                    bootloader_for_revision(flash_data.chip, flash_data.xtal_freq, revision)?
+                }
+                None => default_bootloader(flash_data.chip, flash_data.xtal_freq)?,
+            };
             Cow::Borrowed(default_bootloader)
         };
 
@@ -301,16 +840,28 @@ impl<'a> IdfBootloaderFormat<'a> {
         let mut calc_bootloader_size = 0;
         let bootloader_header_size = size_of::<ImageHeader>();
         calc_bootloader_size += bootloader_header_size;
-        let mut header: ImageHeader = *from_bytes(&bootloader[0..bootloader_header_size]);
+        let mut header = ImageHeader::from_bytes(&bootloader).ok_or(Error::InvalidBootloader)?;
         if header.magic != ESP_MAGIC {
             return Err(Error::InvalidBootloader);
         }
 
+        // A bootloader built for another chip expects to be written at that chip's
+        // boot address, and will not boot on this one regardless.
+        let chip_id = header.chip_id;
+        if chip_id != flash_data.chip.id() {
+            return Err(Error::BootloaderChipMismatch {
+                bootloader: Chip::try_from(chip_id)
+                    .map(|chip| chip.to_string())
+                    .unwrap_or_else(|_| format!("an unknown chip (ID {chip_id})")),
+                chip: flash_data.chip,
+            });
+        }
+
         for _ in 0..header.segment_count {
-            let segment: SegmentHeader = *from_bytes(
-                &bootloader
-                    [calc_bootloader_size..calc_bootloader_size + size_of::<SegmentHeader>()],
-            );
+            let segment = bootloader
+                .get(calc_bootloader_size..)
+                .and_then(SegmentHeader::from_bytes)
+                .ok_or(Error::InvalidBootloader)?;
             calc_bootloader_size += segment.length as usize + size_of::<SegmentHeader>();
         }
 
@@ -328,42 +879,45 @@ impl<'a> IdfBootloaderFormat<'a> {
             flash_data.chip,
         )?;
 
-        bootloader.to_mut().splice(
-            0..size_of::<ImageHeader>(),
-            bytes_of(&header).iter().copied(),
-        );
+        bootloader
+            .to_mut()
+            .splice(0..size_of::<ImageHeader>(), header.to_bytes());
 
         // The header was modified so we need to recalculate the hash of the
-        // bootloader. The hash is at the end of the bootloader segments and
-        // 1-byte checksum at the end of a 16-byte padded boundary.
+        // bootloader, if it has one. The hash is at the end of the bootloader
+        // segments and 1-byte checksum at the end of a 16-byte padded boundary.
         //
         // Source: Point 3 of <https://docs.espressif.com/projects/esp-idf/en/v5.4/esp32c3/api-reference/system/app_image_format.html>
-        calc_bootloader_size += 1; // add checksum size
-        calc_bootloader_size = calc_bootloader_size + ((16 - (calc_bootloader_size % 16)) % 16);
-        let bootloader_sha_start = calc_bootloader_size;
-        calc_bootloader_size += 32; // add sha256 size
-        let bootloader_sha_end = calc_bootloader_size;
-
-        let mut hasher = Sha256::new();
-        hasher.update(&bootloader[..bootloader_sha_start]);
-        let hash = hasher.finalize();
-        log::debug!(
-            "Updating bootloader SHA256 from {} to {}",
-            encode_hex(&bootloader[bootloader_sha_start..bootloader_sha_end]),
-            encode_hex(hash)
-        );
-        bootloader.to_mut()[bootloader_sha_start..bootloader_sha_end].copy_from_slice(&hash);
+        match appended_digest_range(&bootloader) {
+            Some(range) => {
+                let hash = Sha256::digest(&bootloader[..range.start]);
+                log::debug!(
+                    "Updating bootloader SHA256 from {} to {}",
+                    encode_hex(&bootloader[range.clone()]),
+                    encode_hex(hash)
+                );
+                bootloader.to_mut()[range].copy_from_slice(&hash);
+            }
+            None if header.append_digest == 1 => return Err(Error::InvalidBootloader),
+            None => log::debug!("Bootloader has no appended SHA256"),
+        }
 
         // write the header of the app
         // use the same settings as the bootloader
         // just update the entry point
-        header.entry = elf.elf_header().e_entry.get(Endianness::Little);
+        header.entry = match flash_data.entry {
+            Some(entry) => {
+                check_entry_point(flash_data.chip, &elf, entry);
+                entry
+            }
+            None => elf.elf_header().e_entry.get(Endianness::Little),
+        };
         header.wp_pin = WP_PIN_DISABLED;
         header.chip_id = flash_data.chip.id();
         header.min_chip_rev_full = flash_data.min_chip_rev;
         header.append_digest = 1;
 
-        let mut data = bytes_of(&header).to_vec();
+        let mut data = header.to_bytes().to_vec();
 
         // The bootloader needs segments to be 4-byte aligned, but ensuring that
         // alignment by padding segments might result in overlapping segments. We
@@ -397,10 +951,7 @@ impl<'a> IdfBootloaderFormat<'a> {
             None
         };
 
-        let valid_page_sizes = flash_data
-            .chip
-            .valid_mmu_page_sizes()
-            .unwrap_or(&[IROM_ALIGN]);
+        let valid_page_sizes = valid_mmu_page_sizes(flash_data.chip);
         let valid_page_sizes_string = valid_page_sizes
             .iter()
             .map(|size| format!("{size:#x}"))
@@ -410,11 +961,11 @@ impl<'a> IdfBootloaderFormat<'a> {
             let segment = &flash_segments[0];
 
             let offset = (address - segment.addr) as usize;
-            let app_descriptor_size = size_of::<AppDescriptor>();
-
-            let segment_data = segment.data();
-            let app_descriptor_bytes = &segment_data[offset..][..app_descriptor_size];
-            let app_descriptor: AppDescriptor = pod_read_unaligned(app_descriptor_bytes);
+            let app_descriptor = segment
+                .data()
+                .get(offset..)
+                .and_then(AppDescriptor::from_bytes)
+                .ok_or(Error::InvalidAppImage)?;
 
             if app_descriptor.magic_word != AppDescriptor::ESP_APP_DESC_MAGIC_WORD {
                 return Err(
@@ -454,6 +1005,16 @@ impl<'a> IdfBootloaderFormat<'a> {
             None
         };
 
+        // The compile time and date differ between builds of the same sources, clear
+        // them to make the image reproducible.
+        if let Some(address) = app_desc_addr.filter(|_| flash_data.reproducible) {
+            let segment = &mut flash_segments[0];
+            let offset = (address - segment.addr) as usize;
+            let app_descriptor = &mut segment.data.to_mut()[offset..][..size_of::<AppDescriptor>()];
+            app_descriptor[offset_of!(AppDescriptor, time)..offset_of!(AppDescriptor, idf_ver)]
+                .fill(0);
+        }
+
         // Precedence is:
         // - user input (unimplemented)
         // - app descriptor
@@ -493,7 +1054,7 @@ impl<'a> IdfBootloaderFormat<'a> {
                         addr: 0,
                         length: pad_len,
                     };
-                    data.write_all(bytes_of(&pad_header))?;
+                    data.write_all(&pad_header.to_bytes())?;
 
                     for _ in 0..pad_len {
                         data.write_all(&[0])?;
@@ -521,7 +1082,7 @@ impl<'a> IdfBootloaderFormat<'a> {
         data.write_all(&[checksum])?;
 
         // since we added some dummy segments, we need to patch the segment count
-        data[1] = segment_count as u8;
+        data[1] = segment_count_field(segment_count)?;
 
         let mut hasher = Sha256::new();
         hasher.update(&data);
@@ -536,15 +1097,7 @@ impl<'a> IdfBootloaderFormat<'a> {
                 .ok_or(Error::AppPartitionNotFound)?
                 .clone()
         } else {
-            // The default partition table contains the "factory" partition, and if a user
-            // provides a partition table via command-line then the validation step confirms
-            // that at least one "app" partition is present. We prefer the "factory"
-            // partition, and use any available "app" partitions if not present.
-            partition_table
-                .find("factory")
-                .or_else(|| partition_table.find_by_type(Type::App))
-                .ok_or(Error::AppPartitionNotFound)?
-                .clone()
+            default_app_partition(&partition_table)?.clone()
         };
 
         let app_size = data.len() as u32;
@@ -552,28 +1105,25 @@ impl<'a> IdfBootloaderFormat<'a> {
 
         // The size of the application must not exceed the size of the target app
         // partition.
-        if app_size as f32 / partition_table_size as f32 > 1.0 {
-            return Err(Error::ElfTooBig(app_size, partition_table_size));
+        if app_size > partition_table_size {
+            return Err(Error::AppTooBigForPartition {
+                label: target_app_partition.name(),
+                app_size,
+                part_size: partition_table_size,
+                overflow: app_size - partition_table_size,
+            });
         }
 
         let flash_segment = Segment {
-            addr: target_app_partition.offset(),
+            addr: relocate(flash_data.flash_base, target_app_partition.offset())?,
             data: Cow::Owned(data),
         };
 
-        // If the user did not specify a partition offset, we need to assume that the
-        // partition offset is (first partition offset) - 0x1000, since this is
-        // the most common case.
-        let partition_table_offset = partition_table_offset.unwrap_or_else(|| {
-            let partitions = partition_table.partitions();
-            let first_partition = partitions
-                .iter()
-                .min_by(|a, b| a.offset().cmp(&b.offset()))
-                .unwrap();
-            first_partition.offset() - 0x1000
-        });
+        let partition_table_offset = partition_table_offset
+            .unwrap_or_else(|| default_partition_table_offset(&partition_table));
+        let partition_table_offset = relocate(flash_data.flash_base, partition_table_offset)?;
 
-        let boot_addr = flash_data.chip.boot_address();
+        let boot_addr = relocate(flash_data.flash_base, flash_data.chip.boot_address())?;
 
         Ok(Self {
             boot_addr,
@@ -583,9 +1133,207 @@ impl<'a> IdfBootloaderFormat<'a> {
             app_size,
             partition_table_size,
             partition_table_offset,
+            flash_base: flash_data.flash_base,
+            flash_size: flash_data.flash_settings.size.unwrap_or_default(),
+            extra_segments: Vec::new(),
         })
     }
 
+    /// Write the application to the given OTA slot, and select it in the
+    /// `otadata` partition so that it is booted.
+    ///
+    /// The application is written to the `ota_<slot>` app partition of the
+    /// partition table, and the `otadata` partition is overwritten.
+    pub fn with_ota_slot(mut self, slot: u8) -> Result<Self, Error> {
+        if slot > OTA_SUBTYPE_MAX - OTA_SUBTYPE_MIN {
+            return Err(Error::OtaSlotNotFound(slot));
+        }
+
+        let app_partition = self
+            .partition_table
+            .partitions()
+            .iter()
+            .find(
+                |p| matches!(p.subtype(), SubType::App(app) if app as u8 == OTA_SUBTYPE_MIN + slot),
+            )
+            .ok_or(Error::OtaSlotNotFound(slot))?
+            .clone();
+        let otadata_partition = self
+            .partition_table
+            .partitions()
+            .iter()
+            .find(|p| p.ty() == Type::Data && p.subtype() == SubType::Data(DataType::Ota))
+            .ok_or(Error::OtaDataPartitionNotFound)?
+            .clone();
+
+        if self.app_size > app_partition.size() {
+            return Err(Error::AppTooBigForPartition {
+                label: app_partition.name(),
+                app_size: self.app_size,
+                part_size: app_partition.size(),
+                overflow: self.app_size - app_partition.size(),
+            });
+        }
+
+        self.flash_segment.addr = self.relocate(app_partition.offset())?;
+        self.partition_table_size = app_partition.size();
+
+        self.extra_segments.push(Segment {
+            addr: self.relocate(otadata_partition.offset())?,
+            data: Cow::Owned(ota_select_data(slot, otadata_partition.size())),
+        });
+
+        Ok(self)
+    }
+
+    /// Add the data described by the given ELF section to the flashed image.
+    ///
+    /// The section consists of a sequence of records, each made up of a
+    /// little-endian `u32` flash offset, a little-endian `u32` payload length
+    /// and the payload itself, padded with zeroes to a multiple of 4 bytes.
+    /// Offsets are relocated by the flash base like the partitions are, and
+    /// the resulting addresses must be sector aligned. Payloads are padded to
+    /// whole flash sectors, which must not overlap each other or the
+    /// bootloader, partition table and application.
+    pub fn with_extra_from_section(
+        mut self,
+        elf_data: &'a [u8],
+        section: &str,
+    ) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidExtraSection {
+            section: section.to_string(),
+            reason,
+        };
+
+        let elf = ElfFile::parse(elf_data)?;
+        let data = elf
+            .section_by_name(section)
+            .ok_or_else(|| invalid("the section does not exist".into()))?
+            .data()?;
+
+        let mut offset = 0;
+        while offset < data.len() {
+            let header = data
+                .get(offset..offset + 8)
+                .ok_or_else(|| invalid(format!("truncated record header at {offset:#x}")))?;
+            let addr = u32::from_le_bytes(header[..4].try_into().unwrap());
+            let len = u32::from_le_bytes(header[4..].try_into().unwrap()) as usize;
+
+            let payload = data
+                .get(offset + 8..)
+                .and_then(|rest| rest.get(..len))
+                .ok_or_else(|| {
+                    invalid(format!("payload of the record at {offset:#x} is truncated"))
+                })?;
+            if len == 0 {
+                return Err(invalid(format!("record at {offset:#x} is empty")));
+            }
+            let addr = self.relocate(addr)?;
+            if addr % FLASH_SECTOR_SIZE as u32 != 0 {
+                return Err(invalid(format!(
+                    "address {addr:#x} of the record at {offset:#x} is not aligned to a {FLASH_SECTOR_SIZE:#x} byte flash sector"
+                )));
+            }
+
+            // Writing erases whole sectors, so pad the payload to cover them, like
+            // `with_extra_elf` does
+            let mut padded = payload.to_vec();
+            padded.resize(padded.len().next_multiple_of(FLASH_SECTOR_SIZE), 0xff);
+            self.extra_segments.push(Segment {
+                addr,
+                data: Cow::Owned(padded),
+            });
+            offset += 8 + len.next_multiple_of(4);
+        }
+
+        self.check_overlaps().map_err(invalid)?;
+
+        Ok(self)
+    }
+
+    /// Add the loadable sections of a second ELF file, e.g. the firmware of a
+    /// coprocessor, to the flashed image at the given flash offset.
+    ///
+    /// The sections are flattened into a single binary, like `objcopy -O
+    /// binary` would, and padded to whole flash sectors. The offset is
+    /// relocated by the flash base like the partitions are, the resulting
+    /// address must be sector aligned, and the padded binary must not overlap
+    /// the bootloader, partition table, application or any other extra data.
+    pub fn with_extra_elf(mut self, elf_data: &[u8], addr: u32) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidExtraElf { addr, reason };
+        let addr = self.relocate(addr)?;
+
+        let elf = ElfFile::parse(elf_data)?;
+        let image =
+            flat_binary(&elf).ok_or_else(|| invalid("it has no loadable sections".into()))?;
+        if image.size() > MAX_PARTITION_SIZE {
+            return Err(invalid(format!(
+                "its sections span {:#x} bytes, more than fits in flash",
+                image.size()
+            )));
+        }
+        if addr % FLASH_SECTOR_SIZE as u32 != 0 {
+            return Err(invalid(format!(
+                "the address is not aligned to a {FLASH_SECTOR_SIZE:#x} byte flash sector"
+            )));
+        }
+
+        // Writing erases whole sectors, so pad the image to cover them, both to
+        // keep the erased state of the padding and to detect overlaps with
+        // anything else flashed in those sectors
+        let mut data = image.data.into_owned();
+        data.resize(data.len().next_multiple_of(FLASH_SECTOR_SIZE), 0xff);
+
+        self.extra_segments.push(Segment {
+            addr,
+            data: Cow::Owned(data),
+        });
+        self.check_overlaps().map_err(invalid)?;
+
+        Ok(self)
+    }
+
+    /// Check that none of the flashed regions overlap, returning a description
+    /// of the first overlap found otherwise.
+    fn check_overlaps(&self) -> Result<(), String> {
+        let partition_table_len = self.partition_table.to_bin().map_or(0, |bin| bin.len());
+        let mut regions = vec![
+            (
+                self.boot_addr as u64,
+                self.bootloader.len(),
+                "the bootloader".to_string(),
+            ),
+            (
+                self.partition_table_offset as u64,
+                partition_table_len,
+                "the partition table".to_string(),
+            ),
+            (
+                self.flash_segment.addr as u64,
+                self.flash_segment.data.len(),
+                "the application".to_string(),
+            ),
+        ];
+        regions.extend(self.extra_segments.iter().map(|segment| {
+            (
+                segment.addr as u64,
+                segment.data.len(),
+                format!("the data at {:#x}", segment.addr),
+            )
+        }));
+        regions.sort_by_key(|(start, ..)| *start);
+
+        for pair in regions.windows(2) {
+            let (start, len, name) = &pair[0];
+            let (next_start, _, next_name) = &pair[1];
+            if start + *len as u64 > *next_start {
+                return Err(format!("{name} overlaps {next_name}"));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns an iterator over the [Segment]'s that should be placed in flash.
     pub fn flash_segments<'b>(self) -> impl Iterator<Item = Segment<'b>>
     where
@@ -609,6 +1357,7 @@ impl<'a> IdfBootloaderFormat<'a> {
         once(bootloader_segment)
             .chain(once(partition_table_segment))
             .chain(once(app_segment))
+            .chain(self.extra_segments)
     }
 
     /// Returns an iterator over the OTA segment.
@@ -631,6 +1380,92 @@ impl<'a> IdfBootloaderFormat<'a> {
     pub fn partition_table(&self) -> PartitionTable {
         self.partition_table.clone()
     }
+
+    /// Returns the flash address the bootloader is written to.
+    pub fn boot_address(&self) -> u32 {
+        self.boot_addr
+    }
+
+    /// Returns the flash address the partition table is written to.
+    pub fn partition_table_offset(&self) -> u32 {
+        self.partition_table_offset
+    }
+
+    /// Returns the base address added to the offsets of all partitions.
+    pub fn flash_base(&self) -> u32 {
+        self.flash_base
+    }
+
+    /// Returns the flash address of the given partition offset, relocated by
+    /// the flash base.
+    pub fn relocate(&self, offset: u32) -> Result<u32, Error> {
+        relocate(self.flash_base, offset)
+    }
+
+    /// Returns the flash address the application is written to.
+    pub fn app_offset(&self) -> u32 {
+        self.flash_segment.addr
+    }
+
+    /// Returns the application segment, borrowing its data.
+    pub fn app_segment(&self) -> Segment<'_> {
+        self.flash_segment.borrow()
+    }
+
+    /// Returns the size of the flash the image was built for.
+    pub fn flash_size(&self) -> FlashSize {
+        self.flash_size
+    }
+
+    /// Check that the SHA256 digests appended to the bootloader and the
+    /// application match their contents, after any header patching.
+    pub fn verify_digests(&self) -> Result<(), Error> {
+        for (image, data) in [
+            ("bootloader", &self.bootloader[..]),
+            ("application", &self.flash_segment.data[..]),
+        ] {
+            match verify_appended_digest(data) {
+                Some(true) => log::debug!("The SHA256 digest of the {image} is valid"),
+                Some(false) => return Err(Error::AppendedDigestMismatch(image.to_string())),
+                None => warn!("The {image} has no appended SHA256 digest"),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert the number of segments written to an image into the value of the
+/// header's `segment_count` field
+fn segment_count_field(count: usize) -> Result<u8, Error> {
+    if count > MAX_SEGMENTS {
+        return Err(Error::TooManySegments {
+            count,
+            max: MAX_SEGMENTS,
+        });
+    }
+
+    Ok(count as u8)
+}
+
+/// Warn if an overridden entry point does not lie within one of the loaded
+/// segments of the ELF file.
+fn check_entry_point(chip: Chip, elf: &ElfFile<'_>, entry: u32) {
+    if segments(elf).any(|segment| {
+        segment.addr <= entry
+            && segment
+                .addr
+                .checked_add(segment.size())
+                .is_none_or(|end| entry < end)
+    }) {
+        return;
+    }
+
+    if chip.addr_is_flash(entry) || chip.addr_is_ram(entry) {
+        warn!("Entry point {entry:#010x} does not lie within any loaded segment");
+    } else {
+        warn!("Entry point {entry:#010x} lies outside of the flash and RAM regions of the {chip}");
+    }
 }
 
 /// Generates a default partition table.
@@ -772,7 +1607,7 @@ fn save_segment(data: &mut Vec<u8>, segment: &Segment<'_>, checksum: u8) -> Resu
         length: segment.size() + padding,
     };
 
-    data.write_all(bytes_of(&header))?;
+    data.write_all(&header.to_bytes())?;
     data.write_all(segment.data())?;
 
     let padding = &[0u8; 4][0..padding as usize];
@@ -790,7 +1625,81 @@ fn update_checksum(data: &[u8], mut checksum: u8) -> u8 {
     checksum
 }
 
-fn encode_hex<T>(data: T) -> String
+/// Build the contents of an `otadata` partition which selects the given OTA
+/// slot
+///
+/// The first sector holds an `esp_ota_select_entry_t` with the sequence number
+/// `slot + 1`, the second sector is left erased.
+fn ota_select_data(slot: u8, size: u32) -> Vec<u8> {
+    let ota_seq = slot as u32 + 1;
+
+    let mut data = vec![0xFF; size as usize];
+    data[0..4].copy_from_slice(&ota_seq.to_le_bytes());
+    // `seq_label` (20 bytes) and `ota_state` (4 bytes) are left undefined
+    data[28..32].copy_from_slice(&ota_select_crc(ota_seq).to_le_bytes());
+
+    data
+}
+
+/// Find the OTA slot selected by the contents of an `otadata` partition
+///
+/// Both sectors of the partition hold an `esp_ota_select_entry_t`, and the
+/// valid entry with the highest sequence number selects the slot. Returns
+/// `None` if neither entry is valid, in which case the bootloader boots the
+/// factory application.
+pub fn selected_ota_slot(otadata: &[u8], ota_app_count: u8) -> Option<u8> {
+    const ESP_OTA_IMG_INVALID: u32 = 3;
+    const ESP_OTA_IMG_ABORTED: u32 = 4;
+    const SECTOR_SIZE: usize = 0x1000;
+
+    if ota_app_count == 0 {
+        return None;
+    }
+
+    let read_u32 = |entry: &[u8], offset: usize| {
+        u32::from_le_bytes(entry[offset..offset + 4].try_into().unwrap())
+    };
+
+    otadata
+        .chunks(SECTOR_SIZE)
+        .take(2)
+        .filter(|entry| entry.len() >= 32)
+        .filter_map(|entry| {
+            let seq = read_u32(entry, 0);
+            let state = read_u32(entry, 24);
+            let crc = read_u32(entry, 28);
+
+            // Sequence numbers start at 1, a zero one would select no slot
+            (seq != 0
+                && seq != u32::MAX
+                && crc == ota_select_crc(seq)
+                && state != ESP_OTA_IMG_INVALID
+                && state != ESP_OTA_IMG_ABORTED)
+                .then_some(seq)
+        })
+        .max()
+        .map(|seq| ((seq - 1) % ota_app_count as u32) as u8)
+}
+
+/// CRC of an `esp_ota_select_entry_t`, which only covers the sequence number,
+/// see `bootloader_common_ota_select_crc` in ESP-IDF
+fn ota_select_crc(ota_seq: u32) -> u32 {
+    let mut crc = 0u32;
+    for byte in ota_seq.to_le_bytes() {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+pub(crate) fn encode_hex<T>(data: T) -> String
 where
     T: AsRef<[u8]>,
 {
@@ -840,7 +1749,80 @@ pub fn check_idf_bootloader(elf_data: &Vec<u8>) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use bytemuck::bytes_of;
+    use strum::IntoEnumIterator;
+
     use super::*;
+    use crate::flasher::FlashSettings;
+
+    #[test]
+    fn test_compute_flash_layout() {
+        let table = default_partition_table(Chip::Esp32c3, None);
+
+        let layout = compute_flash_layout(Chip::Esp32c3, &table, None).unwrap();
+        assert_eq!(
+            layout.bootloader,
+            LayoutRegion {
+                offset: 0,
+                size: 0x8000
+            }
+        );
+        assert_eq!(layout.partition_table.offset, 0x8000);
+        assert_eq!(layout.app_label, "factory");
+        assert_eq!(layout.app.offset, 0x10000);
+
+        // The ESP32 bootloader is at 0x1000 instead
+        let layout = compute_flash_layout(Chip::Esp32, &table, Some(0x8000)).unwrap();
+        assert_eq!(
+            layout.bootloader,
+            LayoutRegion {
+                offset: 0x1000,
+                size: 0x7000
+            }
+        );
+
+        assert!(compute_flash_layout(Chip::Esp32c3, &table, Some(0x9000)).is_err());
+        assert!(compute_flash_layout(Chip::Esp32, &table, Some(0x1000)).is_err());
+        assert!(compute_flash_layout(Chip::Esp32c3, &table, Some(0x8800)).is_err());
+        assert!(compute_flash_layout(Chip::Esp32c3, &table, Some(0xFFFF_F000)).is_err());
+
+        // No room for the table before a partition at offset 0
+        let table = PartitionTable::try_from_str("factory, app, factory, 0x0, 1M\n").unwrap();
+        assert!(matches!(
+            compute_flash_layout(Chip::Esp32c3, &table, None),
+            Err(Error::InvalidPartitionTableOffset { offset: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_header_byte_order() {
+        #[rustfmt::skip]
+        let bytes = [
+            0xE9, 0x03, 0x02, 0x20, // magic, segment count, mode, config
+            0x78, 0x56, 0x34, 0x12, // entry
+            0xEE, 0x00, 0x00, 0x00, // wp pin, drive settings
+            0x05, 0x00, 0x00, 0x03, 0x01, 0xFF, 0xFF, // chip id, revisions
+            0x00, 0x00, 0x00, 0x00, 0x01, // reserved, append digest
+        ];
+
+        let header = ImageHeader::from_bytes(&bytes).unwrap();
+        assert_eq!({ header.entry }, 0x1234_5678);
+        assert_eq!({ header.chip_id }, Chip::Esp32c3.id());
+        assert_eq!({ header.min_chip_rev_full }, 0x0103);
+        assert_eq!({ header.max_chip_rev_full }, u16::MAX);
+        assert_eq!(header.to_bytes(), bytes);
+
+        let segment = SegmentHeader::from_bytes(&[0x00, 0x00, 0x38, 0x40, 0x10, 0, 0, 0]).unwrap();
+        assert_eq!({ segment.addr }, 0x4038_0000);
+        assert_eq!({ segment.length }, 0x10);
+        assert_eq!(segment.to_bytes(), [0x00, 0x00, 0x38, 0x40, 0x10, 0, 0, 0]);
+
+        // Unknown values of append_digest are rejected
+        let mut invalid = bytes;
+        invalid[23] = 2;
+        assert!(ImageHeader::from_bytes(&invalid).is_none());
+        assert!(ImageHeader::from_bytes(&bytes[..20]).is_none());
+    }
 
     #[test]
     fn test_flash_config_write() {
@@ -856,6 +1838,17 @@ mod tests {
         assert_eq!(header.flash_config, 0x5F);
     }
 
+    #[test]
+    fn test_segment_count_field() {
+        assert_eq!(segment_count_field(0).unwrap(), 0);
+        assert_eq!(segment_count_field(MAX_SEGMENTS).unwrap(), 16);
+        assert!(matches!(
+            segment_count_field(MAX_SEGMENTS + 1),
+            Err(Error::TooManySegments { count: 17, max: 16 })
+        ));
+        assert!(segment_count_field(256).is_err());
+    }
+
     #[test]
     fn test_encode_hex() {
         assert_eq!(encode_hex([0u8]), "00");
@@ -865,6 +1858,325 @@ mod tests {
         assert_eq!(encode_hex([222u8, 202, 251, 173]), "decafbad");
     }
 
+    #[test]
+    fn test_default_bootloader_version() {
+        for chip in Chip::iter() {
+            let version = default_bootloader_version(chip, chip.default_xtal_frequency());
+            assert!(version.unwrap().starts_with("v5."));
+        }
+
+        assert_eq!(bootloader_version(&[0u8; 0x48]), None);
+    }
+
+    #[test]
+    fn test_default_bootloader_chip_id() {
+        for chip in Chip::iter() {
+            let bootloader = default_bootloader(chip, chip.default_xtal_frequency()).unwrap();
+            let header = ImageHeader::from_bytes(bootloader).unwrap();
+            assert_eq!({ header.chip_id }, chip.id(), "{chip}");
+            assert_eq!(
+                chip.bundled_bootloader(chip.default_xtal_frequency())
+                    .unwrap(),
+                bootloader
+            );
+        }
+    }
+
+    #[test]
+    fn test_bootloader_for_revision() {
+        for chip in Chip::iter() {
+            let xtal_freq = chip.default_xtal_frequency();
+            let bootloader = default_bootloader(chip, xtal_freq).unwrap();
+            let min_rev = ImageHeader::from_bytes(bootloader)
+                .unwrap()
+                .min_chip_rev_full;
+            assert!(
+                bootloader_supports_revision(bootloader, chip, min_rev),
+                "{chip}"
+            );
+
+            let selected = bootloader_for_revision(chip, xtal_freq, min_rev).unwrap();
+            assert_eq!(selected, bootloader, "{chip}");
+        }
+
+        // The bundled ESP32-C3 bootloader supports revisions v0.3 to v1.99
+        let bootloader = default_bootloader(Chip::Esp32c3, XtalFrequency::_40Mhz).unwrap();
+        assert!(!bootloader_supports_revision(bootloader, Chip::Esp32c3, 2));
+        assert!(!bootloader_supports_revision(
+            bootloader,
+            Chip::Esp32c3,
+            200
+        ));
+        assert!(!bootloader_supports_revision(bootloader, Chip::Esp32c6, 3));
+    }
+
+    #[test]
+    fn test_ota_select_data() {
+        let data = ota_select_data(1, 0x2000);
+        assert_eq!(data.len(), 0x2000);
+        assert_eq!(data[0..4], [2, 0, 0, 0]);
+        assert_eq!(data[4..28], [0xFF; 24]);
+        assert_eq!(data[28..32], 0x55f6_3774u32.to_le_bytes());
+        assert!(data[32..].iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn test_selected_ota_slot() {
+        assert_eq!(selected_ota_slot(&[0xFF; 0x2000], 2), None);
+        assert_eq!(selected_ota_slot(&ota_select_data(1, 0x2000), 2), Some(1));
+
+        // The entry with the highest sequence number wins
+        let mut data = ota_select_data(0, 0x2000);
+        data[0x1000..].copy_from_slice(&ota_select_data(1, 0x1000));
+        assert_eq!(selected_ota_slot(&data, 2), Some(1));
+
+        // Entries with a bad CRC are ignored
+        data[0x1000 + 28] ^= 0xFF;
+        assert_eq!(selected_ota_slot(&data, 2), Some(0));
+
+        // ... as are entries with a zero sequence number
+        let mut data = [0xFF; 0x2000];
+        data[0..4].copy_from_slice(&0u32.to_le_bytes());
+        data[28..32].copy_from_slice(&ota_select_crc(0).to_le_bytes());
+        assert_eq!(selected_ota_slot(&data, 2), None);
+    }
+
+    #[test]
+    fn test_app_info_from_image() {
+        // Multi-byte fields are little-endian
+        let mut descriptor = bytes_of(&AppDescriptor::zeroed()).to_vec();
+        descriptor[..4].copy_from_slice(&[0x32, 0x54, 0xCD, 0xAB]);
+        descriptor[4..8].copy_from_slice(&[0x03, 0x00, 0x00, 0x00]);
+        let version = offset_of!(AppDescriptor, version);
+        descriptor[version..][..6].copy_from_slice(b"v1.2.3");
+
+        let mut image = vec![0u8; ImageHeader::LEN + SegmentHeader::LEN];
+        image[0] = ESP_MAGIC;
+        image.extend_from_slice(&descriptor);
+
+        let info = AppInfo::from_image(&image).unwrap();
+        assert_eq!(info.version, "v1.2.3");
+        assert_eq!(info.project_name, "");
+        assert_eq!(info.secure_version, 3);
+
+        image[0] = 0;
+        assert!(AppInfo::from_image(&image).is_none());
+        image[0] = ESP_MAGIC;
+        assert!(AppInfo::from_image(&image[..AppInfo::IMAGE_PREFIX_SIZE - 1]).is_none());
+    }
+
+    #[test]
+    fn test_image_segments() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let flash_data = FlashData::new(
+            FlashSettings::default(),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        let app = image.ota_segments().next().unwrap();
+
+        let header = ImageHeader::from_bytes(&app.data).unwrap();
+        let segments = image_segments(&app.data).unwrap();
+        assert_eq!(segments.len(), header.segment_count as usize);
+
+        // The segments and their headers make up the whole image, up to the
+        // checksum and digest
+        let size = size_of::<ImageHeader>()
+            + segments
+                .iter()
+                .map(|s| size_of::<SegmentHeader>() + s.data.len())
+                .sum::<usize>();
+        assert!(size < app.data.len());
+
+        assert!(image_segments(&app.data[..size - 1]).is_none());
+        assert!(image_segments(&[0u8; 24]).is_none());
+    }
+
+    #[test]
+    fn test_flash_base() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let mut flash_data = FlashData::new(
+            FlashSettings::default(),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        flash_data.flash_base = 0x10_0000;
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        assert_eq!(image.boot_address(), 0x10_0000);
+        assert_eq!(image.partition_table_offset(), 0x10_8000);
+        assert_eq!(image.app_offset(), 0x11_0000);
+        assert_eq!(image.relocate(0x9000).unwrap(), 0x10_9000);
+
+        flash_data.flash_base = 0xFFFF_0000;
+        assert!(matches!(
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None),
+            Err(Error::FlashBaseOverflow {
+                flash_base: 0xFFFF_0000,
+                offset: 0x10000
+            })
+        ));
+    }
+
+    #[test]
+    fn test_ota_slot_out_of_range() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let flash_data = FlashData::new(
+            FlashSettings::default(),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+
+        for slot in [16, u8::MAX] {
+            assert!(matches!(
+                image.clone().with_ota_slot(slot),
+                Err(Error::OtaSlotNotFound(s)) if s == slot
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let contains = |image: &IdfBootloaderFormat<'_>, needle: &[u8]| {
+            image
+                .app_segment()
+                .data()
+                .windows(needle.len())
+                .any(|window| window == needle)
+        };
+
+        let elf = fs::read("tests/data/esp_idf_firmware_c6.elf").unwrap();
+        let mut flash_data = FlashData::new(
+            FlashSettings::default(),
+            0,
+            None,
+            Chip::Esp32c6,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        assert!(contains(&image, b"13:40:19"));
+        assert!(contains(&image, b"2025-04-07"));
+
+        flash_data.reproducible = true;
+        let reproducible =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        assert!(!contains(&reproducible, b"13:40:19"));
+        assert!(!contains(&reproducible, b"2025-04-07"));
+        // Only the compile time and date are cleared
+        assert!(contains(&reproducible, b"idf-embedded-test-demo"));
+        assert_eq!(
+            image.app_segment().size(),
+            reproducible.app_segment().size()
+        );
+    }
+
+    #[test]
+    fn test_verify_digests() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        // Patches the flash size into the bootloader header
+        let flash_data = FlashData::new(
+            FlashSettings::new(None, Some(FlashSize::_16Mb), None),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        assert!(image.verify_digests().is_ok());
+        assert_eq!(verify_appended_digest(&image.bootloader), Some(true));
+
+        let mut app = image.flash_segment.data.to_vec();
+        assert_eq!(verify_appended_digest(&app), Some(true));
+        app[size_of::<ImageHeader>() + size_of::<SegmentHeader>()] ^= 0xff;
+        assert_eq!(verify_appended_digest(&app), Some(false));
+
+        let header_len = size_of::<ImageHeader>();
+        app[header_len - 1] = 0;
+        assert_eq!(verify_appended_digest(&app), None);
+    }
+
+    #[test]
+    fn test_patch_flash_settings() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let flash_data = FlashData::new(
+            FlashSettings::default(),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+        let mut app = image.flash_segment.data.to_vec();
+
+        patch_flash_settings(
+            &mut app,
+            Some(FlashMode::Dio),
+            FlashSize::_16Mb,
+            FlashFrequency::_80Mhz,
+            Chip::Esp32c3,
+        )
+        .unwrap();
+
+        let header = ImageHeader::from_bytes(&app).unwrap();
+        assert_eq!(header.flash_mode, FlashMode::Dio as u8);
+        assert_eq!(
+            header.flash_config >> 4,
+            FlashSize::_16Mb.encode_flash_size().unwrap()
+        );
+        assert_eq!(verify_appended_digest(&app), Some(true));
+
+        assert!(matches!(
+            patch_flash_settings(
+                &mut [0u8; 64],
+                None,
+                FlashSize::_4Mb,
+                FlashFrequency::_40Mhz,
+                Chip::Esp32c3,
+            ),
+            Err(Error::InvalidAppImage)
+        ));
+    }
+
+    #[test]
+    fn test_image_header_info() {
+        let elf = fs::read("tests/data/esp32c3").unwrap();
+        let flash_data = FlashData::new(
+            FlashSettings::new(
+                Some(FlashMode::Qio),
+                Some(FlashSize::_8Mb),
+                Some(FlashFrequency::_40Mhz),
+            ),
+            0,
+            None,
+            Chip::Esp32c3,
+            XtalFrequency::_40Mhz,
+        );
+        let image =
+            IdfBootloaderFormat::new(&elf, &flash_data, None, None, None, None, None).unwrap();
+
+        let info = ImageHeaderInfo::from_image(&image.flash_segment.data).unwrap();
+        assert_eq!(info.flash_mode(), Some(FlashMode::Qio));
+        assert_eq!(info.flash_size(), Some(FlashSize::_8Mb));
+        assert_eq!(info.flash_freq(Chip::Esp32c3), Some(FlashFrequency::_40Mhz));
+        assert_eq!(info.chip_id, Chip::Esp32c3.id());
+        assert!(info.append_digest);
+
+        assert!(ImageHeaderInfo::from_image(&[0; ImageHeaderInfo::SIZE]).is_none());
+    }
+
     #[test]
     fn merge_adjacent_segments_pads() {
         let segments = vec![
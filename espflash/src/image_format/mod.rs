@@ -13,16 +13,17 @@ use object::{
     Endianness,
     Object as _,
     ObjectSection as _,
-    elf::SHT_PROGBITS,
+    elf::{SHF_ALLOC, SHT_PROGBITS},
     read::elf::{ElfFile32 as ElfFile, SectionHeader},
 };
 use serde::{Deserialize, Serialize};
 
 pub use self::metadata::Metadata;
-use crate::{image_format::idf::IdfBootloaderFormat, target::Chip};
+use crate::{Error, flasher::FlashSize, image_format::idf::IdfBootloaderFormat, target::Chip};
 
 pub mod idf;
 mod metadata;
+pub mod partitions;
 
 /// Supported binary application image formats
 #[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
@@ -67,6 +68,48 @@ impl<'a> ImageFormat<'a> {
             ImageFormat::EspIdf(idf) => idf.metadata(),
         }
     }
+
+    /// Returns the flash address the application is written to
+    pub fn app_offset(&self) -> u32 {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.app_offset(),
+        }
+    }
+
+    /// Returns the application segment, borrowing its data
+    pub fn app_segment(&self) -> Segment<'_> {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.app_segment(),
+        }
+    }
+
+    /// Returns the flash address the bootloader is written to
+    pub fn boot_address(&self) -> u32 {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.boot_address(),
+        }
+    }
+
+    /// Returns the flash address the partition table is written to
+    pub fn partition_table_offset(&self) -> u32 {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.partition_table_offset(),
+        }
+    }
+
+    /// Returns the size of the flash the image was built for
+    pub fn flash_size(&self) -> FlashSize {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.flash_size(),
+        }
+    }
+
+    /// Check that any digests embedded in the image match its contents
+    pub fn verify_digests(&self) -> Result<(), Error> {
+        match self {
+            ImageFormat::EspIdf(idf) => idf.verify_digests(),
+        }
+    }
 }
 
 impl<'a> From<IdfBootloaderFormat<'a>> for ImageFormat<'a> {
@@ -202,6 +245,35 @@ impl Ord for Segment<'_> {
     }
 }
 
+/// Returns the address and size of every loadable segment in the ELF file which
+/// does not lie entirely within one of the chip's flash or RAM regions.
+///
+/// Empty segments occupy no memory and are never reported.
+pub fn invalid_segments(chip: Chip, elf_data: &[u8]) -> Result<Vec<(u32, u32)>, Error> {
+    let elf = ElfFile::parse(elf_data)?;
+
+    let invalid = segments(&elf)
+        .filter(|segment| {
+            segment.size() > 0 && !segment_in_memory(chip, segment.addr, segment.size())
+        })
+        .map(|segment| (segment.addr, segment.size()))
+        .collect();
+
+    Ok(invalid)
+}
+
+/// Returns whether the non-empty region lies entirely within one of the chip's
+/// flash or RAM regions.
+fn segment_in_memory(chip: Chip, addr: u32, size: u32) -> bool {
+    // A region extending past the end of the address space fits nowhere
+    let Some(end) = addr.checked_add(size - 1) else {
+        return false;
+    };
+
+    (chip.addr_is_flash(addr) && chip.addr_is_flash(end))
+        || (chip.addr_is_ram(addr) && chip.addr_is_ram(end))
+}
+
 /// Returns an iterator over all RAM segments for a given chip and ELF file.
 pub(crate) fn ram_segments<'a>(
     chip: Chip,
@@ -218,6 +290,12 @@ pub(crate) fn rom_segments<'a>(
     segments(elf).filter(move |segment| chip.addr_is_flash(segment.addr))
 }
 
+/// Returns an iterator over the sections of the ELF file which have to be
+/// loaded onto the device.
+///
+/// Only allocated `PROGBITS` sections have contents in the file; `NOBITS`
+/// sections such as `.bss` are zero-initialized at runtime and must not end up
+/// in the image.
 fn segments<'a>(elf: &'a ElfFile<'a>) -> impl Iterator<Item = Segment<'a>> {
     elf.sections()
         .filter(|section| {
@@ -227,7 +305,7 @@ fn segments<'a>(elf: &'a ElfFile<'a>) -> impl Iterator<Item = Segment<'a>> {
                 && header.sh_type(Endianness::Little) == SHT_PROGBITS
                 && header.sh_offset.get(Endianness::Little) > 0
                 && section.address() > 0
-                && !is_empty(section.flags())
+                && is_alloc(section.flags())
         })
         .flat_map(move |section| match section.data() {
             Ok(data) => Some(Segment::new(section.address() as u32, data)),
@@ -235,19 +313,55 @@ fn segments<'a>(elf: &'a ElfFile<'a>) -> impl Iterator<Item = Segment<'a>> {
         })
 }
 
-fn is_empty(flags: object::SectionFlags) -> bool {
+/// Flattens the loadable sections of an ELF file into a single binary, as
+/// `objcopy -O binary` would, starting at the lowest section address.
+///
+/// Unlike [segments], sections at address 0 are included, as coprocessor
+/// programs are commonly linked there. Returns `None` if the ELF file has no
+/// loadable sections.
+pub(crate) fn flat_binary(elf: &ElfFile<'_>) -> Option<Segment<'static>> {
+    let mut sections = elf
+        .sections()
+        .filter(|section| {
+            let header = section.elf_section_header();
+
+            section.size() > 0
+                && header.sh_type(Endianness::Little) == SHT_PROGBITS
+                && is_alloc(section.flags())
+        })
+        .filter_map(|section| {
+            let data = section.data().ok()?;
+            Some(Segment::new(section.address() as u32, data))
+        })
+        .collect::<Vec<_>>();
+    sections.sort();
+
+    let mut sections = sections.into_iter();
+    let mut image = sections.next()?;
+    for section in sections {
+        image += &section;
+    }
+
+    Some(Segment {
+        addr: image.addr,
+        data: Cow::Owned(image.data.into_owned()),
+    })
+}
+
+fn is_alloc(flags: object::SectionFlags) -> bool {
     match flags {
-        object::SectionFlags::None => true,
-        object::SectionFlags::Elf { sh_flags } => sh_flags == 0,
+        object::SectionFlags::None => false,
+        object::SectionFlags::Elf { sh_flags } => sh_flags & SHF_ALLOC as u64 != 0,
         _ => unreachable!(),
     }
 }
 
 #[cfg(test)]
 mod test {
-    use object::read::elf::ElfFile;
+    use object::{Object as _, ObjectSection as _, read::elf::ElfFile};
 
-    use super::segments;
+    use super::{flat_binary, invalid_segments, segment_in_memory, segments};
+    use crate::target::Chip;
 
     #[test]
     fn test_overlapping_sections_are_removed() {
@@ -279,4 +393,100 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn test_nobits_sections_are_excluded() {
+        let elf_data: Vec<u8> = std::fs::read(
+            "tests/data/esp_hal_binary_with_overlapping_defmt_and_embedded_test_sections",
+        )
+        .unwrap();
+
+        let elf = ElfFile::parse(elf_data.as_slice()).unwrap();
+
+        // The `.stack` section is zero-initialized, and larger than all of the
+        // loadable sections combined.
+        let stack = elf.section_by_name(".stack").unwrap();
+        let stack_range = stack.address()..stack.address() + stack.size();
+
+        let segments = segments(&elf).collect::<Vec<_>>();
+        let image_size = segments.iter().map(|s| s.size() as u64).sum::<u64>();
+        assert!(image_size < stack.size());
+
+        for seg in segments {
+            assert!(
+                !stack_range.contains(&(seg.addr as u64)),
+                "Unexpected section: {:x?}",
+                (seg.addr, seg.size())
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_alloc_sections_are_excluded() {
+        let elf_data: Vec<u8> = std::fs::read("tests/data/esp32c3_noalloc").unwrap();
+
+        let elf = ElfFile::parse(elf_data.as_slice()).unwrap();
+
+        // `.noalloc` has file contents, a load address and non-zero flags, but
+        // lacks `SHF_ALLOC`, so it must not end up in the image.
+        let noalloc = elf.section_by_name(".noalloc").unwrap();
+        assert!(noalloc.address() > 0);
+        let noalloc_range = noalloc.address()..noalloc.address() + noalloc.size();
+
+        let segments = segments(&elf).collect::<Vec<_>>();
+        assert!(!segments.is_empty());
+
+        for seg in segments {
+            assert!(
+                !noalloc_range.contains(&(seg.addr as u64)),
+                "Unexpected section: {:x?}",
+                (seg.addr, seg.size())
+            );
+        }
+    }
+
+    #[test]
+    fn test_flat_binary() {
+        let elf_data: Vec<u8> = std::fs::read(
+            "tests/data/esp_hal_binary_with_overlapping_defmt_and_embedded_test_sections",
+        )
+        .unwrap();
+
+        let elf = ElfFile::parse(elf_data.as_slice()).unwrap();
+        let image = flat_binary(&elf).unwrap();
+
+        // Spans from the start of `.rodata_desc` to the end of `.text`
+        assert_eq!(image.addr, 0x3F400020);
+        assert_eq!(image.size(), 0x400D0020 + 62654 - 0x3F400020);
+        assert_eq!(
+            &image.data()[..256],
+            elf.section_by_name(".rodata_desc").unwrap().data().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_segments() {
+        let elf_data: Vec<u8> = std::fs::read(
+            "tests/data/esp_hal_binary_with_overlapping_defmt_and_embedded_test_sections",
+        )
+        .unwrap();
+
+        assert!(invalid_segments(Chip::Esp32, &elf_data).unwrap().is_empty());
+        assert!(
+            !invalid_segments(Chip::Esp32c3, &elf_data)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_segment_in_memory() {
+        assert!(segment_in_memory(Chip::Esp32, 0x3FFB0000, 3716));
+        assert!(segment_in_memory(Chip::Esp32, 0x400D0020, 1));
+        // Starts in RAM, but runs past its end
+        assert!(!segment_in_memory(Chip::Esp32, 0x3FFB0000, 0x1000_0000));
+        // Wraps around the end of the address space
+        assert!(!segment_in_memory(Chip::Esp32, 0xFFFF_FF00, 0x200));
+        assert!(!segment_in_memory(Chip::Esp32, 0xFFFF_FFFF, u32::MAX));
+    }
 }
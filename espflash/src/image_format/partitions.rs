@@ -0,0 +1,798 @@
+//! Programmatic construction and validation of ESP-IDF partition tables
+//!
+//! The resulting [PartitionTable] can be serialized using
+//! [PartitionTable::to_csv] and [PartitionTable::to_bin].
+
+pub use esp_idf_part::{AppType, DataType, Flags, Partition, PartitionTable, SubType, Type};
+use md5::{Digest, Md5};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::Error;
+
+/// Size reserved for the partition table itself
+const PARTITION_TABLE_SIZE: u32 = 0x1000;
+/// Default offset of the partition table
+pub(crate) const DEFAULT_PARTITION_TABLE_OFFSET: u32 = 0x8000;
+/// Required alignment of app partitions
+const APP_PARTITION_ALIGN: u32 = 0x10000;
+/// Required alignment of all other partitions
+const DATA_PARTITION_ALIGN: u32 = 0x1000;
+/// Maximum length of a partition name
+const MAX_NAME_LEN: usize = 16;
+/// Maximum number of partitions, as the last entry of the table is reserved
+/// for its MD5 checksum
+const MAX_PARTITIONS: usize = 95;
+/// Size of a single entry of a binary partition table
+const ENTRY_SIZE: usize = 32;
+/// Magic bytes starting a partition entry
+const PARTITION_MAGIC: [u8; 2] = [0xaa, 0x50];
+/// Magic bytes starting the MD5 checksum entry
+const MD5_MAGIC: [u8; 2] = [0xeb, 0xeb];
+/// Length of a binary partition table as written by ESP-IDF's
+/// `gen_esp32part.py`
+pub(crate) const IDF_TABLE_LEN: usize = 0xC00;
+
+/// Format of a partition table file
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Deserialize, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum PartitionTableFormat {
+    /// ESP-IDF's CSV format
+    Csv,
+    /// ESP-IDF's binary format
+    Bin,
+}
+
+impl PartitionTableFormat {
+    /// Parse a partition table in this format
+    ///
+    /// Unlike [PartitionTable::try_from], the format is not inferred from the
+    /// data, and data which is clearly in the other format is rejected.
+    pub fn parse(self, data: Vec<u8>) -> Result<PartitionTable, Error> {
+        let is_bin = data.starts_with(&PARTITION_MAGIC) || data.starts_with(&MD5_MAGIC);
+
+        match self {
+            Self::Csv if !is_bin => {
+                let csv = String::from_utf8(data)
+                    .map_err(|_| Error::PartitionTableFormatMismatch(self))?;
+                Ok(PartitionTable::try_from_str(csv)?)
+            }
+            Self::Bin if is_bin => Ok(PartitionTable::try_from_bytes(data)?),
+            _ => Err(Error::PartitionTableFormatMismatch(self)),
+        }
+    }
+}
+
+/// Builder for ESP-IDF partition tables
+///
+/// Partitions are validated as they are added: names must be unique, offsets
+/// must be aligned, and partitions may not overlap with each other or with the
+/// partition table itself.
+#[derive(Debug, Clone)]
+pub struct PartitionTableBuilder {
+    partition_table_offset: u32,
+    partitions: Vec<Partition>,
+    next_offset: u32,
+}
+
+impl Default for PartitionTableBuilder {
+    fn default() -> Self {
+        Self {
+            partition_table_offset: DEFAULT_PARTITION_TABLE_OFFSET,
+            partitions: Vec::new(),
+            next_offset: DEFAULT_PARTITION_TABLE_OFFSET + PARTITION_TABLE_SIZE,
+        }
+    }
+}
+
+impl PartitionTableBuilder {
+    /// Creates a builder for a partition table located at the given offset
+    ///
+    /// The offset must be aligned to a flash sector, and leave room for the
+    /// table before the end of the address space.
+    pub fn new(partition_table_offset: u32) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::InvalidPartitionTableOffset {
+            offset: partition_table_offset,
+            reason: reason.into(),
+        };
+
+        if partition_table_offset % DATA_PARTITION_ALIGN != 0 {
+            return Err(invalid("it is not aligned to a 0x1000 byte flash sector"));
+        }
+        let next_offset = partition_table_offset
+            .checked_add(PARTITION_TABLE_SIZE)
+            .ok_or_else(|| invalid("it is past the end of the flash address space"))?;
+
+        Ok(Self {
+            partition_table_offset,
+            partitions: Vec::new(),
+            next_offset,
+        })
+    }
+
+    /// Adds a partition to the table
+    ///
+    /// If `offset` is `None`, the partition is placed directly after the
+    /// previous one, aligned as required by its type.
+    pub fn add_partition(
+        &mut self,
+        name: impl Into<String>,
+        ty: Type,
+        subtype: SubType,
+        offset: Option<u32>,
+        size: u32,
+        flags: Flags,
+    ) -> Result<&mut Self, Error> {
+        let name = name.into();
+        let invalid = |reason: String| Error::InvalidPartition {
+            name: name.clone(),
+            reason,
+        };
+
+        let align = match ty {
+            Type::App => APP_PARTITION_ALIGN,
+            _ => DATA_PARTITION_ALIGN,
+        };
+        let offset = match offset {
+            Some(offset) => offset,
+            None => self
+                .next_offset
+                .checked_next_multiple_of(align)
+                .ok_or_else(|| {
+                    invalid("partition extends past the end of the address space".into())
+                })?,
+        };
+
+        if name.is_empty() || name.len() > MAX_NAME_LEN {
+            return Err(invalid(format!(
+                "name must be between 1 and {MAX_NAME_LEN} bytes long"
+            )));
+        }
+        if self.partitions.iter().any(|part| part.name() == name) {
+            return Err(invalid("a partition with this name already exists".into()));
+        }
+        if size == 0 {
+            return Err(invalid("size must not be zero".into()));
+        }
+        if offset % align != 0 {
+            return Err(invalid(format!(
+                "offset {offset:#x} is not aligned to {align:#x}"
+            )));
+        }
+
+        let end = offset
+            .checked_add(size)
+            .ok_or_else(|| invalid("partition extends past the end of the address space".into()))?;
+        // Checked when creating the builder
+        let table_end = self.partition_table_offset + PARTITION_TABLE_SIZE;
+        if offset < table_end && self.partition_table_offset < end {
+            return Err(invalid("overlaps with the partition table".into()));
+        }
+        if let Some(other) = self.partitions.iter().find(|part| {
+            part.offset()
+                .checked_add(part.size())
+                .is_none_or(|part_end| offset < part_end)
+                && part.offset() < end
+        }) {
+            return Err(invalid(format!(
+                "overlaps with partition '{}'",
+                other.name()
+            )));
+        }
+
+        self.partitions
+            .push(Partition::new(name, ty, subtype, offset, size, flags));
+        self.next_offset = self.next_offset.max(end);
+
+        Ok(self)
+    }
+
+    /// Builds the partition table
+    pub fn build(&self) -> PartitionTable {
+        PartitionTable::new(self.partitions.clone())
+    }
+}
+
+/// Convert a partition table to the binary form written by ESP-IDF's
+/// `gen_esp32part.py`
+///
+/// The partition entries are followed by the MD5 checksum entry, unless `md5`
+/// is `false`, and the table is padded with 0xFF to 0xC00 bytes, so that the
+/// output is byte-identical to that of the IDF tool.
+pub fn to_idf_bin(table: &PartitionTable, md5: bool) -> Result<Vec<u8>, Error> {
+    let bin = table.to_bin()?;
+    let entries_len = bin
+        .chunks_exact(ENTRY_SIZE)
+        .take_while(|entry| entry[..2] == PARTITION_MAGIC)
+        .count()
+        * ENTRY_SIZE;
+
+    let mut data = bin[..entries_len].to_vec();
+    if md5 {
+        let digest = Md5::digest(&data);
+        data.extend_from_slice(&MD5_MAGIC);
+        data.extend_from_slice(&[0xff; 14]);
+        data.extend_from_slice(&digest);
+    }
+    data.resize(IDF_TABLE_LEN, 0xff);
+
+    Ok(data)
+}
+
+/// Parse a binary partition table read back from a device
+///
+/// Returns `Ok(None)` if the flash is erased where the table was expected,
+/// and an error if a table is present but its MD5 checksum entry does not
+/// match its contents. Tables built without a checksum are accepted as is.
+pub fn parse_device_table(data: &[u8]) -> Result<Option<PartitionTable>, Error> {
+    let corrupt = Error::CorruptPartitionTable;
+
+    if data.iter().take(ENTRY_SIZE).all(|&b| b == 0xff) {
+        return Ok(None);
+    }
+
+    for (index, entry) in data.chunks_exact(ENTRY_SIZE).enumerate() {
+        match [entry[0], entry[1]] {
+            PARTITION_MAGIC => continue,
+            MD5_MAGIC => {
+                let offset = index * ENTRY_SIZE;
+                if Md5::digest(&data[..offset]).as_slice() != &entry[16..] {
+                    return Err(corrupt(
+                        "the MD5 checksum does not match the table contents".into(),
+                    ));
+                }
+                break;
+            }
+            _ if entry.iter().all(|&b| b == 0xff) => break,
+            _ => {
+                return Err(corrupt(format!(
+                    "unexpected data in entry {index} at offset {:#x}",
+                    index * ENTRY_SIZE
+                )));
+            }
+        }
+    }
+
+    PartitionTable::try_from_bytes(data.to_vec())
+        .map(Some)
+        .map_err(|err| corrupt(err.to_string()))
+}
+
+/// Severity of a problem found while validating a partition table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Severity {
+    /// The table is valid, but likely not what was intended
+    Warning,
+    /// The table is invalid
+    Error,
+}
+
+/// A problem found while validating a partition table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ValidationIssue {
+    /// Severity of the problem
+    pub severity: Severity,
+    /// Line of the CSV source the problem was found on, if any
+    pub line: Option<usize>,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn error(line: Option<usize>, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            line,
+            message,
+        }
+    }
+
+    fn warning(line: Option<usize>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            line,
+            message,
+        }
+    }
+}
+
+/// A partition as described by a line of a CSV partition table
+struct CsvPartition<'a> {
+    line: usize,
+    name: &'a str,
+    is_app: bool,
+    subtype: &'a str,
+    offset: u32,
+    end: u32,
+}
+
+/// Validate a partition table in ESP-IDF's CSV format
+///
+/// Unlike parsing the table, this does not stop at the first problem, but
+/// reports all problems found: malformed lines, invalid or duplicate names,
+/// unaligned offsets, overlaps, gaps, missing `app` and `nvs` partitions and
+/// tables with too many entries.
+pub fn validate_csv(csv: &str, partition_table_offset: u32) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut partitions = Vec::<CsvPartition<'_>>::new();
+    let Some(table_end) = partition_table_offset.checked_add(PARTITION_TABLE_SIZE) else {
+        issues.push(ValidationIssue::error(
+            None,
+            format!("the partition table offset {partition_table_offset:#x} is out of range"),
+        ));
+        return issues;
+    };
+    let mut next_offset = table_end;
+
+    for (index, line) in csv.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut error = |message: String| {
+            issues.push(ValidationIssue::error(Some(line_number), message));
+        };
+
+        let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+        if fields.len() < 5 {
+            error(format!(
+                "expected at least 5 fields (name, type, subtype, offset, size), found {}",
+                fields.len()
+            ));
+            continue;
+        }
+        let (name, ty, subtype, offset, size) =
+            (fields[0], fields[1], fields[2], fields[3], fields[4]);
+
+        if name.is_empty() || name.len() > MAX_NAME_LEN {
+            error(format!(
+                "name '{name}' must be between 1 and {MAX_NAME_LEN} bytes long"
+            ));
+        }
+        if let Some(other) = partitions.iter().find(|part| part.name == name) {
+            error(format!(
+                "name '{name}' is already used on line {}",
+                other.line
+            ));
+        }
+
+        let is_app = match ty {
+            "app" => true,
+            "data" => false,
+            _ if parse_int(ty).is_some_and(|ty| (0x40..=0xfe).contains(&ty)) => false,
+            _ => {
+                error(format!(
+                    "unknown type '{ty}', expected 'app', 'data' or a custom type between 0x40 and 0xfe"
+                ));
+                continue;
+            }
+        };
+
+        let align = if is_app {
+            APP_PARTITION_ALIGN
+        } else {
+            DATA_PARTITION_ALIGN
+        };
+        let offset = if offset.is_empty() {
+            match next_offset.checked_next_multiple_of(align) {
+                Some(offset) => offset,
+                None => {
+                    error("partition extends past the end of the address space".into());
+                    continue;
+                }
+            }
+        } else {
+            match parse_int(offset) {
+                Some(offset) => {
+                    if offset % align != 0 {
+                        error(format!(
+                            "offset {offset:#x} is not aligned to {align:#x}{}",
+                            if is_app {
+                                " (app partitions must be 64 KiB aligned)"
+                            } else {
+                                ""
+                            }
+                        ));
+                    }
+                    offset
+                }
+                None => {
+                    error(format!("invalid offset '{offset}'"));
+                    continue;
+                }
+            }
+        };
+        let size = match parse_int(size) {
+            Some(0) => {
+                error("size must not be zero".into());
+                continue;
+            }
+            Some(size) => size,
+            None => {
+                error(format!("invalid size '{size}'"));
+                continue;
+            }
+        };
+
+        let Some(end) = offset.checked_add(size) else {
+            error("partition extends past the end of the address space".into());
+            continue;
+        };
+        if offset < table_end && partition_table_offset < end {
+            error(format!(
+                "overlaps with the partition table at {partition_table_offset:#x}"
+            ));
+        }
+
+        next_offset = end;
+        partitions.push(CsvPartition {
+            line: line_number,
+            name,
+            is_app,
+            subtype,
+            offset,
+            end,
+        });
+    }
+
+    if partitions.len() > MAX_PARTITIONS {
+        issues.push(ValidationIssue::error(
+            None,
+            format!(
+                "the table contains {} partitions, but at most {MAX_PARTITIONS} fit",
+                partitions.len()
+            ),
+        ));
+    }
+
+    // Compare every partition with the one reaching furthest before it, so that
+    // overlaps with partitions which are not directly adjacent are found too.
+    let mut sorted = partitions.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|part| part.offset);
+    let mut furthest: Option<&CsvPartition<'_>> = None;
+    for part in sorted {
+        if let Some(prev) = furthest {
+            let prev_end = prev.end;
+            if prev_end > part.offset {
+                issues.push(ValidationIssue::error(
+                    Some(part.line),
+                    format!(
+                        "partition '{}' overlaps with partition '{}' on line {}",
+                        part.name, prev.name, prev.line
+                    ),
+                ));
+            } else if prev_end < part.offset {
+                issues.push(ValidationIssue::warning(
+                    Some(part.line),
+                    format!(
+                        "gap of {:#x} bytes between partition '{}' and partition '{}'",
+                        part.offset - prev_end,
+                        prev.name,
+                        part.name
+                    ),
+                ));
+            }
+        }
+
+        if furthest.is_none_or(|prev| part.end > prev.end) {
+            furthest = Some(part);
+        }
+    }
+
+    if !partitions.iter().any(|part| part.is_app) {
+        issues.push(ValidationIssue::error(
+            None,
+            "the table does not contain an app partition".into(),
+        ));
+    }
+    if !partitions
+        .iter()
+        .any(|part| !part.is_app && part.subtype == "nvs")
+    {
+        issues.push(ValidationIssue::warning(
+            None,
+            "the table does not contain an nvs partition, which most applications require".into(),
+        ));
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Parse an integer in the formats accepted by ESP-IDF's partition table
+/// generator: decimal, or hexadecimal with a `0x` prefix, optionally followed
+/// by a `K` or `M` multiplier
+fn parse_int(value: &str) -> Option<u32> {
+    let (value, multiplier) = match value.as_bytes().last()? {
+        b'k' | b'K' => (&value[..value.len() - 1], 1024),
+        b'm' | b'M' => (&value[..value.len() - 1], 1024 * 1024),
+        _ => (value, 1),
+    };
+    let value = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => value.parse().ok()?,
+    };
+
+    value.checked_mul(multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_automatic_offsets() {
+        let table = PartitionTableBuilder::default()
+            .add_partition(
+                "nvs",
+                Type::Data,
+                SubType::Data(DataType::Nvs),
+                None,
+                0x6000,
+                Flags::empty(),
+            )
+            .unwrap()
+            .add_partition(
+                "phy_init",
+                Type::Data,
+                SubType::Data(DataType::Phy),
+                None,
+                0x1000,
+                Flags::empty(),
+            )
+            .unwrap()
+            .add_partition(
+                "factory",
+                Type::App,
+                SubType::App(AppType::Factory),
+                None,
+                0x10_0000,
+                Flags::empty(),
+            )
+            .unwrap()
+            .build();
+
+        let offsets = table
+            .partitions()
+            .iter()
+            .map(|part| part.offset())
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [0x9000, 0xf000, 0x1_0000]);
+    }
+
+    #[test]
+    fn test_invalid_partitions() {
+        let mut builder = PartitionTableBuilder::default();
+        builder
+            .add_partition(
+                "nvs",
+                Type::Data,
+                SubType::Data(DataType::Nvs),
+                None,
+                0x6000,
+                Flags::empty(),
+            )
+            .unwrap();
+
+        // Overlaps with `nvs`
+        assert!(
+            builder
+                .add_partition(
+                    "other",
+                    Type::Data,
+                    SubType::Data(DataType::Nvs),
+                    Some(0xa000),
+                    0x1000,
+                    Flags::empty(),
+                )
+                .is_err()
+        );
+        // Overlaps with the partition table
+        assert!(
+            builder
+                .add_partition(
+                    "other",
+                    Type::Data,
+                    SubType::Data(DataType::Nvs),
+                    Some(0x8000),
+                    0x1000,
+                    Flags::empty(),
+                )
+                .is_err()
+        );
+        // Misaligned app partition
+        assert!(
+            builder
+                .add_partition(
+                    "factory",
+                    Type::App,
+                    SubType::App(AppType::Factory),
+                    Some(0x11000),
+                    0x10_0000,
+                    Flags::empty(),
+                )
+                .is_err()
+        );
+        // Duplicate name
+        assert!(
+            builder
+                .add_partition(
+                    "nvs",
+                    Type::Data,
+                    SubType::Data(DataType::Nvs),
+                    None,
+                    0x1000,
+                    Flags::empty(),
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_table_offset() {
+        assert!(PartitionTableBuilder::new(0x9000).is_ok());
+        assert!(matches!(
+            PartitionTableBuilder::new(0x8800),
+            Err(Error::InvalidPartitionTableOffset { offset: 0x8800, .. })
+        ));
+        assert!(matches!(
+            PartitionTableBuilder::new(0xFFFF_F800),
+            Err(Error::InvalidPartitionTableOffset { .. })
+        ));
+        assert!(matches!(
+            PartitionTableBuilder::new(0xFFFF_F000),
+            Err(Error::InvalidPartitionTableOffset { .. })
+        ));
+
+        // Automatic offsets past the end of the address space
+        let mut builder = PartitionTableBuilder::new(0xFFFE_E000).unwrap();
+        builder
+            .add_partition(
+                "nvs",
+                Type::Data,
+                SubType::Data(DataType::Nvs),
+                None,
+                0x2000,
+                Flags::empty(),
+            )
+            .unwrap();
+        assert!(matches!(
+            builder.add_partition(
+                "factory",
+                Type::App,
+                SubType::App(AppType::Factory),
+                None,
+                0x1000,
+                Flags::empty(),
+            ),
+            Err(Error::InvalidPartition { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_csv() {
+        let valid = "# Name, Type, SubType, Offset, Size, Flags
+nvs,      data, nvs,     ,        0x6000,
+phy_init, data, phy,     ,        0x1000,
+factory,  app,  factory, 0x10000, 1M,
+";
+        assert!(validate_csv(valid, 0x8000).is_empty());
+
+        let invalid = "nvs,      data, nvs,     0x9000,  0x6000,
+nvs,      data, phy,     0xf000,  0x1000,
+factory,  app,  factory, 0x18000, 1M,
+storage,  data, spiffs,  0x100000, 64K,
+broken,   app
+";
+        let issues = validate_csv(invalid, 0x8000);
+        let lines = issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .map(|issue| issue.line)
+            .collect::<Vec<_>>();
+        // Duplicate name, unaligned app partition, overlap and missing fields
+        assert_eq!(lines, [Some(2), Some(3), Some(4), Some(5)]);
+
+        let overflowing = "nvs,      data, nvs,     0xfff00000, 0x200000,
+factory,  app,  factory, 0xfffe0000, 0x10000,
+ota_0,    app,  ota_0,   ,           0x10000,
+";
+        let issues = validate_csv(overflowing, 0x8000);
+        let lines = issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .map(|issue| issue.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [Some(1), Some(3)]);
+        assert!(!validate_csv(valid, u32::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_parse_device_table() {
+        let csv = std::fs::read("tests/data/partitions.csv").unwrap();
+        let mut bin = PartitionTable::try_from(csv).unwrap().to_bin().unwrap();
+
+        let table = parse_device_table(&bin).unwrap().unwrap();
+        assert_eq!(table.partitions().len(), 3);
+
+        assert!(parse_device_table(&[0xff; 0x1000]).unwrap().is_none());
+
+        // Corrupt the size of the first partition
+        bin[8] ^= 0x01;
+        assert!(matches!(
+            parse_device_table(&bin),
+            Err(Error::CorruptPartitionTable(_))
+        ));
+    }
+
+    #[test]
+    fn test_idf_bin() {
+        let csv = std::fs::read("tests/data/partitions.csv").unwrap();
+        let table = PartitionTable::try_from(csv).unwrap();
+
+        // The entries as packed by `gen_esp32part.py` (`<2sBBLL16sL`: magic,
+        // type, subtype, offset, size, name, flags), followed by the MD5
+        // checksum entry
+        #[rustfmt::skip]
+        let expected: [u8; 4 * ENTRY_SIZE] = [
+            0xaa, 0x50, 0x01, 0x02, 0x00, 0x90, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00,
+            b'n', b'v', b's', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0x00, 0x00, 0x00, 0x00,
+            0xaa, 0x50, 0x01, 0x01, 0x00, 0xf0, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            b'p', b'h', b'y', b'_', b'i', b'n', b'i', b't', 0, 0, 0, 0, 0, 0, 0, 0,
+            0x00, 0x00, 0x00, 0x00,
+            0xaa, 0x50, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x40, 0x00,
+            b'f', b'a', b'c', b't', b'o', b'r', b'y', 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0x00, 0x00, 0x00, 0x00,
+            0xeb, 0xeb, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff,
+            0xd5, 0x7a, 0xaa, 0x4e, 0x83, 0x17, 0xed, 0x5f, 0x61, 0xfa, 0xb3, 0xdf,
+            0x96, 0x81, 0x74, 0x46,
+        ];
+
+        let bin = to_idf_bin(&table, true).unwrap();
+        assert_eq!(bin.len(), IDF_TABLE_LEN);
+        assert_eq!(bin[..expected.len()], expected);
+        assert!(bin[expected.len()..].iter().all(|&b| b == 0xff));
+        assert!(parse_device_table(&bin).unwrap().is_some());
+
+        let bin = to_idf_bin(&table, false).unwrap();
+        assert_eq!(bin.len(), IDF_TABLE_LEN);
+        assert!(bin[3 * ENTRY_SIZE..].iter().all(|&b| b == 0xff));
+    }
+
+    #[test]
+    fn test_explicit_format() {
+        let csv = std::fs::read("tests/data/partitions.csv").unwrap();
+        let bin = PartitionTable::try_from(csv.clone())
+            .unwrap()
+            .to_bin()
+            .unwrap();
+
+        let from_csv = PartitionTableFormat::Csv.parse(csv.clone()).unwrap();
+        let from_bin = PartitionTableFormat::Bin.parse(bin.clone()).unwrap();
+        assert_eq!(from_csv.to_bin().unwrap(), from_bin.to_bin().unwrap());
+
+        assert!(matches!(
+            PartitionTableFormat::Csv.parse(bin),
+            Err(Error::PartitionTableFormatMismatch(
+                PartitionTableFormat::Csv
+            ))
+        ));
+        assert!(matches!(
+            PartitionTableFormat::Bin.parse(csv),
+            Err(Error::PartitionTableFormatMismatch(
+                PartitionTableFormat::Bin
+            ))
+        ));
+    }
+}
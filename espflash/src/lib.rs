@@ -51,9 +51,15 @@ pub mod logging {
     use env_logger::{Builder, Env};
     use log::LevelFilter;
 
+    use crate::connection::TRACE_IO_TARGET;
+
     /// Initialize the logger with the given [LevelFilter]
+    ///
+    /// Protocol traces are always let through, as they are only emitted when
+    /// explicitly enabled on the connection.
     pub fn initialize_logger(filter: LevelFilter) {
         Builder::from_env(Env::default().default_filter_or(filter.as_str()))
+            .filter_module(TRACE_IO_TARGET, LevelFilter::Trace)
             .format_target(false)
             .init();
     }
@@ -0,0 +1,152 @@
+//! Decoding of the ADC and temperature sensor calibration eFuses
+//!
+//! The calibration data is written to eFuse during factory testing, and its
+//! layout differs between chips. On the ESP32 the reference voltage and the
+//! two-point values are stored as offsets from nominal voltages, which are
+//! decoded into millivolts. The reference voltage offset is stored in
+//! sign-magnitude, the two-point offsets in two's complement. On later chips
+//! the ADC calibration is stored as raw ADC codes, which are reported as is,
+//! and the temperature sensor offset is decoded into degrees Celsius.
+
+use serde::Serialize;
+
+use crate::target::Chip;
+
+/// A calibration eFuse field, decoded where the layout is known
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CalibrationValue {
+    /// Name of the field in the chip's eFuse table
+    pub name: &'static str,
+    /// Raw value of the field
+    pub raw: u64,
+    /// Value in physical units, if the encoding of the field is known
+    pub value: Option<f32>,
+    /// Unit of `value`
+    pub unit: Option<&'static str>,
+}
+
+/// Returns the names and definitions of the calibration fields of a chip
+pub fn fields(chip: Chip) -> impl Iterator<Item = &'static (&'static str, super::EfuseField)> {
+    chip.efuse_fields()
+        .iter()
+        .filter(|(name, _)| is_calibration_field(name))
+}
+
+fn is_calibration_field(name: &str) -> bool {
+    name.starts_with("ADC")
+        || name.starts_with("RTCCALIB_")
+        || matches!(
+            name,
+            "BLK3_PART_RESERVE" | "OCODE" | "TEMP_CALIB" | "TEMPERATURE_SENSOR"
+        )
+}
+
+/// Decodes the raw values of a chip's calibration fields
+///
+/// The ESP32 two-point values are only decoded if `BLK3_PART_RESERVE` is set,
+/// as the same bits are otherwise free for application use.
+pub fn decode(chip: Chip, fields: &[(&'static str, u64)]) -> Vec<CalibrationValue> {
+    let two_point = fields
+        .iter()
+        .any(|(name, raw)| *name == "BLK3_PART_RESERVE" && *raw != 0);
+
+    fields
+        .iter()
+        .map(|&(name, raw)| {
+            let decoded = match (chip, name) {
+                (Chip::Esp32, "ADC_VREF") => {
+                    Some((millivolts(1100, 7, sign_magnitude(raw, 5)), "mV"))
+                }
+                (Chip::Esp32, "ADC1_TP_LOW") if two_point => {
+                    Some((millivolts(278, 4, twos_complement(raw, 7)), "mV"))
+                }
+                (Chip::Esp32, "ADC1_TP_HIGH") if two_point => {
+                    Some((millivolts(3265, 4, twos_complement(raw, 9)), "mV"))
+                }
+                (Chip::Esp32, "ADC2_TP_LOW") if two_point => {
+                    Some((millivolts(421, 4, twos_complement(raw, 7)), "mV"))
+                }
+                (Chip::Esp32, "ADC2_TP_HIGH") if two_point => {
+                    Some((millivolts(3406, 4, twos_complement(raw, 9)), "mV"))
+                }
+                (_, "TEMP_CALIB") => Some((sign_magnitude(raw, 9) as f32 / 10.0, "°C")),
+                _ => None,
+            };
+
+            CalibrationValue {
+                name,
+                raw,
+                value: decoded.map(|(value, _)| value),
+                unit: decoded.map(|(_, unit)| unit),
+            }
+        })
+        .collect()
+}
+
+/// Decodes an offset of `step` mV from a nominal voltage
+fn millivolts(nominal: i64, step: i64, offset: i64) -> f32 {
+    (nominal + step * offset) as f32
+}
+
+/// Decodes a two's complement value of the given width
+fn twos_complement(raw: u64, bits: u32) -> i64 {
+    let raw = (raw & ((1 << bits) - 1)) as i64;
+
+    if raw & (1 << (bits - 1)) != 0 {
+        raw - (1 << bits)
+    } else {
+        raw
+    }
+}
+
+/// Decodes a sign-magnitude value, with the sign in the highest bit
+fn sign_magnitude(raw: u64, bits: u32) -> i64 {
+    let magnitude = (raw & ((1 << (bits - 1)) - 1)) as i64;
+
+    if raw & (1 << (bits - 1)) != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_esp32_calibration() {
+        let values = decode(
+            Chip::Esp32,
+            &[
+                ("BLK3_PART_RESERVE", 1),
+                ("ADC_VREF", 0b1_0011),
+                ("ADC1_TP_LOW", 0b000_0101),
+                ("ADC1_TP_HIGH", 0b1_0000_0010),
+            ],
+        );
+
+        assert_eq!(values[1].value, Some(1079.0));
+        assert_eq!(values[2].value, Some(298.0));
+        // Two's complement, -254 steps
+        assert_eq!(values[3].value, Some(2249.0));
+        assert_eq!(values[3].unit, Some("mV"));
+
+        // Two-point values are left raw unless BLK3_PART_RESERVE is set
+        let values = decode(Chip::Esp32, &[("ADC1_TP_LOW", 5)]);
+        assert_eq!(values[0].value, None);
+    }
+
+    #[test]
+    fn decodes_temperature_offset() {
+        let values = decode(
+            Chip::Esp32c3,
+            &[("TEMP_CALIB", 0x100 | 25), ("ADC1_INIT_CODE_ATTEN0", 400)],
+        );
+
+        assert_eq!(values[0].value, Some(-2.5));
+        assert_eq!(values[1].value, None);
+        assert_eq!(values[1].raw, 400);
+    }
+}
@@ -0,0 +1,557 @@
+//! eFuse field definitions for the esp32c3
+//!
+//! This file was automatically generated, please do not edit it manually!
+//!
+//! Generated: 2025-12-08 14:48
+//! Version:   4622cf9245401eca0eb1df8122449a6d
+
+#![allow(unused)]
+
+use super::{EfuseBlock, EfuseField};
+
+/// All eFuse blocks available on this device.
+pub(crate) const BLOCKS: &[EfuseBlock] = &[
+    EfuseBlock {
+        index: 0u8,
+        length: 6u8,
+        read_address: 0x6000882cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 1u8,
+        length: 6u8,
+        read_address: 0x60008844u32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 2u8,
+        length: 8u8,
+        read_address: 0x6000885cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 3u8,
+        length: 8u8,
+        read_address: 0x6000887cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 4u8,
+        length: 8u8,
+        read_address: 0x6000889cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 5u8,
+        length: 8u8,
+        read_address: 0x600088bcu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 6u8,
+        length: 8u8,
+        read_address: 0x600088dcu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 7u8,
+        length: 8u8,
+        read_address: 0x600088fcu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 8u8,
+        length: 8u8,
+        read_address: 0x6000891cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 9u8,
+        length: 8u8,
+        read_address: 0x6000893cu32,
+        write_address: 0x60008800u32,
+    },
+    EfuseBlock {
+        index: 10u8,
+        length: 8u8,
+        read_address: 0x6000895cu32,
+        write_address: 0x60008800u32,
+    },
+];
+
+/// Defined eFuse registers and commands
+pub(crate) mod defines {
+    use super::super::EfuseBlockErrors;
+    pub(crate) const BLOCK_ERRORS: &[EfuseBlockErrors] = &[
+        EfuseBlockErrors {
+            err_num_reg: 0x6000897cu32,
+            err_num_mask: None,
+            err_num_offset: None,
+            fail_bit_reg: 0x6000897cu32,
+            fail_bit_offset: None,
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x0u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0x7u32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x4u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0xbu32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x8u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0xfu32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0xcu32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0x13u32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x10u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0x17u32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x14u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0x1bu32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x18u32),
+            fail_bit_reg: 0x600089c0u32,
+            fail_bit_offset: Some(0x1fu32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c0u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x1cu32),
+            fail_bit_reg: 0x600089c4u32,
+            fail_bit_offset: Some(0x3u32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c4u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x0u32),
+            fail_bit_reg: 0x600089c4u32,
+            fail_bit_offset: Some(0x7u32),
+        },
+        EfuseBlockErrors {
+            err_num_reg: 0x600089c4u32,
+            err_num_mask: Some(0x7u32),
+            err_num_offset: Some(0x4u32),
+            fail_bit_reg: 0x600089c4u32,
+            fail_bit_offset: None,
+        },
+    ];
+    pub(crate) const EFUSE_PWR_OFF_NUM_M: u32 = 0xffff;
+    pub(crate) const EFUSE_RD_REPEAT_ERR3_REG: u32 = 0x60008988;
+    pub(crate) const EFUSE_READ_OP_CODE: u32 = 0x5aa5;
+    pub(crate) const EFUSE_DAC_CLK_DIV_M: u32 = 0xff;
+    pub(crate) const EFUSE_DAC_NUM_M: u32 = 0x1fe00;
+    pub(crate) const EFUSE_MEM_SIZE: u32 = 0x200;
+    pub(crate) const EFUSE_RD_REPEAT_ERR2_REG: u32 = 0x60008984;
+    pub(crate) const EFUSE_DAC_NUM_S: u32 = 0x9;
+    pub(crate) const CODING_SCHEME_NONE: u32 = 0x0;
+    pub(crate) const EFUSE_WR_TIM_CONF2_REG: u32 = 0x600089f4;
+    pub(crate) const EFUSE_WR_TIM_CONF1_REG: u32 = 0x600089f0;
+    pub(crate) const EFUSE_WRITE_OP_CODE: u32 = 0x5a5a;
+    pub(crate) const EFUSE_CLK_REG: u32 = 0x600089c8;
+    pub(crate) const EFUSE_PWR_ON_NUM_M: u32 = 0xffff00;
+    pub(crate) const EFUSE_STATUS_REG: u32 = 0x600089d0;
+    pub(crate) const CODING_SCHEME_RS: u32 = 0x4;
+    pub(crate) const EFUSE_PGM_CMD_MASK: u32 = 0x3;
+    pub(crate) const EFUSE_PGM_DATA0_REG: u32 = 0x60008800;
+    pub(crate) const EFUSE_PGM_CMD: u32 = 0x2;
+    pub(crate) const EFUSE_PWR_ON_NUM_S: u32 = 0x8;
+    pub(crate) const EFUSE_PWR_OFF_NUM_S: u32 = 0x0;
+    pub(crate) const EFUSE_DATE_REG: u32 = 0x600089fc;
+    pub(crate) const EFUSE_CHECK_VALUE0_REG: u32 = 0x60008820;
+    pub(crate) const CODING_SCHEME_NONE_RECOVERY: u32 = 0x3;
+    pub(crate) const EFUSE_RD_REPEAT_ERR1_REG: u32 = 0x60008980;
+    pub(crate) const EFUSE_RD_TIM_CONF_REG: u32 = 0x600089ec;
+    pub(crate) const EFUSE_DAC_CONF_REG: u32 = 0x600089e8;
+    pub(crate) const CODING_SCHEME_REPEAT: u32 = 0x2;
+    pub(crate) const CODING_SCHEME_34: u32 = 0x1;
+    pub(crate) const EFUSE_CONF_REG: u32 = 0x600089cc;
+    pub(crate) const EFUSE_RD_REPEAT_ERR4_REG: u32 = 0x6000898c;
+    pub(crate) const EFUSE_DAC_CLK_DIV_S: u32 = 0x0;
+    pub(crate) const EFUSE_RD_REPEAT_ERR0_REG: u32 = 0x6000897c;
+    pub(crate) const EFUSE_READ_CMD: u32 = 0x1;
+    pub(crate) const EFUSE_RD_RS_ERR1_REG: u32 = 0x600089c4;
+    pub(crate) const EFUSE_RD_RS_ERR0_REG: u32 = 0x600089c0;
+    pub(crate) const EFUSE_CMD_REG: u32 = 0x600089d4;
+}
+
+/// Disable programming of individual eFuses
+pub const WR_DIS: EfuseField = EfuseField::new(0, 0, 0, 32);
+/// Disable reading from BlOCK4-10
+pub const RD_DIS: EfuseField = EfuseField::new(0, 1, 32, 7);
+/// Set this bit to disable boot from RTC RAM
+pub const DIS_RTC_RAM_BOOT: EfuseField = EfuseField::new(0, 1, 39, 1);
+/// Set this bit to disable Icache
+pub const DIS_ICACHE: EfuseField = EfuseField::new(0, 1, 40, 1);
+/// Set this bit to disable function of usb switch to jtag in module of usb
+/// device
+pub const DIS_USB_JTAG: EfuseField = EfuseField::new(0, 1, 41, 1);
+/// Set this bit to disable Icache in download mode (boot_mode\[3:0\] is 0; 1;
+/// 2; 3; 6; 7)
+pub const DIS_DOWNLOAD_ICACHE: EfuseField = EfuseField::new(0, 1, 42, 1);
+/// USB-Serial-JTAG
+pub const DIS_USB_SERIAL_JTAG: EfuseField = EfuseField::new(0, 1, 43, 1);
+/// Set this bit to disable the function that forces chip into download mode
+pub const DIS_FORCE_DOWNLOAD: EfuseField = EfuseField::new(0, 1, 44, 1);
+/// Reserved (used for four backups method)
+pub const RPT4_RESERVED6: EfuseField = EfuseField::new(0, 1, 45, 1);
+/// Set this bit to disable CAN function
+pub const DIS_TWAI: EfuseField = EfuseField::new(0, 1, 46, 1);
+/// Set this bit to enable selection between usb_to_jtag and pad_to_jtag through
+/// strapping gpio10 when both reg_dis_usb_jtag and reg_dis_pad_jtag are equal
+/// to 0
+pub const JTAG_SEL_ENABLE: EfuseField = EfuseField::new(0, 1, 47, 1);
+/// Set these bits to disable JTAG in the soft way (odd number 1 means disable
+/// ). JTAG can be enabled in HMAC module
+pub const SOFT_DIS_JTAG: EfuseField = EfuseField::new(0, 1, 48, 3);
+/// Set this bit to disable JTAG in the hard way. JTAG is disabled permanently
+pub const DIS_PAD_JTAG: EfuseField = EfuseField::new(0, 1, 51, 1);
+/// Set this bit to disable flash encryption when in download boot modes
+pub const DIS_DOWNLOAD_MANUAL_ENCRYPT: EfuseField = EfuseField::new(0, 1, 52, 1);
+/// Controls single-end input threshold vrefh; 1.76 V to 2 V with step of 80 mV;
+/// stored in eFuse
+pub const USB_DREFH: EfuseField = EfuseField::new(0, 1, 53, 2);
+/// Controls single-end input threshold vrefl; 0.8 V to 1.04 V with step of 80
+/// mV; stored in eFuse
+pub const USB_DREFL: EfuseField = EfuseField::new(0, 1, 55, 2);
+/// Set this bit to exchange USB D+ and D- pins
+pub const USB_EXCHG_PINS: EfuseField = EfuseField::new(0, 1, 57, 1);
+/// Set this bit to vdd spi pin function as gpio
+pub const VDD_SPI_AS_GPIO: EfuseField = EfuseField::new(0, 1, 58, 1);
+/// Enable btlc gpio
+pub const BTLC_GPIO_ENABLE: EfuseField = EfuseField::new(0, 1, 59, 2);
+/// Set this bit to enable power glitch function
+pub const POWERGLITCH_EN: EfuseField = EfuseField::new(0, 1, 61, 1);
+/// Sample delay configuration of power glitch
+pub const POWER_GLITCH_DSENSE: EfuseField = EfuseField::new(0, 1, 62, 2);
+/// Reserved (used for four backups method)
+pub const RPT4_RESERVED2: EfuseField = EfuseField::new(0, 2, 64, 16);
+/// RTC watchdog timeout threshold; in unit of slow clock cycle
+pub const WDT_DELAY_SEL: EfuseField = EfuseField::new(0, 2, 80, 2);
+/// Enables flash encryption when 1 or 3 bits are set and disables otherwise
+pub const SPI_BOOT_CRYPT_CNT: EfuseField = EfuseField::new(0, 2, 82, 3);
+/// Revoke 1st secure boot key
+pub const SECURE_BOOT_KEY_REVOKE0: EfuseField = EfuseField::new(0, 2, 85, 1);
+/// Revoke 2nd secure boot key
+pub const SECURE_BOOT_KEY_REVOKE1: EfuseField = EfuseField::new(0, 2, 86, 1);
+/// Revoke 3rd secure boot key
+pub const SECURE_BOOT_KEY_REVOKE2: EfuseField = EfuseField::new(0, 2, 87, 1);
+/// Purpose of Key0
+pub const KEY_PURPOSE_0: EfuseField = EfuseField::new(0, 2, 88, 4);
+/// Purpose of Key1
+pub const KEY_PURPOSE_1: EfuseField = EfuseField::new(0, 2, 92, 4);
+/// Purpose of Key2
+pub const KEY_PURPOSE_2: EfuseField = EfuseField::new(0, 3, 96, 4);
+/// Purpose of Key3
+pub const KEY_PURPOSE_3: EfuseField = EfuseField::new(0, 3, 100, 4);
+/// Purpose of Key4
+pub const KEY_PURPOSE_4: EfuseField = EfuseField::new(0, 3, 104, 4);
+/// Purpose of Key5
+pub const KEY_PURPOSE_5: EfuseField = EfuseField::new(0, 3, 108, 4);
+/// Reserved (used for four backups method)
+pub const RPT4_RESERVED3: EfuseField = EfuseField::new(0, 3, 112, 4);
+/// Set this bit to enable secure boot
+pub const SECURE_BOOT_EN: EfuseField = EfuseField::new(0, 3, 116, 1);
+/// Set this bit to enable revoking aggressive secure boot
+pub const SECURE_BOOT_AGGRESSIVE_REVOKE: EfuseField = EfuseField::new(0, 3, 117, 1);
+/// Reserved (used for four backups method)
+pub const RPT4_RESERVED0: EfuseField = EfuseField::new(0, 3, 118, 6);
+/// Configures flash waiting time after power-up; in unit of ms. If the value is
+/// less than 15; the waiting time is the configurable value; Otherwise; the
+/// waiting time is twice the configurable value
+pub const FLASH_TPUW: EfuseField = EfuseField::new(0, 3, 124, 4);
+/// Set this bit to disable download mode (boot_mode\[3:0\] = 0; 1; 2; 3; 6; 7)
+pub const DIS_DOWNLOAD_MODE: EfuseField = EfuseField::new(0, 4, 128, 1);
+/// Disable direct boot mode
+pub const DIS_DIRECT_BOOT: EfuseField = EfuseField::new(0, 4, 129, 1);
+/// USB printing
+pub const DIS_USB_SERIAL_JTAG_ROM_PRINT: EfuseField = EfuseField::new(0, 4, 130, 1);
+/// ECC mode in ROM
+pub const FLASH_ECC_MODE: EfuseField = EfuseField::new(0, 4, 131, 1);
+/// Disable UART download mode through USB-Serial-JTAG
+pub const DIS_USB_SERIAL_JTAG_DOWNLOAD_MODE: EfuseField = EfuseField::new(0, 4, 132, 1);
+/// Set this bit to enable secure UART download mode
+pub const ENABLE_SECURITY_DOWNLOAD: EfuseField = EfuseField::new(0, 4, 133, 1);
+/// Set the default UARTboot message output mode
+pub const UART_PRINT_CONTROL: EfuseField = EfuseField::new(0, 4, 134, 2);
+/// GPIO33-GPIO37 power supply selection in ROM code
+pub const PIN_POWER_SELECTION: EfuseField = EfuseField::new(0, 4, 136, 1);
+/// Maximum lines of SPI flash
+pub const FLASH_TYPE: EfuseField = EfuseField::new(0, 4, 137, 1);
+/// Set Flash page size
+pub const FLASH_PAGE_SIZE: EfuseField = EfuseField::new(0, 4, 138, 2);
+/// Set 1 to enable ECC for flash boot
+pub const FLASH_ECC_EN: EfuseField = EfuseField::new(0, 4, 140, 1);
+/// Set this bit to force ROM code to send a resume command during SPI boot
+pub const FORCE_SEND_RESUME: EfuseField = EfuseField::new(0, 4, 141, 1);
+/// Secure version (used by ESP-IDF anti-rollback feature)
+pub const SECURE_VERSION: EfuseField = EfuseField::new(0, 4, 142, 16);
+/// reserved
+pub const RESERVED_0_158: EfuseField = EfuseField::new(0, 4, 158, 1);
+/// Use BLOCK0 to check error record registers
+pub const ERR_RST_ENABLE: EfuseField = EfuseField::new(0, 4, 159, 1);
+/// Disables check of wafer version major
+pub const DISABLE_WAFER_VERSION_MAJOR: EfuseField = EfuseField::new(0, 5, 160, 1);
+/// Disables check of blk version major
+pub const DISABLE_BLK_VERSION_MAJOR: EfuseField = EfuseField::new(0, 5, 161, 1);
+/// reserved
+pub const RESERVED_0_162: EfuseField = EfuseField::new(0, 5, 162, 22);
+/// MAC address
+pub const MAC0: EfuseField = EfuseField::new(1, 0, 0, 32);
+/// MAC address
+pub const MAC1: EfuseField = EfuseField::new(1, 1, 32, 16);
+/// SPI PAD CLK
+pub const SPI_PAD_CONFIG_CLK: EfuseField = EfuseField::new(1, 1, 48, 6);
+/// SPI PAD Q(D1)
+pub const SPI_PAD_CONFIG_Q: EfuseField = EfuseField::new(1, 1, 54, 6);
+/// SPI PAD D(D0)
+pub const SPI_PAD_CONFIG_D: EfuseField = EfuseField::new(1, 1, 60, 6);
+/// SPI PAD CS
+pub const SPI_PAD_CONFIG_CS: EfuseField = EfuseField::new(1, 2, 66, 6);
+/// SPI PAD HD(D3)
+pub const SPI_PAD_CONFIG_HD: EfuseField = EfuseField::new(1, 2, 72, 6);
+/// SPI PAD WP(D2)
+pub const SPI_PAD_CONFIG_WP: EfuseField = EfuseField::new(1, 2, 78, 6);
+/// SPI PAD DQS
+pub const SPI_PAD_CONFIG_DQS: EfuseField = EfuseField::new(1, 2, 84, 6);
+/// SPI PAD D4
+pub const SPI_PAD_CONFIG_D4: EfuseField = EfuseField::new(1, 2, 90, 6);
+/// SPI PAD D5
+pub const SPI_PAD_CONFIG_D5: EfuseField = EfuseField::new(1, 3, 96, 6);
+/// SPI PAD D6
+pub const SPI_PAD_CONFIG_D6: EfuseField = EfuseField::new(1, 3, 102, 6);
+/// SPI PAD D7
+pub const SPI_PAD_CONFIG_D7: EfuseField = EfuseField::new(1, 3, 108, 6);
+/// WAFER_VERSION_MINOR least significant bits
+pub const WAFER_VERSION_MINOR_LO: EfuseField = EfuseField::new(1, 3, 114, 3);
+/// Package version
+pub const PKG_VERSION: EfuseField = EfuseField::new(1, 3, 117, 3);
+/// BLK_VERSION_MINOR
+pub const BLK_VERSION_MINOR: EfuseField = EfuseField::new(1, 3, 120, 3);
+/// Flash capacity
+pub const FLASH_CAP: EfuseField = EfuseField::new(1, 3, 123, 3);
+/// Flash temperature
+pub const FLASH_TEMP: EfuseField = EfuseField::new(1, 3, 126, 2);
+/// Flash vendor
+pub const FLASH_VENDOR: EfuseField = EfuseField::new(1, 4, 128, 3);
+/// reserved
+pub const RESERVED_1_131: EfuseField = EfuseField::new(1, 4, 131, 4);
+/// BLOCK1 K_RTC_LDO
+pub const K_RTC_LDO: EfuseField = EfuseField::new(1, 4, 135, 7);
+/// BLOCK1 K_DIG_LDO
+pub const K_DIG_LDO: EfuseField = EfuseField::new(1, 4, 142, 7);
+/// BLOCK1 voltage of rtc dbias20
+pub const V_RTC_DBIAS20: EfuseField = EfuseField::new(1, 4, 149, 8);
+/// BLOCK1 voltage of digital dbias20
+pub const V_DIG_DBIAS20: EfuseField = EfuseField::new(1, 4, 157, 8);
+/// BLOCK1 digital dbias when hvt
+pub const DIG_DBIAS_HVT: EfuseField = EfuseField::new(1, 5, 165, 5);
+/// BLOCK1 pvt threshold when hvt
+pub const THRES_HVT: EfuseField = EfuseField::new(1, 5, 170, 10);
+/// reserved
+pub const RESERVED_1_180: EfuseField = EfuseField::new(1, 5, 180, 3);
+/// WAFER_VERSION_MINOR most significant bit
+pub const WAFER_VERSION_MINOR_HI: EfuseField = EfuseField::new(1, 5, 183, 1);
+/// WAFER_VERSION_MAJOR
+pub const WAFER_VERSION_MAJOR: EfuseField = EfuseField::new(1, 5, 184, 2);
+/// reserved
+pub const RESERVED_1_186: EfuseField = EfuseField::new(1, 5, 186, 6);
+/// Optional unique 128-bit ID
+pub const OPTIONAL_UNIQUE_ID: EfuseField = EfuseField::new(2, 0, 0, 128);
+/// BLK_VERSION_MAJOR of BLOCK2
+pub const BLK_VERSION_MAJOR: EfuseField = EfuseField::new(2, 4, 128, 2);
+/// reserved
+pub const RESERVED_2_130: EfuseField = EfuseField::new(2, 4, 130, 1);
+/// Temperature calibration data
+pub const TEMP_CALIB: EfuseField = EfuseField::new(2, 4, 131, 9);
+/// ADC OCode
+pub const OCODE: EfuseField = EfuseField::new(2, 4, 140, 8);
+/// ADC1 init code at atten0
+pub const ADC1_INIT_CODE_ATTEN0: EfuseField = EfuseField::new(2, 4, 148, 10);
+/// ADC1 init code at atten1
+pub const ADC1_INIT_CODE_ATTEN1: EfuseField = EfuseField::new(2, 4, 158, 10);
+/// ADC1 init code at atten2
+pub const ADC1_INIT_CODE_ATTEN2: EfuseField = EfuseField::new(2, 5, 168, 10);
+/// ADC1 init code at atten3
+pub const ADC1_INIT_CODE_ATTEN3: EfuseField = EfuseField::new(2, 5, 178, 10);
+/// ADC1 calibration voltage at atten0
+pub const ADC1_CAL_VOL_ATTEN0: EfuseField = EfuseField::new(2, 5, 188, 10);
+/// ADC1 calibration voltage at atten1
+pub const ADC1_CAL_VOL_ATTEN1: EfuseField = EfuseField::new(2, 6, 198, 10);
+/// ADC1 calibration voltage at atten2
+pub const ADC1_CAL_VOL_ATTEN2: EfuseField = EfuseField::new(2, 6, 208, 10);
+/// ADC1 calibration voltage at atten3
+pub const ADC1_CAL_VOL_ATTEN3: EfuseField = EfuseField::new(2, 6, 218, 10);
+/// reserved
+pub const RESERVED_2_228: EfuseField = EfuseField::new(2, 7, 228, 28);
+/// User data
+pub const BLOCK_USR_DATA: EfuseField = EfuseField::new(3, 0, 0, 192);
+/// reserved
+pub const RESERVED_3_192: EfuseField = EfuseField::new(3, 6, 192, 8);
+/// Custom MAC address
+pub const CUSTOM_MAC: EfuseField = EfuseField::new(3, 6, 200, 48);
+/// reserved
+pub const RESERVED_3_248: EfuseField = EfuseField::new(3, 7, 248, 8);
+/// Key0 or user data
+pub const BLOCK_KEY0: EfuseField = EfuseField::new(4, 0, 0, 256);
+/// Key1 or user data
+pub const BLOCK_KEY1: EfuseField = EfuseField::new(5, 0, 0, 256);
+/// Key2 or user data
+pub const BLOCK_KEY2: EfuseField = EfuseField::new(6, 0, 0, 256);
+/// Key3 or user data
+pub const BLOCK_KEY3: EfuseField = EfuseField::new(7, 0, 0, 256);
+/// Key4 or user data
+pub const BLOCK_KEY4: EfuseField = EfuseField::new(8, 0, 0, 256);
+/// Key5 or user data
+pub const BLOCK_KEY5: EfuseField = EfuseField::new(9, 0, 0, 256);
+/// System data part 2 (reserved)
+pub const BLOCK_SYS_DATA2: EfuseField = EfuseField::new(10, 0, 0, 256);
+
+/// All eFuse fields defined for this device, by name.
+pub(crate) const FIELDS: &[(&str, EfuseField)] = &[
+    ("WR_DIS", WR_DIS),
+    ("RD_DIS", RD_DIS),
+    ("DIS_RTC_RAM_BOOT", DIS_RTC_RAM_BOOT),
+    ("DIS_ICACHE", DIS_ICACHE),
+    ("DIS_USB_JTAG", DIS_USB_JTAG),
+    ("DIS_DOWNLOAD_ICACHE", DIS_DOWNLOAD_ICACHE),
+    ("DIS_USB_SERIAL_JTAG", DIS_USB_SERIAL_JTAG),
+    ("DIS_FORCE_DOWNLOAD", DIS_FORCE_DOWNLOAD),
+    ("RPT4_RESERVED6", RPT4_RESERVED6),
+    ("DIS_TWAI", DIS_TWAI),
+    ("JTAG_SEL_ENABLE", JTAG_SEL_ENABLE),
+    ("SOFT_DIS_JTAG", SOFT_DIS_JTAG),
+    ("DIS_PAD_JTAG", DIS_PAD_JTAG),
+    ("DIS_DOWNLOAD_MANUAL_ENCRYPT", DIS_DOWNLOAD_MANUAL_ENCRYPT),
+    ("USB_DREFH", USB_DREFH),
+    ("USB_DREFL", USB_DREFL),
+    ("USB_EXCHG_PINS", USB_EXCHG_PINS),
+    ("VDD_SPI_AS_GPIO", VDD_SPI_AS_GPIO),
+    ("BTLC_GPIO_ENABLE", BTLC_GPIO_ENABLE),
+    ("POWERGLITCH_EN", POWERGLITCH_EN),
+    ("POWER_GLITCH_DSENSE", POWER_GLITCH_DSENSE),
+    ("RPT4_RESERVED2", RPT4_RESERVED2),
+    ("WDT_DELAY_SEL", WDT_DELAY_SEL),
+    ("SPI_BOOT_CRYPT_CNT", SPI_BOOT_CRYPT_CNT),
+    ("SECURE_BOOT_KEY_REVOKE0", SECURE_BOOT_KEY_REVOKE0),
+    ("SECURE_BOOT_KEY_REVOKE1", SECURE_BOOT_KEY_REVOKE1),
+    ("SECURE_BOOT_KEY_REVOKE2", SECURE_BOOT_KEY_REVOKE2),
+    ("KEY_PURPOSE_0", KEY_PURPOSE_0),
+    ("KEY_PURPOSE_1", KEY_PURPOSE_1),
+    ("KEY_PURPOSE_2", KEY_PURPOSE_2),
+    ("KEY_PURPOSE_3", KEY_PURPOSE_3),
+    ("KEY_PURPOSE_4", KEY_PURPOSE_4),
+    ("KEY_PURPOSE_5", KEY_PURPOSE_5),
+    ("RPT4_RESERVED3", RPT4_RESERVED3),
+    ("SECURE_BOOT_EN", SECURE_BOOT_EN),
+    (
+        "SECURE_BOOT_AGGRESSIVE_REVOKE",
+        SECURE_BOOT_AGGRESSIVE_REVOKE,
+    ),
+    ("RPT4_RESERVED0", RPT4_RESERVED0),
+    ("FLASH_TPUW", FLASH_TPUW),
+    ("DIS_DOWNLOAD_MODE", DIS_DOWNLOAD_MODE),
+    ("DIS_DIRECT_BOOT", DIS_DIRECT_BOOT),
+    (
+        "DIS_USB_SERIAL_JTAG_ROM_PRINT",
+        DIS_USB_SERIAL_JTAG_ROM_PRINT,
+    ),
+    ("FLASH_ECC_MODE", FLASH_ECC_MODE),
+    (
+        "DIS_USB_SERIAL_JTAG_DOWNLOAD_MODE",
+        DIS_USB_SERIAL_JTAG_DOWNLOAD_MODE,
+    ),
+    ("ENABLE_SECURITY_DOWNLOAD", ENABLE_SECURITY_DOWNLOAD),
+    ("UART_PRINT_CONTROL", UART_PRINT_CONTROL),
+    ("PIN_POWER_SELECTION", PIN_POWER_SELECTION),
+    ("FLASH_TYPE", FLASH_TYPE),
+    ("FLASH_PAGE_SIZE", FLASH_PAGE_SIZE),
+    ("FLASH_ECC_EN", FLASH_ECC_EN),
+    ("FORCE_SEND_RESUME", FORCE_SEND_RESUME),
+    ("SECURE_VERSION", SECURE_VERSION),
+    ("RESERVED_0_158", RESERVED_0_158),
+    ("ERR_RST_ENABLE", ERR_RST_ENABLE),
+    ("DISABLE_WAFER_VERSION_MAJOR", DISABLE_WAFER_VERSION_MAJOR),
+    ("DISABLE_BLK_VERSION_MAJOR", DISABLE_BLK_VERSION_MAJOR),
+    ("RESERVED_0_162", RESERVED_0_162),
+    ("MAC0", MAC0),
+    ("MAC1", MAC1),
+    ("SPI_PAD_CONFIG_CLK", SPI_PAD_CONFIG_CLK),
+    ("SPI_PAD_CONFIG_Q", SPI_PAD_CONFIG_Q),
+    ("SPI_PAD_CONFIG_D", SPI_PAD_CONFIG_D),
+    ("SPI_PAD_CONFIG_CS", SPI_PAD_CONFIG_CS),
+    ("SPI_PAD_CONFIG_HD", SPI_PAD_CONFIG_HD),
+    ("SPI_PAD_CONFIG_WP", SPI_PAD_CONFIG_WP),
+    ("SPI_PAD_CONFIG_DQS", SPI_PAD_CONFIG_DQS),
+    ("SPI_PAD_CONFIG_D4", SPI_PAD_CONFIG_D4),
+    ("SPI_PAD_CONFIG_D5", SPI_PAD_CONFIG_D5),
+    ("SPI_PAD_CONFIG_D6", SPI_PAD_CONFIG_D6),
+    ("SPI_PAD_CONFIG_D7", SPI_PAD_CONFIG_D7),
+    ("WAFER_VERSION_MINOR_LO", WAFER_VERSION_MINOR_LO),
+    ("PKG_VERSION", PKG_VERSION),
+    ("BLK_VERSION_MINOR", BLK_VERSION_MINOR),
+    ("FLASH_CAP", FLASH_CAP),
+    ("FLASH_TEMP", FLASH_TEMP),
+    ("FLASH_VENDOR", FLASH_VENDOR),
+    ("RESERVED_1_131", RESERVED_1_131),
+    ("K_RTC_LDO", K_RTC_LDO),
+    ("K_DIG_LDO", K_DIG_LDO),
+    ("V_RTC_DBIAS20", V_RTC_DBIAS20),
+    ("V_DIG_DBIAS20", V_DIG_DBIAS20),
+    ("DIG_DBIAS_HVT", DIG_DBIAS_HVT),
+    ("THRES_HVT", THRES_HVT),
+    ("RESERVED_1_180", RESERVED_1_180),
+    ("WAFER_VERSION_MINOR_HI", WAFER_VERSION_MINOR_HI),
+    ("WAFER_VERSION_MAJOR", WAFER_VERSION_MAJOR),
+    ("RESERVED_1_186", RESERVED_1_186),
+    ("OPTIONAL_UNIQUE_ID", OPTIONAL_UNIQUE_ID),
+    ("BLK_VERSION_MAJOR", BLK_VERSION_MAJOR),
+    ("RESERVED_2_130", RESERVED_2_130),
+    ("TEMP_CALIB", TEMP_CALIB),
+    ("OCODE", OCODE),
+    ("ADC1_INIT_CODE_ATTEN0", ADC1_INIT_CODE_ATTEN0),
+    ("ADC1_INIT_CODE_ATTEN1", ADC1_INIT_CODE_ATTEN1),
+    ("ADC1_INIT_CODE_ATTEN2", ADC1_INIT_CODE_ATTEN2),
+    ("ADC1_INIT_CODE_ATTEN3", ADC1_INIT_CODE_ATTEN3),
+    ("ADC1_CAL_VOL_ATTEN0", ADC1_CAL_VOL_ATTEN0),
+    ("ADC1_CAL_VOL_ATTEN1", ADC1_CAL_VOL_ATTEN1),
+    ("ADC1_CAL_VOL_ATTEN2", ADC1_CAL_VOL_ATTEN2),
+    ("ADC1_CAL_VOL_ATTEN3", ADC1_CAL_VOL_ATTEN3),
+    ("RESERVED_2_228", RESERVED_2_228),
+    ("BLOCK_USR_DATA", BLOCK_USR_DATA),
+    ("RESERVED_3_192", RESERVED_3_192),
+    ("CUSTOM_MAC", CUSTOM_MAC),
+    ("RESERVED_3_248", RESERVED_3_248),
+    ("BLOCK_KEY0", BLOCK_KEY0),
+    ("BLOCK_KEY1", BLOCK_KEY1),
+    ("BLOCK_KEY2", BLOCK_KEY2),
+    ("BLOCK_KEY3", BLOCK_KEY3),
+    ("BLOCK_KEY4", BLOCK_KEY4),
+    ("BLOCK_KEY5", BLOCK_KEY5),
+    ("BLOCK_SYS_DATA2", BLOCK_SYS_DATA2),
+];
@@ -0,0 +1,349 @@
+//! ESP32 flash target module.
+//!
+//! This module defines the traits and types used for flashing operations on a
+//! target device's flash memory.
+
+use std::io::Write;
+
+use flate2::{
+    Compression,
+    write::{ZlibDecoder, ZlibEncoder},
+};
+use log::debug;
+use md5::{Digest, Md5};
+
+use crate::{
+    Error,
+    flasher::{FLASH_SECTOR_SIZE, SpiAttachParams},
+    image_format::Segment,
+    target::{Chip, WDT_WKEY},
+};
+#[cfg(feature = "serialport")]
+use crate::{
+    command::{Command, CommandType},
+    connection::Connection,
+    target::FlashTarget,
+    target::ProgressCallbacks,
+};
+
+/// Applications running from an ESP32's (or variant's) flash
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Esp32Target {
+    chip: Chip,
+    spi_attach_params: SpiAttachParams,
+    use_stub: bool,
+    verify: bool,
+    skip: bool,
+    flash_write_size: usize,
+    compress_bootloader: bool,
+    boot_addr: u32,
+    need_deflate_end: bool,
+    need_flash_end: bool,
+}
+
+impl Esp32Target {
+    /// Create a new ESP32 target.
+    pub fn new(
+        chip: Chip,
+        spi_attach_params: SpiAttachParams,
+        use_stub: bool,
+        verify: bool,
+        skip: bool,
+    ) -> Self {
+        Esp32Target {
+            chip,
+            spi_attach_params,
+            use_stub,
+            verify,
+            skip,
+            flash_write_size: chip.flash_write_size(),
+            compress_bootloader: true,
+            boot_addr: chip.boot_address(),
+            need_deflate_end: false,
+            need_flash_end: false,
+        }
+    }
+
+    /// Use the given block size when writing to flash, instead of the chip's
+    /// default.
+    pub fn with_flash_write_size(mut self, flash_write_size: usize) -> Self {
+        self.flash_write_size = flash_write_size;
+        self
+    }
+
+    /// Write the bootloader region without compression, while still
+    /// compressing everything else.
+    pub fn with_bootloader_compression(mut self, compress: bool) -> Self {
+        self.compress_bootloader = compress;
+        self
+    }
+
+    /// Address of the bootloader region, if it's not at the chip's default
+    /// boot address, e.g. as the flash layout was relocated.
+    pub fn with_boot_address(mut self, boot_addr: u32) -> Self {
+        self.boot_addr = boot_addr;
+        self
+    }
+}
+
+#[cfg(feature = "serialport")]
+impl Esp32Target {
+    /// Write the zlib-compressed `compressed` data of a segment
+    fn write_compressed(
+        &mut self,
+        connection: &mut Connection,
+        segment: &Segment<'_>,
+        compressed: &[u8],
+        erase_size: u32,
+        progress: &mut dyn ProgressCallbacks,
+    ) -> Result<(), Error> {
+        let flash_write_size = self.flash_write_size;
+        let chunks = compressed.chunks(flash_write_size);
+
+        connection.with_timeout(
+            connection.op_timeout(CommandType::FlashDeflBegin, erase_size),
+            |connection| {
+                connection.command(Command::FlashDeflBegin {
+                    size: segment.data.len() as u32,
+                    blocks: chunks.len() as u32,
+                    block_size: flash_write_size as u32,
+                    offset: segment.addr,
+                    supports_encryption: self.chip != Chip::Esp32 && !self.use_stub,
+                })?;
+                Ok(())
+            },
+        )?;
+        self.need_deflate_end = true;
+
+        // decode the chunks to see how much data the device will have to save
+        let mut decoder = ZlibDecoder::new(Vec::new());
+        let mut decoded_size = 0;
+
+        for (i, block) in chunks.enumerate() {
+            decoder.write_all(block)?;
+            decoder.flush()?;
+            let size = decoder.get_ref().len() - decoded_size;
+            decoded_size = decoder.get_ref().len();
+
+            connection.with_timeout(
+                CommandType::FlashDeflData.timeout_for_size(size as u32),
+                |connection| {
+                    connection.command(Command::FlashDeflData {
+                        sequence: i as u32,
+                        pad_to: 0,
+                        pad_byte: 0xff,
+                        data: block,
+                    })?;
+                    Ok(())
+                },
+            )?;
+
+            progress.update(i + 1);
+            if progress.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the data of a segment as is
+    fn write_uncompressed(
+        &mut self,
+        connection: &mut Connection,
+        segment: &Segment<'_>,
+        erase_size: u32,
+        progress: &mut dyn ProgressCallbacks,
+    ) -> Result<(), Error> {
+        let flash_write_size = self.flash_write_size;
+        let chunks = segment.data.chunks(flash_write_size);
+
+        connection.with_timeout(
+            connection.op_timeout(CommandType::FlashBegin, erase_size),
+            |connection| {
+                connection.command(Command::FlashBegin {
+                    size: segment.data.len() as u32,
+                    blocks: chunks.len() as u32,
+                    block_size: flash_write_size as u32,
+                    offset: segment.addr,
+                    supports_encryption: self.chip != Chip::Esp32 && !self.use_stub,
+                })?;
+                Ok(())
+            },
+        )?;
+        self.need_flash_end = true;
+
+        for (i, block) in chunks.enumerate() {
+            // Every block must be complete, so pad the last one with erased flash
+            connection.with_timeout(
+                CommandType::FlashData.timeout_for_size(flash_write_size as u32),
+                |connection| {
+                    connection.command(Command::FlashData {
+                        sequence: i as u32,
+                        pad_to: flash_write_size,
+                        pad_byte: 0xff,
+                        data: block,
+                    })?;
+                    Ok(())
+                },
+            )?;
+
+            progress.update(i + 1);
+            if progress.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serialport")]
+impl FlashTarget for Esp32Target {
+    fn begin(&mut self, connection: &mut Connection) -> Result<(), Error> {
+        connection.with_timeout(CommandType::SpiAttach.timeout(), |connection| {
+            let command = if self.use_stub {
+                Command::SpiAttachStub {
+                    spi_params: self.spi_attach_params,
+                }
+            } else {
+                Command::SpiAttach {
+                    spi_params: self.spi_attach_params,
+                }
+            };
+
+            connection.command(command)
+        })?;
+
+        // The stub usually disables these watchdog timers, however if we're not using
+        // the stub we need to disable them before flashing begins.
+        //
+        // TODO: the stub doesn't appear to disable the watchdog on ESP32-S3, so we
+        //       explicitly disable the watchdog here.
+        if connection.is_using_usb_serial_jtag() {
+            if let (Some(wdt_wprotect), Some(wdt_config0)) =
+                (self.chip.wdt_wprotect(), self.chip.wdt_config0())
+            {
+                connection.command(Command::WriteReg {
+                    address: wdt_wprotect,
+                    value: WDT_WKEY,
+                    mask: None,
+                })?; // WP disable
+                connection.command(Command::WriteReg {
+                    address: wdt_config0,
+                    value: 0x0,
+                    mask: None,
+                })?; // turn off RTC WDT
+                connection.command(Command::WriteReg {
+                    address: wdt_wprotect,
+                    value: 0x0,
+                    mask: None,
+                })?; // WP enable
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_segment(
+        &mut self,
+        connection: &mut Connection,
+        segment: Segment<'_>,
+        progress: &mut dyn ProgressCallbacks,
+    ) -> Result<(), Error> {
+        let addr = segment.addr;
+
+        let mut md5_hasher = Md5::new();
+        md5_hasher.update(&segment.data);
+        let checksum_md5 = md5_hasher.finalize();
+
+        let compressed = if self.compress_bootloader || addr != self.boot_addr {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(&segment.data)?;
+            Some(encoder.finish()?)
+        } else {
+            debug!("Writing segment at address '0x{addr:x}' uncompressed");
+            None
+        };
+        let data_len = compressed.as_ref().map_or(segment.data.len(), Vec::len);
+
+        let erase_count = segment.data.len().div_ceil(FLASH_SECTOR_SIZE);
+
+        // round up to sector size
+        let erase_size = (erase_count * FLASH_SECTOR_SIZE) as u32;
+
+        progress.init(addr, data_len.div_ceil(self.flash_write_size));
+
+        if self.skip {
+            let flash_checksum_md5: u128 = connection.with_timeout(
+                connection.op_timeout(CommandType::FlashMd5, segment.data.len() as u32),
+                |connection| {
+                    connection
+                        .command(Command::FlashMd5 {
+                            offset: addr,
+                            size: segment.data.len() as u32,
+                        })?
+                        .try_into()
+                },
+            )?;
+
+            if checksum_md5[..] == flash_checksum_md5.to_be_bytes() {
+                debug!("Segment at address '0x{addr:x}' has not changed, skipping write");
+
+                progress.finish(true);
+                return Ok(());
+            }
+        }
+
+        match &compressed {
+            Some(compressed) => {
+                self.write_compressed(connection, &segment, compressed, erase_size, progress)?
+            }
+            None => self.write_uncompressed(connection, &segment, erase_size, progress)?,
+        }
+
+        if self.verify {
+            progress.verifying();
+            let flash_checksum_md5: u128 = connection.with_timeout(
+                connection.op_timeout(CommandType::FlashMd5, segment.data.len() as u32),
+                |connection| {
+                    connection
+                        .command(Command::FlashMd5 {
+                            offset: addr,
+                            size: segment.data.len() as u32,
+                        })?
+                        .try_into()
+                },
+            )?;
+
+            if checksum_md5[..] != flash_checksum_md5.to_be_bytes() {
+                return Err(Error::VerifyFailed);
+            }
+            debug!("Segment at address '0x{addr:x}' verified successfully");
+        }
+
+        progress.finish(false);
+
+        Ok(())
+    }
+
+    fn finish(&mut self, connection: &mut Connection, reboot: bool) -> Result<(), Error> {
+        // Ending either kind of write ends the flash operation as a whole
+        if self.need_flash_end && !self.need_deflate_end {
+            connection.with_timeout(CommandType::FlashEnd.timeout(), |connection| {
+                connection.command(Command::FlashEnd { reboot: false })
+            })?;
+        }
+        if self.need_deflate_end {
+            connection.with_timeout(CommandType::FlashDeflEnd.timeout(), |connection| {
+                connection.command(Command::FlashDeflEnd { reboot: false })
+            })?;
+        }
+
+        if reboot {
+            connection.reset_after(self.use_stub, self.chip)?;
+        }
+
+        Ok(())
+    }
+}
@@ -400,7 +400,7 @@ fn generate_efuse_constants(
     sorted.sort_by(|a, b| (a.1).cmp(b.1));
 
     writeln!(writer)?;
-    for (name, attrs) in sorted {
+    for (name, attrs) in &sorted {
         let EfuseAttrs {
             block,
             word,
@@ -418,6 +418,20 @@ fn generate_efuse_constants(
         )?;
     }
 
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// All eFuse fields defined for this device, by name."
+    )?;
+    writeln!(
+        writer,
+        "pub(crate) const FIELDS: &[(&str, EfuseField)] = &["
+    )?;
+    for (name, _) in &sorted {
+        writeln!(writer, "    (\"{name}\", {name}),")?;
+    }
+    writeln!(writer, "];")?;
+
     Ok(())
 }
 
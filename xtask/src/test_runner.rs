@@ -553,7 +553,7 @@ impl TestRunner {
         let flash_output = self.flash_output_file();
 
         self.run_simple_command_test(
-            &["erase-flash"],
+            &["erase-flash", "--yes"],
             Some(&["Flash has been erased!"]),
             Duration::from_secs(40),
             "erase-flash",
@@ -601,7 +601,7 @@ impl TestRunner {
 
         // Valid erase - should succeed
         self.run_simple_command_test(
-            &["erase-region", "0x1000", "0x1000"],
+            &["erase-region", "0x1000", "0x1000", "--yes"],
             Some(&["Erasing region at"]),
             Duration::from_secs(10),
             "erase-region valid",
@@ -870,7 +870,7 @@ impl TestRunner {
 
         // First erase the flash
         self.run_simple_command_test(
-            &["erase-flash"],
+            &["erase-flash", "--yes"],
             Some(&["Flash has been erased!"]),
             Duration::from_secs(40),
             "erase-flash for checksum",